@@ -0,0 +1,244 @@
+//! Command-line arguments. Without any, rtyping starts the game; the
+//! `history` commands run without opening the terminal UI. `--profile`
+//! picks whose settings and history either of them uses.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::config;
+use crate::domain::history::HistoryFormat;
+use crate::domain::profile::{self, DEFAULT_PROFILE};
+
+pub const USAGE: &str = "\
+Usage:
+  rtyping [--profile NAME]     Start the game
+  rtyping [--profile NAME] history export [--format csv|json] [--output FILE]
+                               Write every saved run to FILE, or to standard output
+  rtyping [--profile NAME] history import FILE [--format csv|json]
+                               Add the runs in FILE whose IDs are not saved yet
+  rtyping --help               Show this help
+
+--profile uses the settings and history of profile NAME, which must exist;
+create profiles from the title menu. `default` is the profile without a name. The format defaults to
+the file extension, and export defaults to CSV.";
+
+/// The parsed command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    /// The profile to use, or `None` for the default profile.
+    pub profile: Option<String>,
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Play,
+    Help,
+    ExportHistory {
+        format: HistoryFormat,
+        output: Option<PathBuf>,
+    },
+    ImportHistory {
+        path: PathBuf,
+        format: HistoryFormat,
+    },
+}
+
+/// Reads the arguments after the program name.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Invocation, String> {
+    let mut args = args.into_iter().peekable();
+    let mut profile = None;
+    if args.next_if(|arg| arg == "--profile").is_some() {
+        let name = args.next().ok_or("`--profile` needs a name")?;
+        if !name.trim().eq_ignore_ascii_case(DEFAULT_PROFILE) {
+            profile = Some(profile::validate_name(&name)?.to_string());
+        }
+    }
+    Ok(Invocation {
+        profile,
+        command: parse_command(args)?,
+    })
+}
+
+fn parse_command(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let Some(first) = args.next() else {
+        return Ok(Command::Play);
+    };
+    match first.as_str() {
+        "-h" | "--help" => return Ok(Command::Help),
+        "history" => {}
+        other => return Err(format!("unknown argument `{other}`")),
+    }
+
+    let action = args.next().ok_or("`history` needs `export` or `import`")?;
+    let mut format = None;
+    let mut output = None;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let name = args.next().ok_or("`--format` needs csv or json")?;
+                format = Some(
+                    HistoryFormat::parse(&name)
+                        .ok_or_else(|| format!("unknown format `{name}`; use csv or json"))?,
+                );
+            }
+            "--output" | "-o" if action == "export" => {
+                output = Some(PathBuf::from(args.next().ok_or("`--output` needs a file")?));
+            }
+            value if action == "import" && path.is_none() && !value.starts_with('-') => {
+                path = Some(PathBuf::from(value));
+            }
+            other => return Err(format!("unknown argument `{other}`")),
+        }
+    }
+
+    match action.as_str() {
+        "export" => Ok(Command::ExportHistory {
+            format: format
+                .or_else(|| output.as_deref().and_then(HistoryFormat::of_path))
+                .unwrap_or(HistoryFormat::Csv),
+            output,
+        }),
+        "import" => {
+            let path = path.ok_or("`history import` needs a file")?;
+            let format = format
+                .or_else(|| HistoryFormat::of_path(&path))
+                .ok_or("cannot tell the format from the file name; add `--format csv|json`")?;
+            Ok(Command::ImportHistory { path, format })
+        }
+        other => Err(format!("unknown history command `{other}`")),
+    }
+}
+
+/// Runs a command other than [`Command::Play`] for `profile`. Progress and
+/// warnings go to standard error so exports can be piped.
+pub fn run(profile: Option<&str>, command: Command) -> io::Result<()> {
+    match command {
+        Command::Play => Ok(()),
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Command::ExportHistory { format, output } => {
+            let report = config::read_history(profile, &configured_history_path(profile)?)?;
+            for warning in &report.warnings {
+                eprintln!("warning: {warning}");
+            }
+            for damage in &report.damage {
+                eprintln!(
+                    "warning: {}; exporting the runs that could be read",
+                    damage.describe()
+                );
+            }
+            let Some(path) = output else {
+                let text = config::export_history_text(&report.entries, format)?;
+                return io::stdout().lock().write_all(text.as_bytes());
+            };
+            config::write_history_export(&report.entries, format, &path)?;
+            eprintln!(
+                "Exported {} runs as {} to {}",
+                report.entries.len(),
+                format.label(),
+                path.display()
+            );
+            Ok(())
+        }
+        Command::ImportHistory { path, format } => {
+            let report =
+                config::import_history(profile, &configured_history_path(profile)?, &path, format)?;
+            eprintln!("{}", report.describe());
+            Ok(())
+        }
+    }
+}
+
+/// The saved `history_path` setting, so commands see the same history as
+/// the game.
+fn configured_history_path(profile: Option<&str>) -> io::Result<String> {
+    Ok(config::load_config(profile)?.config.game.history_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(ToString::to_string)).map(|invocation| invocation.command)
+    }
+
+    fn parse_profile(args: &[&str]) -> Result<Option<String>, String> {
+        parse(args.iter().map(ToString::to_string)).map(|invocation| invocation.profile)
+    }
+
+    #[test]
+    fn no_arguments_start_the_game() {
+        assert_eq!(parse_args(&[]), Ok(Command::Play));
+        assert_eq!(parse_args(&["--help"]), Ok(Command::Help));
+        assert!(parse_args(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn profile_comes_before_the_command() {
+        assert_eq!(parse_profile(&[]), Ok(None));
+        assert_eq!(
+            parse_profile(&["--profile", "alice"]),
+            Ok(Some("alice".into()))
+        );
+        assert_eq!(parse_profile(&["--profile", "default"]), Ok(None));
+        assert_eq!(
+            parse(["--profile", "bob", "history", "export"].map(String::from)),
+            Ok(Invocation {
+                profile: Some("bob".into()),
+                command: Command::ExportHistory {
+                    format: HistoryFormat::Csv,
+                    output: None,
+                },
+            })
+        );
+        assert!(parse_profile(&["--profile"]).is_err());
+        assert!(parse_profile(&["--profile", "../x"]).is_err());
+        assert!(parse_args(&["history", "export", "--profile", "alice"]).is_err());
+    }
+
+    #[test]
+    fn export_takes_a_format_or_the_output_extension() {
+        assert_eq!(
+            parse_args(&["history", "export", "--format", "csv"]),
+            Ok(Command::ExportHistory {
+                format: HistoryFormat::Csv,
+                output: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&["history", "export", "--output", "runs.JSON"]),
+            Ok(Command::ExportHistory {
+                format: HistoryFormat::Json,
+                output: Some(PathBuf::from("runs.JSON")),
+            })
+        );
+        assert!(parse_args(&["history", "export", "--format", "xml"]).is_err());
+        assert!(parse_args(&["history", "export", "runs.csv"]).is_err());
+    }
+
+    #[test]
+    fn import_needs_a_file_with_a_known_format() {
+        assert_eq!(
+            parse_args(&["history", "import", "runs.csv"]),
+            Ok(Command::ImportHistory {
+                path: PathBuf::from("runs.csv"),
+                format: HistoryFormat::Csv,
+            })
+        );
+        assert_eq!(
+            parse_args(&["history", "import", "--format", "json", "backup.txt"]),
+            Ok(Command::ImportHistory {
+                path: PathBuf::from("backup.txt"),
+                format: HistoryFormat::Json,
+            })
+        );
+        assert!(parse_args(&["history", "import"]).is_err());
+        assert!(parse_args(&["history", "import", "runs.txt"]).is_err());
+        assert!(parse_args(&["history", "sync"]).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Replaces `path` with `content` so that a crash leaves either the old or
+/// the new file, never a partial one: the content is written and synced to
+/// a temporary file in the same directory, which is then renamed over
+/// `path`.
+pub(super) fn write_atomic(path: &Path, content: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp_path = temp_path(path);
+    let result = File::create(&temp_path).and_then(|mut file| {
+        file.write_all(content)?;
+        file.sync_all()
+    });
+    if let Err(err) = result.and_then(|()| fs::rename(&temp_path, path)) {
+        fs::remove_file(&temp_path).ok();
+        return Err(err);
+    }
+    Ok(())
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn write_atomic_replaces_the_file_and_leaves_no_temporary_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "rtyping-atomic-test-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("system time should be after unix epoch")
+                .as_nanos()
+        ));
+        let path = dir.join("nested").join("history.jsonl");
+
+        write_atomic(&path, b"old").expect("first write should succeed");
+        write_atomic(&path, b"new").expect("second write should succeed");
+
+        assert_eq!(fs::read(&path).expect("file should exist"), b"new");
+        assert!(!temp_path(&path).exists());
+    }
+}
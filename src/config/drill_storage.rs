@@ -0,0 +1,67 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::domain::drill::{DrillDeck, DrillLoadReport};
+
+pub(super) fn load_drills_from_path(drills_path: &Path) -> io::Result<DrillLoadReport> {
+    if !drills_path.exists() {
+        return Ok(DrillLoadReport::default());
+    }
+
+    let content = fs::read_to_string(drills_path)?;
+    match serde_json::from_str::<DrillDeck>(&content) {
+        Ok(deck) => Ok(DrillLoadReport {
+            deck,
+            warnings: Vec::new(),
+        }),
+        Err(err) => Ok(DrillLoadReport {
+            deck: DrillDeck::default(),
+            warnings: vec![format!("Failed to load drill schedule: {err}")],
+        }),
+    }
+}
+
+pub(super) fn save_drills_to_path(deck: &DrillDeck, drills_path: &Path) -> io::Result<()> {
+    if let Some(parent) = drills_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let content = serde_json::to_string_pretty(deck)
+        .map_err(|err| io::Error::other(format!("failed to serialize drill schedule: {err}")))?;
+    fs::write(drills_path, content)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+
+    use super::*;
+    use crate::config::test_support::tempfile_dir;
+
+    #[test]
+    fn save_and_load_drills_round_trip() {
+        let dir = tempfile_dir("drills");
+        let path = dir.join("nested").join("drills.json");
+        let mut deck = DrillDeck::default();
+        deck.review("th", 4, 20_000);
+
+        save_drills_to_path(&deck, &path).expect("drill schedule should save");
+        let report = load_drills_from_path(&path).expect("drill schedule should load");
+
+        assert_eq!(report.deck, deck);
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn load_broken_drills_starts_an_empty_schedule() {
+        let dir = tempfile_dir("drills");
+        let path = dir.join("drills.json");
+        fs::write(&path, "[").expect("broken schedule should be written");
+
+        let report = load_drills_from_path(&path).expect("broken schedule should not fail hard");
+
+        assert_eq!(report.deck, DrillDeck::default());
+        assert_eq!(report.warnings.len(), 1);
+    }
+}
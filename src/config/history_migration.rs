@@ -0,0 +1,223 @@
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::domain::history::{HISTORY_SCHEMA_VERSION, HistoryEntry};
+
+/// Version of entries saved before the format was versioned. They have no
+/// `schema_version`, `id`, or `timestamp`.
+const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+pub(super) struct MigratedHistory {
+    pub(super) entries: Vec<HistoryEntry>,
+    pub(super) warnings: Vec<String>,
+    /// Positions of the values that are not history entries.
+    pub(super) unreadable: Vec<usize>,
+}
+
+/// Parses saved entries, upgrading older shapes to the current version.
+/// Entries from a newer version are read as far as this version understands
+/// them; values that are not entries at all are skipped and reported.
+pub(super) fn migrate_entries(values: Vec<Value>) -> MigratedHistory {
+    let mut newer = 0;
+    let mut entries = Vec::with_capacity(values.len());
+    let mut unreadable = Vec::new();
+    for (index, value) in values.into_iter().enumerate() {
+        let value = migrate_entry(index, value);
+        if schema_version(&value) > HISTORY_SCHEMA_VERSION {
+            newer += 1;
+        }
+        match serde_json::from_value(value) {
+            Ok(entry) => entries.push(entry),
+            Err(_) => unreadable.push(index),
+        }
+    }
+
+    let warnings = if newer == 0 {
+        Vec::new()
+    } else {
+        vec![format!(
+            "{newer} history entries were saved by a newer version; fields this version does not know are dropped when history is saved"
+        )]
+    };
+    MigratedHistory {
+        entries,
+        warnings,
+        unreadable,
+    }
+}
+
+fn migrate_entry(index: usize, mut value: Value) -> Value {
+    let version = schema_version(&value);
+    if version >= HISTORY_SCHEMA_VERSION {
+        return value;
+    }
+    let Value::Object(fields) = &mut value else {
+        return value;
+    };
+
+    // Version 2 added `id` and `timestamp`; the time of legacy runs is unknown.
+    if version <= LEGACY_SCHEMA_VERSION {
+        add_legacy_id(index, fields);
+    }
+    fields.insert(
+        "schema_version".to_string(),
+        Value::from(HISTORY_SCHEMA_VERSION),
+    );
+    value
+}
+
+fn schema_version(value: &Value) -> u32 {
+    value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(LEGACY_SCHEMA_VERSION)
+}
+
+/// Legacy entries get an ID derived from their position and content, so the
+/// same file migrates to the same IDs until it is saved in the new format.
+fn add_legacy_id(index: usize, fields: &mut Map<String, Value>) {
+    if fields
+        .get("id")
+        .and_then(Value::as_str)
+        .is_some_and(|id| !id.is_empty())
+    {
+        return;
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(index.to_be_bytes());
+    hasher.update(Value::Object(fields.clone()).to_string());
+    let digest = hasher.finalize();
+    let prefix = digest
+        .get(..8)
+        .and_then(|bytes| <[u8; 8]>::try_from(bytes).ok())
+        .unwrap_or_default();
+    fields.insert(
+        "id".to_string(),
+        Value::from(format!("{:016x}", u64::from_be_bytes(prefix))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+
+    use super::*;
+    use crate::domain::history::TestLength;
+
+    fn migrate(json: &str) -> MigratedHistory {
+        let values = serde_json::from_str(json).expect("history JSON should parse");
+        migrate_entries(values)
+    }
+
+    fn only_entry(history: &MigratedHistory) -> &HistoryEntry {
+        assert_eq!(history.entries.len(), 1);
+        history.entries.first().expect("one entry")
+    }
+
+    #[test]
+    fn original_shape_gets_a_version_and_an_id_but_no_timestamp() {
+        let json = r#"[{"wpm":42.0,"accuracy":95.0,"miss_count":1,"elapsed_seconds":30,
+            "generation_source":"Local","mode":"timed","missed_chars":["a"]}]"#;
+
+        let history = migrate(json);
+        let entry = only_entry(&history);
+
+        assert_eq!(entry.schema_version, HISTORY_SCHEMA_VERSION);
+        assert_eq!(entry.id.len(), 16);
+        assert_eq!(entry.timestamp, None);
+        assert_eq!(entry.test_length(), TestLength::Time(30));
+        assert!(history.warnings.is_empty());
+    }
+
+    #[test]
+    fn shape_with_test_length_and_failure_keeps_both() {
+        let json = r#"[{"wpm":30.0,"accuracy":80.0,"miss_count":4,"elapsed_seconds":12,
+            "generation_source":"Groq","mode":"timed","missed_chars":[],
+            "test":{"kind":"words","length":25},"failed":true}]"#;
+
+        let history = migrate(json);
+        let entry = only_entry(&history);
+
+        assert_eq!(entry.test, Some(TestLength::Words(25)));
+        assert!(entry.failed);
+        assert_eq!(entry.schema_version, HISTORY_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn shape_with_timeline_and_analytics_keeps_them() {
+        let json = r#"[{"wpm":30.0,"accuracy":100.0,"miss_count":0,"elapsed_seconds":1,
+            "generation_source":"Local","mode":"timed","missed_chars":[],
+            "target_text":"ab","keystrokes":[{"elapsed_ms":5,"key":"a","position":1}],
+            "analytics":{"per_second_wpm":[24],"burst_wpm":24.0},"adaptive_step":2,
+            "difficulty":12.5}]"#;
+
+        let history = migrate(json);
+        let entry = only_entry(&history);
+
+        assert_eq!(entry.target_text, "ab");
+        assert_eq!(entry.keystrokes.len(), 1);
+        assert!(entry.analytics.is_some());
+        assert_eq!(entry.adaptive_step, Some(2));
+        assert!(!entry.id.is_empty());
+    }
+
+    #[test]
+    fn legacy_ids_are_stable_and_distinct_for_identical_entries() {
+        let json = r#"[{"wpm":1.0,"accuracy":1.0,"miss_count":0,"elapsed_seconds":1,
+            "generation_source":"Local","mode":"timed","missed_chars":[]},
+            {"wpm":1.0,"accuracy":1.0,"miss_count":0,"elapsed_seconds":1,
+            "generation_source":"Local","mode":"timed","missed_chars":[]}]"#;
+
+        let ids = |history: MigratedHistory| {
+            history
+                .entries
+                .into_iter()
+                .map(|entry| entry.id)
+                .collect::<Vec<_>>()
+        };
+        let first = ids(migrate(json));
+
+        assert_eq!(first, ids(migrate(json)));
+        assert_ne!(first.first(), first.get(1));
+    }
+
+    #[test]
+    fn current_shape_is_kept_as_saved() {
+        let json = r#"[{"schema_version":2,"id":"00000000000000ff","timestamp":1792389143,
+            "wpm":50.0,"accuracy":99.0,"miss_count":0,"elapsed_seconds":60,
+            "generation_source":"Local","mode":"timed","missed_chars":[]}]"#;
+
+        let history = migrate(json);
+        let entry = only_entry(&history);
+
+        assert_eq!(entry.id, "00000000000000ff");
+        assert_eq!(entry.timestamp, Some(1_792_389_143));
+        assert!(history.warnings.is_empty());
+    }
+
+    #[test]
+    fn newer_shape_is_read_with_a_warning() {
+        let json = r#"[{"schema_version":9,"id":"abc","wpm":50.0,"accuracy":99.0,
+            "miss_count":0,"elapsed_seconds":60,"generation_source":"Local",
+            "mode":"timed","missed_chars":[],"future_field":true}]"#;
+
+        let history = migrate(json);
+
+        assert_eq!(only_entry(&history).schema_version, 9);
+        assert_eq!(history.warnings.len(), 1);
+    }
+
+    #[test]
+    fn values_that_are_not_entries_are_skipped_and_reported() {
+        let json = r#"[{"wpm":"fast"},{"wpm":50.0,"accuracy":99.0,"miss_count":0,
+            "elapsed_seconds":60,"generation_source":"Local","mode":"timed",
+            "missed_chars":[]},7]"#;
+
+        let history = migrate(json);
+
+        assert_eq!(history.entries.len(), 1);
+        assert_eq!(history.unreadable, vec![0, 2]);
+    }
+}
@@ -1,41 +1,402 @@
-use std::fs;
-use std::io;
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::domain::history::{HistoryEntry, HistoryLoadReport};
+use serde_json::Value;
 
-pub(super) fn load_history_from_path(history_path: &Path) -> io::Result<HistoryLoadReport> {
-    if !history_path.exists() {
-        return Ok(HistoryLoadReport {
+use crate::domain::history::{
+    HistoryBackup, HistoryDamage, HistoryEntry, HistoryFile, HistoryLoadReport,
+};
+
+use super::atomic_write::write_atomic;
+use super::history_migration::{self, MigratedHistory};
+use super::paths::{HistoryPaths, is_shared_log_name};
+
+/// Rotated copies of the log kept next to it, `history.jsonl.1` (newest)
+/// to `history.jsonl.3`.
+const BACKUP_SLOTS: usize = 3;
+
+/// Entries read from one file, and what could not be read.
+pub(super) struct FileContents {
+    pub(super) entries: Vec<HistoryEntry>,
+    pub(super) warnings: Vec<String>,
+    damage: Option<HistoryDamage>,
+}
+
+/// Reads the log and any legacy `history.json`. Unreadable lines are skipped
+/// and reported as damage rather than failing the whole load. A legacy file
+/// that reads cleanly is merged into the log, which is rewritten, and then
+/// retired as `history.json.old`.
+pub(super) fn load_history_from_paths(paths: &HistoryPaths) -> io::Result<HistoryLoadReport> {
+    seed_shared_log(paths)?;
+    let report = read_history_from_paths(paths)?;
+    if paths.legacy_path.exists() && report.damage.is_empty() {
+        save_history_to_path(&report.entries, &paths.log_path)?;
+        retire_legacy(paths)?;
+    }
+    Ok(report)
+}
+
+/// Reads the same history as [`load_history_from_paths`] without changing
+/// any file: a shared log that is not started yet is read from the local
+/// log, and a legacy file is merged in memory only.
+pub(super) fn read_history_from_paths(paths: &HistoryPaths) -> io::Result<HistoryLoadReport> {
+    let mut report = HistoryLoadReport::default();
+    let log_path = if paths.shared_dir.is_some() && !paths.log_path.exists() {
+        &paths.local_log_path
+    } else {
+        &paths.log_path
+    };
+    if log_path.exists() {
+        add_contents(&mut report, read_log(log_path)?);
+    }
+    if !paths.legacy_path.exists() {
+        return Ok(report);
+    }
+
+    let logged = std::mem::take(&mut report.entries);
+    add_contents(&mut report, read_legacy(&paths.legacy_path)?);
+    let legacy_ids = report
+        .entries
+        .iter()
+        .map(|entry| entry.id.clone())
+        .collect::<BTreeSet<_>>();
+    report.entries.extend(
+        logged
+            .into_iter()
+            .filter(|entry| !legacy_ids.contains(&entry.id)),
+    );
+    Ok(report)
+}
+
+/// The first time history is shared, this machine's log starts as a copy of
+/// the local one, so earlier runs reach the other machines.
+fn seed_shared_log(paths: &HistoryPaths) -> io::Result<()> {
+    if paths.shared_dir.is_none() || paths.log_path.exists() || !paths.local_log_path.exists() {
+        return Ok(());
+    }
+    if let Some(parent) = paths.log_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(&paths.log_path, &fs::read(&paths.local_log_path)?)
+}
+
+/// Adds the runs of the other machines' logs in the shared directory that
+/// are not loaded yet, then orders all runs by time, with runs saved before
+/// timestamps were recorded first. The other logs are only read: lines that
+/// cannot be read there are reported as warnings, not as damage to recover.
+pub(super) fn merge_shared_logs(report: &mut HistoryLoadReport, paths: &HistoryPaths) {
+    if paths.shared_dir.is_none() {
+        return;
+    }
+
+    let shared = read_shared_logs(paths);
+    let mut ids = report
+        .entries
+        .iter()
+        .map(|entry| entry.id.clone())
+        .collect::<BTreeSet<_>>();
+    report.entries.extend(
+        shared
+            .entries
+            .into_iter()
+            .filter(|entry| ids.insert(entry.id.clone())),
+    );
+    report.entries.sort_by_key(|entry| entry.timestamp);
+    report.warnings.extend(shared.warnings);
+}
+
+/// Entries of the logs other machines write to the shared directory for
+/// the same profile.
+pub(super) fn read_shared_logs(paths: &HistoryPaths) -> FileContents {
+    let mut shared = FileContents {
+        entries: Vec::new(),
+        warnings: Vec::new(),
+        damage: None,
+    };
+    let Some(dir) = &paths.shared_dir else {
+        return shared;
+    };
+    let mut logs = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path != &paths.log_path
+                    && path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| is_shared_log_name(name, paths.profile.as_deref()))
+            })
+            .collect::<Vec<_>>(),
+        Err(err) => {
+            shared
+                .warnings
+                .push(format!("Failed to read {}: {err}", dir.display()));
+            return shared;
+        }
+    };
+    logs.sort();
+
+    for log in logs {
+        let name = log.file_name().unwrap_or_default().to_string_lossy();
+        match read_log(&log) {
+            Ok(contents) => {
+                if let Some(damage) = contents.damage {
+                    shared.warnings.push(format!(
+                        "{name}: {} unreadable lines skipped",
+                        damage.unreadable.len()
+                    ));
+                }
+                shared.entries.extend(contents.entries);
+                shared.warnings.extend(contents.warnings);
+            }
+            Err(err) => shared
+                .warnings
+                .push(format!("Failed to read {name}: {err}")),
+        }
+    }
+    shared
+}
+
+fn add_contents(report: &mut HistoryLoadReport, contents: FileContents) {
+    report.entries.extend(contents.entries);
+    report.warnings.extend(contents.warnings);
+    report.damage.extend(contents.damage);
+}
+
+fn read_log(log_path: &Path) -> io::Result<FileContents> {
+    let content = fs::read(log_path)?;
+    let mut line_numbers = Vec::new();
+    let mut values = Vec::new();
+    let mut unreadable = Vec::new();
+    for (index, line) in content.split(|byte| *byte == b'\n').enumerate() {
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+        match serde_json::from_slice::<Value>(line) {
+            Ok(value) => {
+                line_numbers.push(index + 1);
+                values.push(value);
+            }
+            Err(_) => unreadable.push(index + 1),
+        }
+    }
+
+    let migrated = history_migration::migrate_entries(values);
+    unreadable.extend(
+        migrated
+            .unreadable
+            .iter()
+            .filter_map(|position| line_numbers.get(*position)),
+    );
+    unreadable.sort_unstable();
+    Ok(contents(HistoryFile::Log, migrated, unreadable))
+}
+
+fn read_legacy(legacy_path: &Path) -> io::Result<FileContents> {
+    let content = fs::read(legacy_path)?;
+    let Ok(values) = serde_json::from_slice::<Vec<Value>>(&content) else {
+        return Ok(FileContents {
             entries: Vec::new(),
             warnings: Vec::new(),
+            damage: Some(HistoryDamage {
+                file: HistoryFile::Legacy,
+                unreadable: Vec::new(),
+            }),
         });
-    }
+    };
 
-    let content = fs::read_to_string(history_path)?;
-    match serde_json::from_str::<Vec<HistoryEntry>>(&content) {
-        Ok(entries) => Ok(HistoryLoadReport {
-            entries,
-            warnings: Vec::new(),
-        }),
-        Err(err) => Ok(HistoryLoadReport {
-            entries: Vec::new(),
-            warnings: vec![format!("Failed to load history: {err}")],
-        }),
+    let migrated = history_migration::migrate_entries(values);
+    let unreadable = migrated
+        .unreadable
+        .iter()
+        .map(|position| position + 1)
+        .collect();
+    Ok(contents(HistoryFile::Legacy, migrated, unreadable))
+}
+
+fn contents(file: HistoryFile, migrated: MigratedHistory, unreadable: Vec<usize>) -> FileContents {
+    FileContents {
+        entries: migrated.entries,
+        warnings: migrated.warnings,
+        damage: (!unreadable.is_empty()).then_some(HistoryDamage { file, unreadable }),
     }
 }
 
-pub(super) fn save_history_to_path(
-    entries: &[HistoryEntry],
-    history_path: &Path,
-) -> io::Result<()> {
-    if let Some(parent) = history_path.parent() {
+/// Adds one entry as a new line at the end of the log. A last line cut off
+/// by a crash is ended first, so it stays a separate unreadable line.
+pub(super) fn append_history_to_path(entry: &HistoryEntry, log_path: &Path) -> io::Result<()> {
+    if let Some(parent) = log_path.parent() {
         fs::create_dir_all(parent)?;
     }
 
-    let content = serde_json::to_string_pretty(entries)
+    let mut line = serialize_entry(entry)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(log_path)?;
+    if ends_mid_line(&mut file)? {
+        line.insert(0, b'\n');
+    }
+    file.write_all(&line)?;
+    file.sync_data()
+}
+
+fn ends_mid_line(file: &mut File) -> io::Result<bool> {
+    if file.metadata()?.len() == 0 {
+        return Ok(false);
+    }
+    file.seek(SeekFrom::End(-1))?;
+    let mut last = [0_u8];
+    file.read_exact(&mut last)?;
+    Ok(last != [b'\n'])
+}
+
+/// Rewrites the whole log atomically, after backing up the current one.
+pub(super) fn save_history_to_path(entries: &[HistoryEntry], log_path: &Path) -> io::Result<()> {
+    let mut content = Vec::new();
+    for entry in entries {
+        content.extend(serialize_entry(entry)?);
+    }
+    if log_path.exists() {
+        push_backup(log_path, &fs::read(log_path)?)?;
+    }
+    replace_log(log_path, &content)
+}
+
+fn serialize_entry(entry: &HistoryEntry) -> io::Result<Vec<u8>> {
+    let mut line = serde_json::to_vec(entry)
         .map_err(|err| io::Error::other(format!("failed to serialize history: {err}")))?;
-    fs::write(history_path, content)
+    line.push(b'\n');
+    Ok(line)
+}
+
+/// Backs up the log when it has grown past backup slot 1, that is when it
+/// starts with the backup and has more lines. A log that shrank or was
+/// replaced outside the app leaves the backups alone, so the older slots
+/// still hold what it lost.
+pub(super) fn rotate_backups(log_path: &Path) -> io::Result<()> {
+    if !log_path.exists() {
+        return Ok(());
+    }
+    let current = fs::read(log_path)?;
+    if let Ok(backup) = fs::read(backup_path(log_path, 1))
+        && !(current.len() > backup.len() && current.starts_with(&backup))
+    {
+        return Ok(());
+    }
+    push_backup(log_path, &current)
+}
+
+/// Copies the log into backup slot 1, shifting older copies down and
+/// dropping the oldest. Nothing changes when slot 1 already matches the log.
+fn push_backup(log_path: &Path, current: &[u8]) -> io::Result<()> {
+    let newest = backup_path(log_path, 1);
+    if fs::read(&newest).is_ok_and(|backup| backup == current) {
+        return Ok(());
+    }
+
+    for slot in (1..BACKUP_SLOTS).rev() {
+        let path = backup_path(log_path, slot);
+        if path.exists() {
+            fs::rename(&path, backup_path(log_path, slot + 1))?;
+        }
+    }
+    write_atomic(&newest, current)
+}
+
+/// Writes `content` as the log and records it in backup slot 1, so runs
+/// appended later extend the newest backup and rotate again.
+fn replace_log(log_path: &Path, content: &[u8]) -> io::Result<()> {
+    write_atomic(log_path, content)?;
+    push_backup(log_path, content)
+}
+
+/// Backups that exist, newest first, with how many entries each can restore.
+pub(super) fn list_backups(log_path: &Path) -> Vec<HistoryBackup> {
+    (1..=BACKUP_SLOTS)
+        .filter_map(|slot| {
+            let path = backup_path(log_path, slot);
+            let modified = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs());
+            let contents = read_log(&path).ok()?;
+            Some(HistoryBackup {
+                slot,
+                modified,
+                entries: contents.entries.len(),
+            })
+        })
+        .collect()
+}
+
+/// Replaces the log with a backup. Damaged files and the replaced log are
+/// renamed aside rather than deleted.
+pub(super) fn restore_backup(
+    paths: &HistoryPaths,
+    slot: usize,
+    damage: &[HistoryDamage],
+) -> io::Result<HistoryLoadReport> {
+    let content = fs::read(backup_path(&paths.log_path, slot))?;
+    set_aside_damaged(paths, damage)?;
+    if paths.log_path.exists() {
+        set_aside(&paths.log_path, "replaced")?;
+    }
+    replace_log(&paths.log_path, &content)?;
+    load_history_from_paths(paths)
+}
+
+/// Writes the readable entries of the log and legacy file as the new log.
+/// They are read again so runs merged from other machines stay in their own
+/// logs. Damaged files are renamed aside and a readable legacy file is
+/// retired.
+pub(super) fn keep_readable(paths: &HistoryPaths, damage: &[HistoryDamage]) -> io::Result<()> {
+    let readable = load_history_from_paths(paths)?;
+    set_aside_damaged(paths, damage)?;
+    save_history_to_path(&readable.entries, &paths.log_path)?;
+    retire_legacy(paths)
+}
+
+fn set_aside_damaged(paths: &HistoryPaths, damage: &[HistoryDamage]) -> io::Result<()> {
+    for damaged in damage {
+        let path = paths.path(damaged.file);
+        if path.exists() {
+            set_aside(path, "damaged")?;
+        }
+    }
+    Ok(())
+}
+
+/// Renames `path` to `<name>.<reason>-<unix seconds>` so earlier copies are
+/// not overwritten.
+fn set_aside(path: &Path, reason: &str) -> io::Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    fs::rename(path, with_suffix(path, &format!(".{reason}-{now}")))
+}
+
+fn retire_legacy(paths: &HistoryPaths) -> io::Result<()> {
+    if !paths.legacy_path.exists() {
+        return Ok(());
+    }
+    fs::rename(&paths.legacy_path, with_suffix(&paths.legacy_path, ".old"))
+}
+
+fn backup_path(log_path: &Path, slot: usize) -> PathBuf {
+    with_suffix(log_path, &format!(".{slot}"))
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
 }
 
 #[cfg(test)]
@@ -43,10 +404,14 @@ mod tests {
     #![expect(clippy::expect_used)]
 
     use super::*;
-    use crate::domain::history::HistoryMode;
+    use crate::config::test_support::tempfile_dir;
+    use crate::domain::history::{HISTORY_SCHEMA_VERSION, HistoryMode};
 
-    fn entry(wpm: f64) -> HistoryEntry {
+    fn entry(id: &str, wpm: f64) -> HistoryEntry {
         HistoryEntry {
+            schema_version: HISTORY_SCHEMA_VERSION,
+            id: id.into(),
+            timestamp: Some(1_792_389_143),
             wpm,
             accuracy: 95.0,
             miss_count: 1,
@@ -54,54 +419,256 @@ mod tests {
             generation_source: "Local".into(),
             mode: HistoryMode::Timed,
             missed_chars: vec!['a'],
+            ..HistoryEntry::default()
+        }
+    }
+
+    fn paths_in(dir: &Path) -> HistoryPaths {
+        HistoryPaths {
+            log_path: dir.join("history.jsonl"),
+            legacy_path: dir.join("history.json"),
+            local_log_path: dir.join("history.jsonl"),
+            shared_dir: None,
+            profile: None,
+        }
+    }
+
+    fn shared_paths_in(dir: &Path) -> HistoryPaths {
+        let shared_dir = dir.join("shared");
+        HistoryPaths {
+            log_path: shared_dir.join("history-this.jsonl"),
+            shared_dir: Some(shared_dir),
+            ..paths_in(dir)
+        }
+    }
+
+    fn timed(id: &str, timestamp: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: Some(timestamp),
+            ..entry(id, 40.0)
         }
     }
 
+    fn ids(entries: &[HistoryEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.id.as_str()).collect()
+    }
+
+    fn load(paths: &HistoryPaths) -> HistoryLoadReport {
+        load_history_from_paths(paths).expect("history should load")
+    }
+
+    fn wpms(entries: &[HistoryEntry]) -> Vec<f64> {
+        entries.iter().map(|entry| entry.wpm).collect()
+    }
+
     #[test]
     fn load_missing_history_returns_empty_report() {
-        let dir = tempfile_dir();
-        let path = dir.join("missing.json");
+        let report = load(&paths_in(&tempfile_dir("history")));
 
-        let report = load_history_from_path(&path).expect("missing history should load");
+        assert_eq!(report, HistoryLoadReport::default());
+    }
 
-        assert!(report.entries.is_empty());
+    #[test]
+    fn appended_entries_load_in_order() {
+        let paths = paths_in(&tempfile_dir("history").join("nested"));
+
+        append_history_to_path(&entry("a", 42.0), &paths.log_path).expect("entry should append");
+        append_history_to_path(&entry("b", 50.0), &paths.log_path).expect("entry should append");
+        let report = load(&paths);
+
+        assert_eq!(report.entries, vec![entry("a", 42.0), entry("b", 50.0)]);
         assert!(report.warnings.is_empty());
+        assert!(report.damage.is_empty());
     }
 
     #[test]
-    fn save_and_load_history_round_trip() {
-        let dir = tempfile_dir();
-        let path = dir.join("nested").join("history.json");
+    fn unreadable_lines_are_skipped_reported_and_kept() {
+        let paths = paths_in(&tempfile_dir("history"));
+        append_history_to_path(&entry("a", 42.0), &paths.log_path).expect("entry should append");
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(&paths.log_path)
+            .expect("log should open");
+        file.write_all(b"{\"wpm\":\"broken\"}\n{\"wpm\":4")
+            .expect("damage should be written");
+        append_history_to_path(&entry("b", 50.0), &paths.log_path).expect("entry should append");
 
-        save_history_to_path(&[entry(42.0)], &path).expect("history should save");
-        let report = load_history_from_path(&path).expect("history should load");
+        let report = load(&paths);
 
-        assert_eq!(report.entries, vec![entry(42.0)]);
-        assert!(report.warnings.is_empty());
+        assert_eq!(wpms(&report.entries), vec![42.0, 50.0]);
+        assert_eq!(
+            report.damage,
+            vec![HistoryDamage {
+                file: HistoryFile::Log,
+                unreadable: vec![2, 3],
+            }]
+        );
+        let content = fs::read_to_string(&paths.log_path).expect("log should be readable");
+        assert_eq!(content.lines().count(), 4);
     }
 
     #[test]
-    fn load_broken_history_returns_warning_and_empty_entries() {
-        let dir = tempfile_dir();
-        let path = dir.join("history.json");
-        fs::write(&path, "{").expect("broken history should be written");
+    fn readable_legacy_file_is_moved_into_the_log_and_retired() {
+        let dir = tempfile_dir("history");
+        let paths = paths_in(&dir);
+        let legacy = serde_json::to_string(&[entry("a", 42.0)]).expect("legacy should serialize");
+        fs::write(&paths.legacy_path, legacy).expect("legacy should be written");
+
+        let report = load(&paths);
 
-        let report = load_history_from_path(&path).expect("broken history should not fail hard");
+        assert_eq!(report.entries, vec![entry("a", 42.0)]);
+        assert!(!paths.legacy_path.exists());
+        assert!(dir.join("history.json.old").exists());
+        assert_eq!(load(&paths).entries, report.entries);
+    }
+
+    #[test]
+    fn unreadable_legacy_file_is_reported_and_left_in_place() {
+        let paths = paths_in(&tempfile_dir("history"));
+        fs::write(&paths.legacy_path, "[{").expect("legacy should be written");
+
+        let report = load(&paths);
 
         assert!(report.entries.is_empty());
-        assert_eq!(report.warnings.len(), 1);
-    }
-
-    fn tempfile_dir() -> std::path::PathBuf {
-        let mut path = std::env::temp_dir();
-        path.push(format!(
-            "rtyping-history-test-{}",
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .expect("system time should be after unix epoch")
-                .as_nanos()
-        ));
-        fs::create_dir_all(&path).expect("temp dir should be created");
-        path
+        assert_eq!(
+            report.damage,
+            vec![HistoryDamage {
+                file: HistoryFile::Legacy,
+                unreadable: Vec::new(),
+            }]
+        );
+        assert!(paths.legacy_path.exists());
+        assert!(!paths.log_path.exists());
+    }
+
+    #[test]
+    fn backups_rotate_through_three_slots_and_skip_unchanged_logs() {
+        let paths = paths_in(&tempfile_dir("history"));
+        for (id, wpm) in [("a", 1.0), ("b", 2.0), ("c", 3.0), ("d", 4.0)] {
+            append_history_to_path(&entry(id, wpm), &paths.log_path).expect("entry should append");
+            rotate_backups(&paths.log_path).expect("backup should rotate");
+            rotate_backups(&paths.log_path).expect("unchanged log should not rotate");
+        }
+
+        let backups = list_backups(&paths.log_path);
+
+        assert_eq!(
+            backups
+                .iter()
+                .map(|backup| (backup.slot, backup.entries))
+                .collect::<Vec<_>>(),
+            vec![(1, 4), (2, 3), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn shrunken_log_keeps_the_backups() {
+        let paths = paths_in(&tempfile_dir("history"));
+        for (id, wpm) in [("a", 1.0), ("b", 2.0), ("c", 3.0)] {
+            append_history_to_path(&entry(id, wpm), &paths.log_path).expect("entry should append");
+            rotate_backups(&paths.log_path).expect("backup should rotate");
+        }
+
+        fs::write(&paths.log_path, "").expect("log should be truncated");
+        rotate_backups(&paths.log_path).expect("shrunken log should not rotate");
+        append_history_to_path(&entry("d", 4.0), &paths.log_path).expect("entry should append");
+        rotate_backups(&paths.log_path).expect("replaced log should not rotate");
+
+        let backups = list_backups(&paths.log_path);
+
+        assert_eq!(
+            backups
+                .iter()
+                .map(|backup| (backup.slot, backup.entries))
+                .collect::<Vec<_>>(),
+            vec![(1, 3), (2, 2), (3, 1)]
+        );
+    }
+
+    #[test]
+    fn rewritten_log_becomes_the_newest_backup() {
+        let paths = paths_in(&tempfile_dir("history"));
+        append_history_to_path(&entry("a", 1.0), &paths.log_path).expect("entry should append");
+        rotate_backups(&paths.log_path).expect("backup should rotate");
+
+        save_history_to_path(&[entry("b", 2.0)], &paths.log_path).expect("log should be saved");
+        append_history_to_path(&entry("c", 3.0), &paths.log_path).expect("entry should append");
+        rotate_backups(&paths.log_path).expect("grown log should rotate");
+
+        assert_eq!(
+            list_backups(&paths.log_path)
+                .iter()
+                .map(|backup| (backup.slot, backup.entries))
+                .collect::<Vec<_>>(),
+            vec![(1, 2), (2, 1), (3, 1)]
+        );
+    }
+
+    #[test]
+    fn recovery_sets_damaged_files_aside() {
+        let dir = tempfile_dir("history");
+        let paths = paths_in(&dir);
+        append_history_to_path(&entry("a", 42.0), &paths.log_path).expect("entry should append");
+        rotate_backups(&paths.log_path).expect("backup should rotate");
+        fs::write(&paths.log_path, "oops\n").expect("damage should be written");
+        let damaged = load(&paths);
+
+        let restored =
+            restore_backup(&paths, 1, &damaged.damage).expect("backup should be restored");
+        assert_eq!(restored.entries, vec![entry("a", 42.0)]);
+        assert!(restored.damage.is_empty());
+
+        fs::write(&paths.log_path, "oops\n").expect("damage should be written");
+        let damaged = load(&paths);
+        keep_readable(&paths, &damaged.damage).expect("history should be kept");
+        assert_eq!(load(&paths), HistoryLoadReport::default());
+        let set_aside = fs::read_dir(&dir)
+            .expect("dir should be listed")
+            .filter_map(Result::ok)
+            .filter(|file| file.file_name().to_string_lossy().contains(".damaged-"))
+            .count();
+        assert!(set_aside >= 1);
+    }
+
+    #[test]
+    fn shared_logs_merge_by_id_in_time_order() {
+        let dir = tempfile_dir("history");
+        let paths = shared_paths_in(&dir);
+        let shared_dir = dir.join("shared");
+        append_history_to_path(&timed("a", 300), &paths.log_path).expect("entry should append");
+        append_history_to_path(&timed("b", 100), &paths.log_path).expect("entry should append");
+        let peer = shared_dir.join("history-peer.jsonl");
+        append_history_to_path(&timed("c", 200), &peer).expect("entry should append");
+        append_history_to_path(&timed("a", 300), &peer).expect("entry should append");
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&peer)
+            .and_then(|mut file| file.write_all(b"oops\n"))
+            .expect("damage should be written");
+        fs::copy(&peer, shared_dir.join("history-peer.jsonl.1")).expect("backup should copy");
+
+        let mut report = load(&paths);
+        merge_shared_logs(&mut report, &paths);
+
+        assert_eq!(ids(&report.entries), ["b", "c", "a"]);
+        assert!(report.damage.is_empty());
+        assert_eq!(
+            report.warnings,
+            ["history-peer.jsonl: 1 unreadable lines skipped"]
+        );
+    }
+
+    #[test]
+    fn shared_log_starts_as_a_copy_of_the_local_log() {
+        let dir = tempfile_dir("history");
+        let paths = shared_paths_in(&dir);
+        append_history_to_path(&timed("a", 100), &paths.local_log_path)
+            .expect("entry should append");
+
+        assert_eq!(ids(&load(&paths).entries), ["a"]);
+        append_history_to_path(&timed("b", 200), &paths.log_path).expect("entry should append");
+
+        assert_eq!(ids(&load(&paths).entries), ["a", "b"]);
+        assert_eq!(ids(&load(&paths_in(&dir)).entries), ["a"]);
     }
 }
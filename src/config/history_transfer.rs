@@ -0,0 +1,388 @@
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::domain::history::{
+    HISTORY_SCHEMA_VERSION, HistoryEntry, HistoryFormat, HistoryImportReport, HistoryMode,
+    RhythmRecord, TestLength,
+};
+use crate::domain::timestamp;
+
+use super::history_migration;
+
+/// Columns of a CSV export. `date` is for reading the file and is ignored on
+/// import.
+const CSV_COLUMNS: [&str; 14] = [
+    "id",
+    "timestamp",
+    "date",
+    "mode",
+    "test_kind",
+    "test_length",
+    "source",
+    "wpm",
+    "accuracy",
+    "misses",
+    "elapsed_seconds",
+    "failed",
+    "difficulty",
+    "adaptive_step",
+];
+
+/// Entries read from an export file.
+pub(super) struct ImportedHistory {
+    pub(super) entries: Vec<HistoryEntry>,
+    pub(super) skipped: usize,
+}
+
+pub(super) fn export_text(entries: &[HistoryEntry], format: HistoryFormat) -> io::Result<String> {
+    match format {
+        HistoryFormat::Csv => Ok(export_csv(entries)),
+        HistoryFormat::Json => serde_json::to_string_pretty(entries)
+            .map(|mut json| {
+                json.push('\n');
+                json
+            })
+            .map_err(|err| io::Error::other(format!("failed to serialize history: {err}"))),
+    }
+}
+
+fn export_csv(entries: &[HistoryEntry]) -> String {
+    let mut csv = CSV_COLUMNS.join(",");
+    csv.push('\n');
+    for entry in entries {
+        let row = csv_row(entry)
+            .iter()
+            .map(|field| quote_csv(field))
+            .collect::<Vec<_>>();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn csv_row(entry: &HistoryEntry) -> [String; 14] {
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    let (test_kind, test_length) = match entry.test {
+        Some(TestLength::Time(seconds)) => ("time", seconds.to_string()),
+        Some(TestLength::Words(words)) => ("words", words.to_string()),
+        Some(TestLength::Passage(scale)) => ("passage", scale.to_string()),
+        None => ("", String::new()),
+    };
+    [
+        entry.id.clone(),
+        optional(entry.timestamp.map(|seconds| seconds.to_string())),
+        optional(entry.timestamp.map(timestamp::format_date_time)),
+        entry.mode.label().to_string(),
+        test_kind.to_string(),
+        test_length,
+        entry.generation_source.clone(),
+        entry.wpm.to_string(),
+        entry.accuracy.to_string(),
+        entry.miss_count.to_string(),
+        entry.elapsed_seconds.to_string(),
+        entry.failed.to_string(),
+        optional(entry.difficulty.map(|difficulty| difficulty.to_string())),
+        optional(entry.adaptive_step.map(|step| step.to_string())),
+    ]
+}
+
+fn quote_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Reads the runs of an export. Runs without an ID cannot be deduplicated
+/// and are skipped with anything else that is not a run.
+pub(super) fn parse_import(text: &str, format: HistoryFormat) -> io::Result<ImportedHistory> {
+    let mut imported = match format {
+        HistoryFormat::Csv => parse_csv_import(text)?,
+        HistoryFormat::Json => parse_json_import(text)?,
+    };
+    let before = imported.entries.len();
+    imported.entries.retain(|entry| !entry.id.is_empty());
+    imported.skipped += before - imported.entries.len();
+    Ok(imported)
+}
+
+fn parse_json_import(text: &str) -> io::Result<ImportedHistory> {
+    let values = serde_json::from_str::<Vec<Value>>(text).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("expected a JSON array of runs: {err}"),
+        )
+    })?;
+    let migrated = history_migration::migrate_entries(values);
+    Ok(ImportedHistory {
+        entries: migrated.entries,
+        skipped: migrated.unreadable.len(),
+    })
+}
+
+/// CSV imports carry the summary columns only, so imported runs have no
+/// keystroke timeline or per-key counts. Columns are found by name.
+fn parse_csv_import(text: &str) -> io::Result<ImportedHistory> {
+    let mut records = parse_csv(text).into_iter();
+    let header = records.next().unwrap_or_default();
+    let columns = header
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (name.trim(), index))
+        .collect::<HashMap<_, _>>();
+    for required in ["id", "wpm", "accuracy"] {
+        if !columns.contains_key(required) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("CSV has no `{required}` column"),
+            ));
+        }
+    }
+
+    let mut imported = ImportedHistory {
+        entries: Vec::new(),
+        skipped: 0,
+    };
+    for record in records.filter(|record| record.iter().any(|field| !field.is_empty())) {
+        let field = |name: &str| {
+            columns
+                .get(name)
+                .and_then(|&index| record.get(index))
+                .map(|field| field.trim())
+                .filter(|field| !field.is_empty())
+        };
+        match entry_from_fields(field) {
+            Some(entry) => imported.entries.push(entry),
+            None => imported.skipped += 1,
+        }
+    }
+    Ok(imported)
+}
+
+fn entry_from_fields<'a>(field: impl Fn(&str) -> Option<&'a str>) -> Option<HistoryEntry> {
+    let mode = match field("mode").unwrap_or("timed") {
+        "timed" => HistoryMode::Timed,
+        "practice" => HistoryMode::Practice {
+            keys_practiced: Vec::new(),
+        },
+        "rhythm" => HistoryMode::Rhythm(RhythmRecord::default()),
+        _ => return None,
+    };
+    let test = match (field("test_kind"), field("test_length")) {
+        (Some("time"), Some(length)) => Some(TestLength::Time(length.parse().ok()?)),
+        (Some("words"), Some(length)) => Some(TestLength::Words(length.parse().ok()?)),
+        (Some("passage"), Some(length)) => Some(TestLength::Passage(length.parse().ok()?)),
+        (None, _) => None,
+        _ => return None,
+    };
+
+    Some(HistoryEntry {
+        schema_version: HISTORY_SCHEMA_VERSION,
+        id: field("id")?.to_string(),
+        timestamp: parse_field(field("timestamp")).ok()?,
+        wpm: field("wpm")?.parse().ok()?,
+        accuracy: field("accuracy")?.parse().ok()?,
+        miss_count: parse_field(field("misses")).ok()?.unwrap_or_default(),
+        elapsed_seconds: parse_field(field("elapsed_seconds"))
+            .ok()?
+            .unwrap_or_default(),
+        generation_source: field("source").unwrap_or_default().to_string(),
+        mode,
+        test,
+        failed: parse_field(field("failed")).ok()?.unwrap_or_default(),
+        difficulty: parse_field(field("difficulty")).ok()?,
+        adaptive_step: parse_field(field("adaptive_step")).ok()?,
+        ..HistoryEntry::default()
+    })
+}
+
+/// `Ok(None)` for an empty field.
+fn parse_field<T: FromStr>(field: Option<&str>) -> Result<Option<T>, T::Err> {
+    field.map(str::parse).transpose()
+}
+
+/// Splits CSV text into records, handling quoted fields with commas, doubled
+/// quotes, and line breaks.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(ch) = chars.next() {
+        match (quoted, ch) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (false, '"') => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(ch),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// Adds the imported runs whose IDs are not saved in `entries` or
+/// `elsewhere` yet, keeping `entries` ordered by time. Runs without a
+/// timestamp stay first, as the oldest.
+pub(super) fn merge_imported(
+    entries: &mut Vec<HistoryEntry>,
+    elsewhere: &[HistoryEntry],
+    imported: ImportedHistory,
+) -> HistoryImportReport {
+    let mut ids = entries
+        .iter()
+        .chain(elsewhere)
+        .map(|entry| entry.id.clone())
+        .collect::<BTreeSet<_>>();
+    let mut report = HistoryImportReport {
+        skipped: imported.skipped,
+        ..HistoryImportReport::default()
+    };
+    for entry in imported.entries {
+        if ids.insert(entry.id.clone()) {
+            entries.push(entry);
+            report.added += 1;
+        } else {
+            report.duplicates += 1;
+        }
+    }
+    if report.added > 0 {
+        entries.sort_by_key(|entry| entry.timestamp);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+
+    use super::*;
+
+    fn run(id: &str, timestamp: Option<u64>) -> HistoryEntry {
+        HistoryEntry {
+            schema_version: HISTORY_SCHEMA_VERSION,
+            id: id.to_string(),
+            timestamp,
+            wpm: 52.5,
+            accuracy: 97.25,
+            miss_count: 3,
+            elapsed_seconds: 30,
+            generation_source: "Local, \"quoted\"".to_string(),
+            test: Some(TestLength::Time(30)),
+            difficulty: Some(41.5),
+            missed_chars: vec!['e'],
+            ..HistoryEntry::default()
+        }
+    }
+
+    fn round_trip(entries: &[HistoryEntry], format: HistoryFormat) -> ImportedHistory {
+        let text = export_text(entries, format).expect("history should export");
+        parse_import(&text, format).expect("export should import")
+    }
+
+    #[test]
+    fn csv_export_has_a_header_and_quotes_fields() {
+        let csv = export_csv(&[run("00ff", Some(1_792_389_143))]);
+
+        assert_eq!(
+            csv,
+            "id,timestamp,date,mode,test_kind,test_length,source,wpm,accuracy,misses,elapsed_seconds,failed,difficulty,adaptive_step\n\
+             00ff,1792389143,2026-10-19 05:52,timed,time,30,\"Local, \"\"quoted\"\"\",52.5,97.25,3,30,false,41.5,\n"
+        );
+    }
+
+    #[test]
+    fn csv_round_trip_keeps_the_summary_columns() {
+        let practice = HistoryEntry {
+            mode: HistoryMode::Practice {
+                keys_practiced: vec!['a'],
+            },
+            test: None,
+            ..run("2", None)
+        };
+        let imported = round_trip(&[run("1", Some(60)), practice], HistoryFormat::Csv);
+
+        assert_eq!(imported.skipped, 0);
+        assert_eq!(imported.entries.len(), 2);
+        let timed = imported.entries.first().expect("timed run");
+        let practice = imported.entries.get(1).expect("practice run");
+        assert_eq!(
+            timed,
+            &HistoryEntry {
+                missed_chars: Vec::new(),
+                ..run("1", Some(60))
+            }
+        );
+        assert_eq!(practice.mode.label(), "practice");
+        assert_eq!(practice.test, None);
+        assert_eq!(practice.timestamp, None);
+    }
+
+    #[test]
+    fn json_round_trip_keeps_every_field() {
+        let entries = [run("1", Some(60)), run("2", None)];
+
+        let imported = round_trip(&entries, HistoryFormat::Json);
+
+        assert_eq!(imported.entries, entries);
+    }
+
+    #[test]
+    fn rows_that_are_not_runs_are_skipped() {
+        let csv =
+            "wpm,accuracy,id,mode\n40,90,a,timed\nfast,90,b,timed\n40,90,,timed\n40,90,c,race\n\n";
+
+        let imported = parse_import(csv, HistoryFormat::Csv).expect("CSV should import");
+
+        assert_eq!(imported.entries.len(), 1);
+        assert_eq!(imported.skipped, 3);
+        assert!(parse_import("id,wpm\n", HistoryFormat::Csv).is_err());
+        assert!(parse_import("{}", HistoryFormat::Json).is_err());
+    }
+
+    #[test]
+    fn merge_skips_saved_ids_and_orders_by_time() {
+        let mut entries = vec![run("old", None), run("b", Some(200))];
+        let imported = ImportedHistory {
+            entries: vec![
+                run("a", Some(100)),
+                run("b", Some(200)),
+                run("a", Some(100)),
+                run("peer", Some(50)),
+            ],
+            skipped: 1,
+        };
+
+        let report = merge_imported(&mut entries, &[run("peer", Some(50))], imported);
+
+        assert_eq!(
+            report,
+            HistoryImportReport {
+                added: 1,
+                duplicates: 3,
+                skipped: 1,
+            }
+        );
+        let ids = entries
+            .iter()
+            .map(|entry| entry.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["old", "a", "b"]);
+    }
+}
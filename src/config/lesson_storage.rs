@@ -0,0 +1,74 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::domain::lesson::{LessonLoadReport, LessonProgress};
+
+pub(super) fn load_lessons_from_path(lessons_path: &Path) -> io::Result<LessonLoadReport> {
+    if !lessons_path.exists() {
+        return Ok(LessonLoadReport::default());
+    }
+
+    let content = fs::read_to_string(lessons_path)?;
+    match serde_json::from_str::<LessonProgress>(&content) {
+        Ok(progress) => Ok(LessonLoadReport {
+            progress,
+            warnings: Vec::new(),
+        }),
+        Err(err) => Ok(LessonLoadReport {
+            progress: LessonProgress::default(),
+            warnings: vec![format!("Failed to load lesson progress: {err}")],
+        }),
+    }
+}
+
+pub(super) fn save_lessons_to_path(
+    progress: &LessonProgress,
+    lessons_path: &Path,
+) -> io::Result<()> {
+    if let Some(parent) = lessons_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let content = serde_json::to_string_pretty(progress)
+        .map_err(|err| io::Error::other(format!("failed to serialize lesson progress: {err}")))?;
+    fs::write(lessons_path, content)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+
+    use super::*;
+    use crate::config::test_support::tempfile_dir;
+    use crate::domain::lesson::CURRICULUM;
+
+    #[test]
+    fn save_and_load_lessons_round_trip() {
+        let dir = tempfile_dir("lessons");
+        let path = dir.join("nested").join("lessons.json");
+        let mut progress = LessonProgress::default();
+        let [home_row, ..] = CURRICULUM;
+        progress.record_run(&home_row, 20.0, 98.0);
+
+        save_lessons_to_path(&progress, &path).expect("lesson progress should save");
+        let report = load_lessons_from_path(&path).expect("lesson progress should load");
+
+        assert_eq!(report.progress, progress);
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn load_missing_or_broken_lessons_starts_from_scratch() {
+        let dir = tempfile_dir("lessons");
+        let path = dir.join("lessons.json");
+        let missing = load_lessons_from_path(&path).expect("missing progress should load");
+        assert_eq!(missing.progress, LessonProgress::default());
+        assert!(missing.warnings.is_empty());
+
+        fs::write(&path, "{").expect("broken progress should be written");
+        let broken = load_lessons_from_path(&path).expect("broken progress should not fail hard");
+        assert_eq!(broken.progress, LessonProgress::default());
+        assert_eq!(broken.warnings.len(), 1);
+    }
+}
@@ -1,17 +1,126 @@
+mod atomic_write;
 mod crypto;
+mod drill_storage;
+mod history_migration;
 mod history_storage;
+mod history_transfer;
+mod lesson_storage;
 mod paths;
+mod profile_storage;
 mod storage;
 
+#[cfg(test)]
+mod test_support;
 #[cfg(test)]
 mod tests;
 
+use std::fs;
 use std::io;
+use std::path::{Path, PathBuf};
 
 use crate::domain::config::{AppConfig, ConfigLoadReport};
-use crate::domain::history::{HistoryEntry, HistoryLoadReport};
+use crate::domain::drill::{DrillDeck, DrillLoadReport};
+use crate::domain::history::{
+    HistoryBackup, HistoryDamage, HistoryEntry, HistoryFormat, HistoryImportReport,
+    HistoryLoadReport,
+};
+use crate::domain::lesson::{LessonLoadReport, LessonProgress};
+use crate::domain::timestamp;
+
+/// Looks up an existing profile by name, for the `profile` the other
+/// functions read and write. `None` is the default profile in the config
+/// directory itself, which always exists; a missing named profile is an
+/// error rather than created, so a mistyped name does not start a new one.
+pub fn find_profile(profile: Option<&str>) -> io::Result<Option<String>> {
+    let Some(name) = profile else {
+        return Ok(None);
+    };
+    let name = profile_storage::checked_name(name)?;
+    if !paths::profiles_dir()?.join(name).is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no such profile `{name}`"),
+        ));
+    }
+    Ok(Some(name.to_string()))
+}
 
-pub fn load_config() -> io::Result<ConfigLoadReport> {
+pub fn list_profiles() -> io::Result<Vec<String>> {
+    profile_storage::list_profiles_in(&paths::profiles_dir()?)
+}
+
+pub fn create_profile(name: &str) -> io::Result<()> {
+    profile_storage::create_profile_in(&paths::profiles_dir()?, name)
+}
+
+/// Renames a profile's directory along with its logs in a shared history
+/// directory, so its runs from every machine follow it.
+pub fn rename_profile(from: &str, to: &str) -> io::Result<()> {
+    let from = profile_storage::checked_name(from)?;
+    let to = profile_storage::checked_name(to)?;
+    let profiles_dir = paths::profiles_dir()?;
+    let history_path = load_config(Some(from))?.config.game.history_path;
+    let Some(shared_dir) = paths::existing_history_paths(Some(from), &history_path)?.shared_dir
+    else {
+        return profile_storage::rename_profile_in(&profiles_dir, from, to);
+    };
+
+    // The logs are renamed first, since a relative history directory moves
+    // along with the profile's directory.
+    profile_storage::rename_shared_logs_in(&shared_dir, from, to)?;
+    if let Err(err) = profile_storage::rename_profile_in(&profiles_dir, from, to) {
+        profile_storage::rename_shared_logs_in(&shared_dir, to, from)?;
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Removes a profile with its settings and history, including its logs in
+/// a shared history directory. Callers keep the profile in use from being
+/// deleted.
+pub fn delete_profile(name: &str) -> io::Result<()> {
+    let name = profile_storage::checked_name(name)?;
+    let history_path = load_config(Some(name))?.config.game.history_path;
+    if let Some(dir) = paths::existing_history_paths(Some(name), &history_path)?.shared_dir {
+        profile_storage::delete_shared_logs_in(&dir, name)?;
+    }
+    profile_storage::delete_profile_in(&paths::profiles_dir()?, name)
+}
+
+/// Loads `profile`'s config. Provider fields a profile leaves empty come
+/// from the config directory's own config, so API keys are shared unless a
+/// profile sets its own.
+pub fn load_config(profile: Option<&str>) -> io::Result<ConfigLoadReport> {
+    let shared = load_shared_config()?;
+    let Some(paths) = paths::profile_config_paths(profile)? else {
+        return Ok(shared);
+    };
+
+    let mut report = storage::load_config_from_paths(&paths.config_path, &paths.key_path)?;
+    report.config.google = report.config.google.with_shared(&shared.config.google);
+    report.config.groq = report.config.groq.with_shared(&shared.config.groq);
+    report.warnings.extend(shared.warnings);
+    Ok(report)
+}
+
+/// Saves `profile`'s config. A profile only keeps the provider fields that
+/// differ from the shared ones.
+pub fn save_config(profile: Option<&str>, config: &AppConfig) -> io::Result<()> {
+    let Some(paths) = paths::profile_config_paths(profile)? else {
+        let paths = paths::config_paths()?;
+        return storage::save_config_to_paths(config, &paths.config_path, &paths.key_path);
+    };
+
+    let shared = load_shared_config()?.config;
+    let profile_config = AppConfig {
+        google: config.google.overrides_of(&shared.google),
+        groq: config.groq.overrides_of(&shared.groq),
+        game: config.game.clone(),
+    };
+    storage::save_config_to_paths(&profile_config, &paths.config_path, &paths.key_path)
+}
+
+fn load_shared_config() -> io::Result<ConfigLoadReport> {
     let paths = paths::config_paths()?;
     if paths.config_path.exists() {
         return storage::load_config_from_paths(&paths.config_path, &paths.key_path);
@@ -29,17 +138,148 @@ pub fn load_config() -> io::Result<ConfigLoadReport> {
     storage::load_config_from_paths(&paths.config_path, &paths.key_path)
 }
 
-pub fn save_config(config: &AppConfig) -> io::Result<()> {
-    let paths = paths::config_paths()?;
-    storage::save_config_to_paths(config, &paths.config_path, &paths.key_path)
+/// Loads history and, when it read cleanly, backs up the log. With a shared
+/// `history_path`, the logs of the other machines there are merged in.
+pub fn load_history(profile: Option<&str>, history_path: &str) -> io::Result<HistoryLoadReport> {
+    let paths = paths::history_paths(profile, history_path)?;
+    let mut report = history_storage::load_history_from_paths(&paths)?;
+    if report.damage.is_empty()
+        && let Err(err) = history_storage::rotate_backups(&paths.log_path)
+    {
+        report
+            .warnings
+            .push(format!("Failed to back up history: {err}"));
+    }
+    history_storage::merge_shared_logs(&mut report, &paths);
+    Ok(report)
+}
+
+/// Reads the same history as [`load_history`] without changing any file,
+/// for commands that only look at it.
+pub fn read_history(profile: Option<&str>, history_path: &str) -> io::Result<HistoryLoadReport> {
+    let paths = paths::existing_history_paths(profile, history_path)?;
+    let mut report = history_storage::read_history_from_paths(&paths)?;
+    history_storage::merge_shared_logs(&mut report, &paths);
+    Ok(report)
+}
+
+pub fn append_history(
+    profile: Option<&str>,
+    history_path: &str,
+    entry: &HistoryEntry,
+) -> io::Result<()> {
+    let paths = paths::history_paths(profile, history_path)?;
+    history_storage::append_history_to_path(entry, &paths.log_path)
+}
+
+pub fn history_backups(
+    profile: Option<&str>,
+    history_path: &str,
+) -> io::Result<Vec<HistoryBackup>> {
+    let paths = paths::history_paths(profile, history_path)?;
+    Ok(history_storage::list_backups(&paths.log_path))
+}
+
+pub fn restore_history_backup(
+    profile: Option<&str>,
+    history_path: &str,
+    slot: usize,
+    damage: &[HistoryDamage],
+) -> io::Result<HistoryLoadReport> {
+    let paths = paths::history_paths(profile, history_path)?;
+    let mut report = history_storage::restore_backup(&paths, slot, damage)?;
+    history_storage::merge_shared_logs(&mut report, &paths);
+    Ok(report)
+}
+
+pub fn keep_readable_history(
+    profile: Option<&str>,
+    history_path: &str,
+    damage: &[HistoryDamage],
+) -> io::Result<()> {
+    let paths = paths::history_paths(profile, history_path)?;
+    history_storage::keep_readable(&paths, damage)
+}
+
+/// Text of `entries` in an export format.
+pub fn export_history_text(entries: &[HistoryEntry], format: HistoryFormat) -> io::Result<String> {
+    history_transfer::export_text(entries, format)
+}
+
+/// Writes `entries` to a new file in `profile`'s `exports` directory,
+/// named after the current time, and returns its path.
+pub fn export_history(
+    profile: Option<&str>,
+    entries: &[HistoryEntry],
+    format: HistoryFormat,
+    now: u64,
+) -> io::Result<PathBuf> {
+    let dir = paths::exports_dir(profile)?;
+    fs::create_dir_all(&dir)?;
+    let minutes = now % timestamp::SECONDS_PER_DAY / 60;
+    let path = dir.join(format!(
+        "history-{}-{:02}{:02}{:02}.{}",
+        timestamp::format_date(timestamp::day_number(now)),
+        minutes / 60,
+        minutes % 60,
+        now % 60,
+        format.extension()
+    ));
+    write_history_export(entries, format, &path)?;
+    Ok(path)
+}
+
+/// Writes `entries` to `path` in an export format, replacing the file whole.
+pub fn write_history_export(
+    entries: &[HistoryEntry],
+    format: HistoryFormat,
+    path: &Path,
+) -> io::Result<()> {
+    atomic_write::write_atomic(path, export_history_text(entries, format)?.as_bytes())
+}
+
+/// Adds the runs of an export file to this machine's log, skipping IDs that
+/// are already saved in it or in a shared history directory. History that
+/// cannot be read in full is left alone until it has been recovered.
+pub fn import_history(
+    profile: Option<&str>,
+    history_path: &str,
+    path: &Path,
+    format: HistoryFormat,
+) -> io::Result<HistoryImportReport> {
+    let imported = history_transfer::parse_import(&fs::read_to_string(path)?, format)?;
+    let paths = paths::history_paths(profile, history_path)?;
+    let mut history = history_storage::load_history_from_paths(&paths)?;
+    if !history.damage.is_empty() {
+        return Err(io::Error::other(
+            "saved history could not be read in full; start rtyping to recover it first",
+        ));
+    }
+
+    let shared = history_storage::read_shared_logs(&paths);
+    let report = history_transfer::merge_imported(&mut history.entries, &shared.entries, imported);
+    if report.added > 0 {
+        history_storage::save_history_to_path(&history.entries, &paths.log_path)?;
+    }
+    Ok(report)
+}
+
+pub fn load_drills(profile: Option<&str>) -> io::Result<DrillLoadReport> {
+    let drills_path = paths::drills_path(profile)?;
+    drill_storage::load_drills_from_path(&drills_path)
+}
+
+pub fn save_drills(profile: Option<&str>, deck: &DrillDeck) -> io::Result<()> {
+    let drills_path = paths::drills_path(profile)?;
+    drill_storage::save_drills_to_path(deck, &drills_path)
 }
 
-pub fn load_history() -> io::Result<HistoryLoadReport> {
-    let history_path = paths::history_path()?;
-    history_storage::load_history_from_path(&history_path)
+pub fn load_lessons(profile: Option<&str>) -> io::Result<LessonLoadReport> {
+    let lessons_path = paths::lessons_path(profile)?;
+    lesson_storage::load_lessons_from_path(&lessons_path)
 }
 
-pub fn save_history(entries: &[HistoryEntry]) -> io::Result<()> {
-    let history_path = paths::history_path()?;
-    history_storage::save_history_to_path(entries, &history_path)
+pub fn save_lessons(profile: Option<&str>, progress: &LessonProgress) -> io::Result<()> {
+    let lessons_path = paths::lessons_path(profile)?;
+    lesson_storage::save_lessons_to_path(progress, &lessons_path)
 }
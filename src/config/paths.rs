@@ -1,12 +1,34 @@
 use std::env;
+use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use rand::RngExt;
+
+use crate::domain::history::HistoryFile;
+
+use super::atomic_write::write_atomic;
+
+pub(super) fn profiles_dir() -> io::Result<PathBuf> {
+    Ok(preferred_config_base_dir()?.join("profiles"))
+}
+
+/// Where `profile` keeps its settings and history. The default profile,
+/// `None`, uses the config directory itself.
+fn profile_base_dir(profile: Option<&str>) -> io::Result<PathBuf> {
+    match profile {
+        Some(profile) => Ok(profiles_dir()?.join(profile)),
+        None => preferred_config_base_dir(),
+    }
+}
 
 pub(super) struct ConfigPaths {
     pub config_path: PathBuf,
     pub key_path: PathBuf,
 }
 
+/// The config in the config directory itself: the default profile's, and
+/// the provider settings every profile shares.
 pub(super) fn config_paths() -> io::Result<ConfigPaths> {
     let base_dir = preferred_config_base_dir()?;
     Ok(ConfigPaths {
@@ -15,8 +37,195 @@ pub(super) fn config_paths() -> io::Result<ConfigPaths> {
     })
 }
 
-pub(super) fn history_path() -> io::Result<PathBuf> {
-    Ok(preferred_config_base_dir()?.join("history.json"))
+/// A named profile's own config, encrypted with the shared key, or `None`
+/// for the default profile.
+pub(super) fn profile_config_paths(profile: Option<&str>) -> io::Result<Option<ConfigPaths>> {
+    let Some(profile) = profile else {
+        return Ok(None);
+    };
+    Ok(Some(ConfigPaths {
+        config_path: profiles_dir()?.join(profile).join("config.json"),
+        key_path: config_paths()?.key_path,
+    }))
+}
+
+pub(super) struct HistoryPaths {
+    /// The log this machine appends to.
+    pub log_path: PathBuf,
+    pub legacy_path: PathBuf,
+    /// `history.jsonl` next to the config. It is the log unless history is
+    /// shared, in which case it seeds this machine's log.
+    pub local_log_path: PathBuf,
+    /// Directory shared with other machines, whose logs are merged on load.
+    pub shared_dir: Option<PathBuf>,
+    /// Profile whose logs in the shared directory are this history's, or
+    /// `None` for the default profile.
+    pub profile: Option<String>,
+}
+
+impl HistoryPaths {
+    pub fn path(&self, file: HistoryFile) -> &Path {
+        match file {
+            HistoryFile::Log => &self.log_path,
+            HistoryFile::Legacy => &self.legacy_path,
+        }
+    }
+}
+
+/// History files of `profile` for the `history_path` setting.
+/// When it names a directory, each machine writes its own
+/// `history-<machine id>.jsonl` there, or `history-<machine id>-<profile>.jsonl`
+/// for a named profile. `~/` is the home directory and relative paths start
+/// from the profile's directory.
+pub(super) fn history_paths(profile: Option<&str>, history_path: &str) -> io::Result<HistoryPaths> {
+    history_paths_with(profile, history_path, |base_dir| {
+        machine_id(base_dir).map(Some)
+    })
+}
+
+/// Like [`history_paths`], but without creating a machine ID. Before one
+/// exists this machine has no shared log, so the local log is read instead.
+pub(super) fn existing_history_paths(
+    profile: Option<&str>,
+    history_path: &str,
+) -> io::Result<HistoryPaths> {
+    history_paths_with(profile, history_path, |base_dir| {
+        Ok(read_machine_id(base_dir))
+    })
+}
+
+fn history_paths_with(
+    profile: Option<&str>,
+    history_path: &str,
+    machine_id: impl FnOnce(&Path) -> io::Result<Option<String>>,
+) -> io::Result<HistoryPaths> {
+    let base_dir = profile_base_dir(profile)?;
+    let local_log_path = base_dir.join(HistoryFile::Log.file_name());
+    let legacy_path = base_dir.join(HistoryFile::Legacy.file_name());
+    let Some(shared_dir) = shared_history_dir(&base_dir, history_path) else {
+        return Ok(HistoryPaths {
+            log_path: local_log_path.clone(),
+            legacy_path,
+            local_log_path,
+            shared_dir: None,
+            profile: profile.map(str::to_string),
+        });
+    };
+
+    let log_path = match machine_id(&preferred_config_base_dir()?)? {
+        Some(id) => shared_dir.join(shared_log_name(&id, profile)),
+        None => local_log_path.clone(),
+    };
+    Ok(HistoryPaths {
+        log_path,
+        legacy_path,
+        local_log_path,
+        shared_dir: Some(shared_dir),
+        profile: profile.map(str::to_string),
+    })
+}
+
+fn shared_history_dir(base_dir: &Path, history_path: &str) -> Option<PathBuf> {
+    let history_path = history_path.trim();
+    if history_path.is_empty() {
+        return None;
+    }
+    let home_relative = history_path
+        .strip_prefix("~/")
+        .or_else(|| (history_path == "~").then_some(""));
+    if let Some(rest) = home_relative
+        && let Some(home) = dirs::home_dir()
+    {
+        return Some(home.join(rest));
+    }
+    Some(base_dir.join(history_path))
+}
+
+/// Name of a machine's log for `profile` in a shared history directory.
+pub(super) fn shared_log_name(machine_id: &str, profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("history-{machine_id}-{profile}.jsonl"),
+        None => format!("history-{machine_id}.jsonl"),
+    }
+}
+
+/// Whether a file in a shared history directory is a machine's log for
+/// `profile`, rather than another profile's log, a backup or a file set
+/// aside. Machine IDs have no `-`, so the profile starts after the first one.
+pub(super) fn is_shared_log_name(name: &str, profile: Option<&str>) -> bool {
+    let Some(rest) = name
+        .strip_prefix("history-")
+        .and_then(|rest| rest.strip_suffix(".jsonl"))
+    else {
+        return false;
+    };
+    let (machine_id, log_profile) = match rest.split_once('-') {
+        Some((machine_id, log_profile)) => (machine_id, Some(log_profile)),
+        None => (rest, None),
+    };
+    is_machine_id(machine_id) && log_profile == profile
+}
+
+/// Splits a file of a named profile's logs in a shared history directory,
+/// that is a machine's log or a backup or set-aside copy of one, into the
+/// machine ID and what follows the log's name. `None` for any other file.
+pub(super) fn split_profile_log_file<'a>(
+    name: &'a str,
+    profile: &str,
+) -> Option<(&'a str, &'a str)> {
+    let (machine_id, rest) = name.strip_prefix("history-")?.split_once('-')?;
+    let suffix = rest.strip_prefix(profile)?.strip_prefix(".jsonl")?;
+    (is_machine_id(machine_id) && (suffix.is_empty() || is_log_copy_suffix(suffix)))
+        .then_some((machine_id, suffix))
+}
+
+/// Whether `suffix` marks a copy of a log: `.<slot>` for a backup or
+/// `.<reason>-<seconds>` for a log set aside.
+fn is_log_copy_suffix(suffix: &str) -> bool {
+    let Some(copy) = suffix.strip_prefix('.') else {
+        return false;
+    };
+    let stamp = match copy.split_once('-') {
+        Some((reason, stamp)) if reason.chars().all(|ch| ch.is_ascii_lowercase()) => stamp,
+        Some(_) => return false,
+        None => copy,
+    };
+    !stamp.is_empty() && stamp.chars().all(|ch| ch.is_ascii_digit())
+}
+
+/// Random ID of this machine, kept in `machine-id` in the config directory
+/// so it is not shared with other machines. Every profile uses the same ID;
+/// the profile name in the log name keeps their logs apart.
+fn machine_id(base_dir: &Path) -> io::Result<String> {
+    if let Some(id) = read_machine_id(base_dir) {
+        return Ok(id);
+    }
+
+    let id = format!("{:016x}", rand::rng().random::<u64>());
+    fs::create_dir_all(base_dir)?;
+    write_atomic(&base_dir.join("machine-id"), format!("{id}\n").as_bytes())?;
+    Ok(id)
+}
+
+fn read_machine_id(base_dir: &Path) -> Option<String> {
+    let id = fs::read_to_string(base_dir.join("machine-id")).ok()?;
+    is_machine_id(id.trim()).then(|| id.trim().to_string())
+}
+
+fn is_machine_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|ch| ch.is_ascii_alphanumeric())
+}
+
+pub(super) fn drills_path(profile: Option<&str>) -> io::Result<PathBuf> {
+    Ok(profile_base_dir(profile)?.join("drills.json"))
+}
+
+pub(super) fn lessons_path(profile: Option<&str>) -> io::Result<PathBuf> {
+    Ok(profile_base_dir(profile)?.join("lessons.json"))
+}
+
+pub(super) fn exports_dir(profile: Option<&str>) -> io::Result<PathBuf> {
+    Ok(profile_base_dir(profile)?.join("exports"))
 }
 
 pub(super) fn alternate_config_paths() -> io::Result<Option<ConfigPaths>> {
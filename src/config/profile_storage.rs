@@ -0,0 +1,223 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::domain::profile;
+
+use super::paths::{shared_log_name, split_profile_log_file};
+
+/// Names of the profile directories in `profiles_dir`, sorted. Entries
+/// that are not valid profile names are left out.
+pub(super) fn list_profiles_in(profiles_dir: &Path) -> io::Result<Vec<String>> {
+    let entries = match fs::read_dir(profiles_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(name) = entry.file_name().to_str()
+            && profile::validate_name(name) == Ok(name)
+        {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+pub(super) fn create_profile_in(profiles_dir: &Path, name: &str) -> io::Result<()> {
+    let name = checked_name(name)?;
+    fs::create_dir_all(profiles_dir)?;
+    fs::create_dir(profiles_dir.join(name)).map_err(|err| already_exists(err, name))
+}
+
+pub(super) fn rename_profile_in(profiles_dir: &Path, from: &str, to: &str) -> io::Result<()> {
+    let from = checked_name(from)?;
+    let to = checked_name(to)?;
+    let target = profiles_dir.join(to);
+    if target.exists() {
+        return Err(already_exists(io::ErrorKind::AlreadyExists.into(), to));
+    }
+    fs::rename(profiles_dir.join(from), target)
+}
+
+/// Removes a profile's directory with its settings, history and progress.
+pub(super) fn delete_profile_in(profiles_dir: &Path, name: &str) -> io::Result<()> {
+    fs::remove_dir_all(profiles_dir.join(checked_name(name)?))
+}
+
+/// Renames the logs `from` keeps in a shared history directory, those of
+/// every machine with their backups, so a renamed profile keeps its runs.
+/// Nothing is renamed when `to` already has logs there.
+pub(super) fn rename_shared_logs_in(shared_dir: &Path, from: &str, to: &str) -> io::Result<()> {
+    if !shared_log_files(shared_dir, to)?.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "profile `{to}` already has history in {}",
+                shared_dir.display()
+            ),
+        ));
+    }
+    for (name, machine_id, suffix) in shared_log_files(shared_dir, from)? {
+        let renamed = format!("{}{suffix}", shared_log_name(&machine_id, Some(to)));
+        fs::rename(shared_dir.join(name), shared_dir.join(renamed))?;
+    }
+    Ok(())
+}
+
+/// Removes the logs `name` keeps in a shared history directory, those of
+/// every machine with their backups, so a later profile of the same name
+/// starts without them.
+pub(super) fn delete_shared_logs_in(shared_dir: &Path, name: &str) -> io::Result<()> {
+    for (file, ..) in shared_log_files(shared_dir, name)? {
+        fs::remove_file(shared_dir.join(file))?;
+    }
+    Ok(())
+}
+
+/// Names of the files of `profile`'s logs in a shared history directory,
+/// with the machine ID and the suffix after the log's name of each.
+fn shared_log_files(shared_dir: &Path, profile: &str) -> io::Result<Vec<(String, String, String)>> {
+    let entries = match fs::read_dir(shared_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if let Some(name) = entry.file_name().to_str()
+            && let Some((machine_id, suffix)) = split_profile_log_file(name, profile)
+        {
+            files.push((name.to_string(), machine_id.to_string(), suffix.to_string()));
+        }
+    }
+    Ok(files)
+}
+
+pub(super) fn checked_name(name: &str) -> io::Result<&str> {
+    profile::validate_name(name)
+        .map_err(|message| io::Error::new(io::ErrorKind::InvalidInput, message))
+}
+
+fn already_exists(err: io::Error, name: &str) -> io::Error {
+    if err.kind() == io::ErrorKind::AlreadyExists {
+        io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("profile `{name}` already exists"),
+        )
+    } else {
+        err
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+
+    use super::*;
+    use crate::config::test_support::tempfile_dir;
+
+    #[test]
+    fn profiles_are_created_renamed_and_deleted_as_directories() {
+        let dir = tempfile_dir("profiles");
+        let profiles_dir = dir.join("profiles");
+        assert!(
+            list_profiles_in(&profiles_dir)
+                .expect("missing directory should list")
+                .is_empty()
+        );
+
+        create_profile_in(&profiles_dir, "bob").expect("profile should be created");
+        create_profile_in(&profiles_dir, " alice ").expect("profile should be created");
+        fs::write(profiles_dir.join("notes.txt"), "").expect("stray file should be written");
+        fs::create_dir(profiles_dir.join(".cache")).expect("stray dir should be created");
+        assert!(create_profile_in(&profiles_dir, "bob").is_err());
+        assert!(create_profile_in(&profiles_dir, "../escape").is_err());
+        assert_eq!(
+            list_profiles_in(&profiles_dir).expect("profiles should list"),
+            ["alice", "bob"]
+        );
+
+        fs::write(profiles_dir.join("bob").join("history.jsonl"), "{}\n")
+            .expect("history should be written");
+        assert!(rename_profile_in(&profiles_dir, "bob", "alice").is_err());
+        rename_profile_in(&profiles_dir, "bob", "carol").expect("profile should be renamed");
+        assert!(profiles_dir.join("carol").join("history.jsonl").exists());
+
+        delete_profile_in(&profiles_dir, "carol").expect("profile should be deleted");
+        assert_eq!(
+            list_profiles_in(&profiles_dir).expect("profiles should list"),
+            ["alice"]
+        );
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn shared_logs_of_every_machine_follow_the_profile() {
+        let dir = tempfile_dir("profiles");
+        for name in [
+            "history-abc-bob.jsonl",
+            "history-abc-bob.jsonl.1",
+            "history-abc-bob.jsonl.damaged-1700000000",
+            "history-def-bob.jsonl",
+            "history-abc-bob.2.jsonl",
+            "history-abc.jsonl",
+            "notes-bob.jsonl",
+        ] {
+            fs::write(dir.join(name), "").expect("log should be written");
+        }
+        let names = || {
+            let mut names = fs::read_dir(&dir)
+                .expect("shared dir should list")
+                .map(|entry| {
+                    entry
+                        .expect("entry should read")
+                        .file_name()
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .collect::<Vec<_>>();
+            names.sort();
+            names
+        };
+
+        rename_shared_logs_in(&dir, "bob", "carol").expect("logs should be renamed");
+        assert_eq!(
+            names(),
+            [
+                "history-abc-bob.2.jsonl",
+                "history-abc-carol.jsonl",
+                "history-abc-carol.jsonl.1",
+                "history-abc-carol.jsonl.damaged-1700000000",
+                "history-abc.jsonl",
+                "history-def-carol.jsonl",
+                "notes-bob.jsonl",
+            ]
+        );
+        assert!(rename_shared_logs_in(&dir, "bob.2", "carol").is_err());
+        assert!(dir.join("history-abc-bob.2.jsonl").exists());
+
+        delete_shared_logs_in(&dir, "carol").expect("logs should be deleted");
+        assert_eq!(
+            names(),
+            [
+                "history-abc-bob.2.jsonl",
+                "history-abc.jsonl",
+                "notes-bob.jsonl"
+            ]
+        );
+        delete_shared_logs_in(&dir.join("missing"), "carol").expect("missing dir has no logs");
+
+        fs::remove_dir_all(dir).ok();
+    }
+}
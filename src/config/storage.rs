@@ -29,25 +29,76 @@ struct StoredProviderConfig {
 struct StoredGameSettings {
     #[serde(default = "default_timeout")]
     timeout: String,
+    #[serde(default = "default_test_mode")]
+    test_mode: String,
+    #[serde(default = "default_word_count")]
+    word_count: String,
+    #[serde(default = "default_correction")]
+    correction: String,
+    #[serde(default = "default_fail_accuracy")]
+    fail_accuracy: String,
+    #[serde(default = "default_space_skip")]
+    space_skip: String,
+    #[serde(default = "default_layout")]
+    layout: String,
     #[serde(default = "default_text_scale")]
     text_scale: String,
+    #[serde(default = "default_disabled")]
+    lowercase_only: String,
+    #[serde(default = "default_enabled")]
+    punctuation: String,
+    #[serde(default = "default_disabled")]
+    numbers: String,
+    #[serde(default = "default_disabled")]
+    symbols: String,
     #[serde(default = "default_rhythm_speed")]
     rhythm_speed: String,
+    #[serde(default = "default_adaptive")]
+    adaptive: String,
     #[serde(default = "default_freq")]
     freq: String,
     #[serde(default = "default_sound_enabled")]
     sound_enabled: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    history_path: String,
 }
 
 fn default_timeout() -> String {
     "60".to_string()
 }
+fn default_test_mode() -> String {
+    "time".to_string()
+}
+fn default_word_count() -> String {
+    "25".to_string()
+}
+fn default_correction() -> String {
+    "normal".to_string()
+}
+fn default_fail_accuracy() -> String {
+    "100".to_string()
+}
+fn default_space_skip() -> String {
+    "false".to_string()
+}
+fn default_layout() -> String {
+    "qwerty".to_string()
+}
 fn default_text_scale() -> String {
     "60".to_string()
 }
+fn default_disabled() -> String {
+    "false".to_string()
+}
+fn default_enabled() -> String {
+    "true".to_string()
+}
 fn default_rhythm_speed() -> String {
     "2".to_string()
 }
+fn default_adaptive() -> String {
+    "false".to_string()
+}
 fn default_freq() -> String {
     "80.0".to_string()
 }
@@ -59,10 +110,22 @@ impl Default for StoredGameSettings {
     fn default() -> Self {
         Self {
             timeout: default_timeout(),
+            test_mode: default_test_mode(),
+            word_count: default_word_count(),
+            correction: default_correction(),
+            fail_accuracy: default_fail_accuracy(),
+            space_skip: default_space_skip(),
+            layout: default_layout(),
             text_scale: default_text_scale(),
+            lowercase_only: default_disabled(),
+            punctuation: default_enabled(),
+            numbers: default_disabled(),
+            symbols: default_disabled(),
             rhythm_speed: default_rhythm_speed(),
+            adaptive: default_adaptive(),
             freq: default_freq(),
             sound_enabled: default_sound_enabled(),
+            history_path: String::new(),
         }
     }
 }
@@ -119,10 +182,22 @@ pub(super) fn load_config_from_paths(
             groq,
             game: GameSettings {
                 timeout: stored.game.timeout.clone(),
+                test_mode: stored.game.test_mode.clone(),
+                word_count: stored.game.word_count.clone(),
+                correction: stored.game.correction.clone(),
+                fail_accuracy: stored.game.fail_accuracy.clone(),
+                space_skip: stored.game.space_skip.clone(),
+                layout: stored.game.layout.clone(),
                 text_scale: stored.game.text_scale.clone(),
+                lowercase_only: stored.game.lowercase_only.clone(),
+                punctuation: stored.game.punctuation.clone(),
+                numbers: stored.game.numbers.clone(),
+                symbols: stored.game.symbols.clone(),
                 rhythm_speed: stored.game.rhythm_speed.clone(),
+                adaptive: stored.game.adaptive.clone(),
                 freq: stored.game.freq.clone(),
                 sound_enabled: stored.game.sound_enabled.clone(),
+                history_path: stored.game.history_path.clone(),
             },
         },
         warnings,
@@ -144,10 +219,22 @@ pub(super) fn save_config_to_paths(
         groq: store_provider_config(&config.groq, &key, "groq")?,
         game: StoredGameSettings {
             timeout: config.game.timeout.clone(),
+            test_mode: config.game.test_mode.clone(),
+            word_count: config.game.word_count.clone(),
+            correction: config.game.correction.clone(),
+            fail_accuracy: config.game.fail_accuracy.clone(),
+            space_skip: config.game.space_skip.clone(),
+            layout: config.game.layout.clone(),
             text_scale: config.game.text_scale.clone(),
+            lowercase_only: config.game.lowercase_only.clone(),
+            punctuation: config.game.punctuation.clone(),
+            numbers: config.game.numbers.clone(),
+            symbols: config.game.symbols.clone(),
             rhythm_speed: config.game.rhythm_speed.clone(),
+            adaptive: config.game.adaptive.clone(),
             freq: config.game.freq.clone(),
             sound_enabled: config.game.sound_enabled.clone(),
+            history_path: config.game.history_path.clone(),
         },
     };
     let body = serde_json::to_string_pretty(&stored)
@@ -0,0 +1,20 @@
+#![expect(clippy::expect_used)]
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Creates a fresh directory under the system temp dir for one test, named
+/// after `name` so leftovers show which tests wrote them.
+pub(super) fn tempfile_dir(name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!(
+        "rtyping-{name}-test-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time should be after unix epoch")
+            .as_nanos()
+    ));
+    fs::create_dir_all(&path).expect("temp dir should be created");
+    path
+}
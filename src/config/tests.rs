@@ -1,9 +1,13 @@
 #![expect(clippy::expect_used)]
 
-use super::paths::alternate_config_paths;
+use super::paths::{alternate_config_paths, history_paths, is_shared_log_name};
 use super::storage::{load_config_from_paths, save_config_to_paths, test_support};
-use super::{load_config, save_config};
+use super::{
+    append_history, create_profile, delete_profile, find_profile, list_profiles, load_config,
+    load_history, read_history, rename_profile, save_config,
+};
 use crate::domain::config::{AppConfig, GameSettings, ProviderConfig};
+use crate::domain::history::{HISTORY_SCHEMA_VERSION, HistoryEntry};
 use rand::RngExt;
 use std::env;
 use std::fs;
@@ -174,7 +178,7 @@ fn save_config_uses_xdg_config_home() {
     let env_sandbox = EnvSandbox::new();
     let config = sample_config();
 
-    save_config(&config).expect("save should succeed");
+    save_config(None, &config).expect("save should succeed");
 
     let config_path = env_sandbox.preferred_dir().join("config.json");
     let key_path = env_sandbox.preferred_dir().join("config.key");
@@ -226,7 +230,7 @@ fn load_config_prefers_xdg_path_over_legacy_path() {
         .expect("legacy config should be saved");
     }
 
-    let report = load_config().expect("load should succeed");
+    let report = load_config(None).expect("load should succeed");
     assert_eq!(report.config, preferred);
 }
 
@@ -251,10 +255,10 @@ fn load_config_falls_back_to_legacy_path_when_preferred_is_missing() {
         )
         .expect("legacy config should be saved");
 
-        let report = load_config().expect("load should succeed");
+        let report = load_config(None).expect("load should succeed");
         assert_eq!(report.config, legacy);
     } else {
-        let report = load_config().expect("load should succeed");
+        let report = load_config(None).expect("load should succeed");
         assert_eq!(report.config, AppConfig::default());
     }
 }
@@ -351,3 +355,209 @@ fn alternate_path_is_optional_and_distinct_when_present() {
         );
     }
 }
+
+#[test]
+fn shared_history_path_gives_each_machine_its_own_log() {
+    let env_sandbox = EnvSandbox::new();
+    let base = env_sandbox.preferred_dir();
+
+    let local = history_paths(None, "  ").expect("history paths should resolve");
+    assert_eq!(local.log_path, base.join("history.jsonl"));
+    assert_eq!(local.shared_dir, None);
+
+    let shared = history_paths(None, "~/Sync/rtyping").expect("history paths should resolve");
+    let shared_dir = env_sandbox.root.join("home").join("Sync").join("rtyping");
+    assert_eq!(shared.shared_dir.as_deref(), Some(shared_dir.as_path()));
+    let log_name = shared
+        .log_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .expect("log should have a name");
+    assert!(is_shared_log_name(log_name, None));
+    assert!(shared.log_path.starts_with(&shared_dir));
+    assert_eq!(shared.local_log_path, base.join("history.jsonl"));
+
+    let again = history_paths(None, "~/Sync/rtyping").expect("history paths should resolve");
+    assert_eq!(again.log_path, shared.log_path);
+    let relative = history_paths(None, "synced").expect("history paths should resolve");
+    assert_eq!(relative.shared_dir, Some(base.join("synced")));
+    assert!(!is_shared_log_name("history-abc.jsonl.1", None));
+    assert!(!is_shared_log_name("history-.jsonl", None));
+    assert!(!is_shared_log_name("history-abc-alice.jsonl", None));
+    assert!(is_shared_log_name(
+        "history-abc-team-b.jsonl",
+        Some("team-b")
+    ));
+    assert!(!is_shared_log_name("history-abc.jsonl", Some("alice")));
+}
+
+#[test]
+fn reading_history_changes_no_files() {
+    let env_sandbox = EnvSandbox::new();
+    let base = env_sandbox.preferred_dir();
+    let run = |id: &str| HistoryEntry {
+        schema_version: HISTORY_SCHEMA_VERSION,
+        id: id.into(),
+        ..HistoryEntry::default()
+    };
+    append_history(None, "", &run("a")).expect("entry should append");
+    let legacy = serde_json::to_string(&[run("b")]).expect("legacy should serialize");
+    fs::write(base.join("history.json"), legacy).expect("legacy should be written");
+
+    let report = read_history(None, "synced").expect("history should read");
+
+    let mut ids = report
+        .entries
+        .iter()
+        .map(|entry| entry.id.as_str())
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    assert_eq!(ids, ["a", "b"]);
+    assert!(report.damage.is_empty());
+    assert!(base.join("history.json").exists());
+    assert!(!base.join("history.json.old").exists());
+    assert!(!base.join("history.jsonl.1").exists());
+    assert!(!base.join("machine-id").exists());
+    assert!(!base.join("synced").exists());
+}
+
+#[test]
+fn profiles_sharing_a_history_directory_keep_their_own_runs() {
+    let _env_sandbox = EnvSandbox::new();
+    let run = |id: &str| HistoryEntry {
+        schema_version: HISTORY_SCHEMA_VERSION,
+        id: id.into(),
+        ..HistoryEntry::default()
+    };
+    let ids = |profile| {
+        load_history(profile, "~/Sync/rtyping")
+            .expect("history should load")
+            .entries
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect::<Vec<_>>()
+    };
+
+    append_history(None, "~/Sync/rtyping", &run("default-run")).expect("entry should append");
+    append_history(Some("alice"), "~/Sync/rtyping", &run("alice-run"))
+        .expect("entry should append");
+    append_history(Some("bob"), "~/Sync/rtyping", &run("bob-run")).expect("entry should append");
+
+    assert_eq!(ids(Some("bob")), ["bob-run"]);
+    assert_eq!(ids(Some("alice")), ["alice-run"]);
+    assert_eq!(ids(None), ["default-run"]);
+}
+
+#[test]
+fn profiles_keep_their_own_settings_and_share_provider_keys() {
+    let env_sandbox = EnvSandbox::new();
+    let base = env_sandbox.preferred_dir();
+    let shared = sample_config();
+    save_config(None, &shared).expect("shared config should save");
+
+    create_profile("alice").expect("profile should be created");
+    let mut alice = load_config(Some("alice"))
+        .expect("profile config should load")
+        .config;
+    assert_eq!(alice.google, shared.google);
+    assert_eq!(alice.game, GameSettings::default());
+    assert_eq!(
+        history_paths(Some("alice"), "")
+            .expect("history paths should resolve")
+            .log_path,
+        base.join("profiles").join("alice").join("history.jsonl")
+    );
+
+    alice.game.timeout = "15".into();
+    alice.groq.model = "mixtral".into();
+    save_config(Some("alice"), &alice).expect("profile config should save");
+    let stored = fs::read_to_string(base.join("profiles").join("alice").join("config.json"))
+        .expect("profile config should exist");
+    assert!(!stored.contains("example.com"));
+    assert_eq!(
+        load_config(Some("alice"))
+            .expect("profile config should load")
+            .config,
+        alice
+    );
+
+    rename_profile("alice", "alicia").expect("profile should be renamed");
+    assert_eq!(
+        load_config(Some("alicia"))
+            .expect("renamed profile should load")
+            .config,
+        alice
+    );
+    assert_eq!(
+        load_config(None).expect("shared config should load").config,
+        shared
+    );
+    assert_eq!(list_profiles().expect("profiles should list"), ["alicia"]);
+    delete_profile("alicia").expect("profile should be deleted");
+    assert!(list_profiles().expect("profiles should list").is_empty());
+}
+
+#[test]
+fn only_existing_profiles_are_found() {
+    let env_sandbox = EnvSandbox::new();
+
+    assert_eq!(find_profile(None).expect("default profile exists"), None);
+    let missing = find_profile(Some("alice")).expect_err("missing profile should fail");
+    assert_eq!(missing.to_string(), "no such profile `alice`");
+    assert!(!env_sandbox.preferred_dir().join("profiles").exists());
+
+    create_profile("alice").expect("profile should be created");
+    assert_eq!(
+        find_profile(Some(" alice ")).expect("profile should be found"),
+        Some("alice".to_string())
+    );
+}
+
+#[test]
+fn renamed_profiles_keep_and_deleted_profiles_drop_their_shared_runs() {
+    let env_sandbox = EnvSandbox::new();
+    let shared_dir = env_sandbox.root.join("home").join("Sync").join("rtyping");
+    let run = |id: &str| HistoryEntry {
+        schema_version: HISTORY_SCHEMA_VERSION,
+        id: id.into(),
+        ..HistoryEntry::default()
+    };
+    let ids = |profile| {
+        load_history(Some(profile), "~/Sync/rtyping")
+            .expect("history should load")
+            .entries
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect::<Vec<_>>()
+    };
+    create_profile("alice").expect("profile should be created");
+    let config = AppConfig {
+        game: GameSettings {
+            history_path: "~/Sync/rtyping".into(),
+            ..GameSettings::default()
+        },
+        ..AppConfig::default()
+    };
+    save_config(Some("alice"), &config).expect("profile config should save");
+    append_history(Some("alice"), "~/Sync/rtyping", &run("this-machine"))
+        .expect("entry should append");
+    let other_machine = serde_json::to_string(&run("other-machine")).expect("run should serialize");
+    fs::write(
+        shared_dir.join("history-0ther-alice.jsonl"),
+        format!("{other_machine}\n"),
+    )
+    .expect("other machine's log should be written");
+
+    rename_profile("alice", "alicia").expect("profile should be renamed");
+    assert_eq!(ids("alicia"), ["this-machine", "other-machine"]);
+
+    delete_profile("alicia").expect("profile should be deleted");
+    create_profile("alicia").expect("profile should be created again");
+    assert!(ids("alicia").is_empty());
+    assert_eq!(
+        fs::read_dir(&shared_dir)
+            .expect("shared dir should list")
+            .count(),
+        0
+    );
+}
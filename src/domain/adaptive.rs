@@ -0,0 +1,99 @@
+use super::text_content::TextContent;
+
+/// Adaptive levels run from 0 (easiest) to `ADAPTIVE_MAX_STEP`; the middle
+/// level plays the configured settings unchanged.
+pub const ADAPTIVE_MAX_STEP: u8 = 4;
+pub const ADAPTIVE_DEFAULT_STEP: u8 = 2;
+/// Recent accuracy is kept inside this band, in percent.
+pub const TARGET_ACCURACY_LOW: f64 = 94.0;
+pub const TARGET_ACCURACY_HIGH: f64 = 98.0;
+
+const LENGTH_PERCENT: [usize; 5] = [60, 80, 100, 120, 140];
+
+/// Settings chosen for the next run, with the reason they were chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdaptivePlan {
+    pub step: u8,
+    pub reason: String,
+}
+
+impl AdaptivePlan {
+    /// Text length in percent of the configured `TextScale` or `WordCount`.
+    pub fn length_percent(&self) -> usize {
+        LENGTH_PERCENT
+            .get(usize::from(self.step))
+            .copied()
+            .unwrap_or(100)
+    }
+
+    pub fn scaled_length(&self, length: usize) -> usize {
+        (length * self.length_percent() / 100).max(1)
+    }
+
+    /// The configured speed shifted by the level, kept within 1 to 5.
+    pub fn rhythm_speed(&self, configured: u8) -> u8 {
+        (configured + self.step)
+            .saturating_sub(ADAPTIVE_DEFAULT_STEP)
+            .clamp(1, 5)
+    }
+
+    /// The two easiest levels are lowercase without punctuation, the easiest
+    /// also without rare words; the middle level adds capitals.
+    pub fn content(&self) -> TextContent {
+        TextContent {
+            capitals: self.step >= 2,
+            punctuation: self.step >= 3,
+            rare_words: self.step >= 1,
+            ..TextContent::FULL
+        }
+    }
+
+    /// Level and the settings it applies, e.g. `level 3/5: length 100%, ...`.
+    pub fn summary(&self, rhythm_speed: u8) -> String {
+        format!(
+            "level {}/{}: length {}%, rhythm speed {rhythm_speed}, {}",
+            self.step + 1,
+            ADAPTIVE_MAX_STEP + 1,
+            self.length_percent(),
+            self.content().label()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(step: u8) -> AdaptivePlan {
+        AdaptivePlan {
+            step,
+            reason: String::new(),
+        }
+    }
+
+    #[test]
+    fn levels_scale_length_speed_and_content() {
+        assert_eq!(plan(0).scaled_length(60), 36);
+        assert_eq!(plan(4).scaled_length(25), 35);
+        assert_eq!(plan(0).rhythm_speed(2), 1);
+        assert_eq!(plan(4).rhythm_speed(5), 5);
+        assert_eq!(plan(3).rhythm_speed(2), 3);
+        assert!(plan(3).content().is_full());
+        assert_eq!(
+            plan(1).content(),
+            TextContent {
+                capitals: false,
+                punctuation: false,
+                ..TextContent::FULL
+            }
+        );
+    }
+
+    #[test]
+    fn summary_lists_the_applied_settings() {
+        assert_eq!(
+            plan(2).summary(2),
+            "level 3/5: length 100%, rhythm speed 2, capitals, no punctuation, rare words"
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use super::history::TestLength;
+use super::layout::LayoutKind;
+use super::text_content::TextContent;
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ProviderConfig {
     pub api_url: String,
@@ -11,25 +15,167 @@ impl ProviderConfig {
             && !self.api_key.trim().is_empty()
             && !self.model.trim().is_empty()
     }
+
+    /// A profile's provider settings, with the fields it leaves empty taken
+    /// from the shared settings.
+    pub fn with_shared(&self, shared: &ProviderConfig) -> ProviderConfig {
+        let field = |own: &String, shared: &String| {
+            if own.is_empty() {
+                shared.clone()
+            } else {
+                own.clone()
+            }
+        };
+        ProviderConfig {
+            api_url: field(&self.api_url, &shared.api_url),
+            api_key: field(&self.api_key, &shared.api_key),
+            model: field(&self.model, &shared.model),
+        }
+    }
+
+    /// What a profile saves: the fields that differ from the shared
+    /// settings, with the rest left empty so they follow the shared ones.
+    pub fn overrides_of(&self, shared: &ProviderConfig) -> ProviderConfig {
+        let field = |own: &String, shared: &String| {
+            if own == shared {
+                String::new()
+            } else {
+                own.clone()
+            }
+        };
+        ProviderConfig {
+            api_url: field(&self.api_url, &shared.api_url),
+            api_key: field(&self.api_key, &shared.api_key),
+            model: field(&self.model, &shared.model),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestMode {
+    Time,
+    Words,
+    Passage,
+}
+
+impl TestMode {
+    pub const ALL: [TestMode; 3] = [TestMode::Time, TestMode::Words, TestMode::Passage];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Time => "time",
+            Self::Words => "words",
+            Self::Passage => "passage",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.label().eq_ignore_ascii_case(value.trim()))
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Time => Self::Words,
+            Self::Words => Self::Passage,
+            Self::Passage => Self::Time,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrectionPolicy {
+    Normal,
+    LetterStop,
+    WordStop,
+    NoBackspace,
+    SuddenDeath,
+}
+
+impl CorrectionPolicy {
+    pub const ALL: [CorrectionPolicy; 5] = [
+        CorrectionPolicy::Normal,
+        CorrectionPolicy::LetterStop,
+        CorrectionPolicy::WordStop,
+        CorrectionPolicy::NoBackspace,
+        CorrectionPolicy::SuddenDeath,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::LetterStop => "letter-stop",
+            Self::WordStop => "word-stop",
+            Self::NoBackspace => "no-backspace",
+            Self::SuddenDeath => "sudden-death",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.label().eq_ignore_ascii_case(value.trim()))
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Normal => Self::LetterStop,
+            Self::LetterStop => Self::WordStop,
+            Self::WordStop => Self::NoBackspace,
+            Self::NoBackspace => Self::SuddenDeath,
+            Self::SuddenDeath => Self::Normal,
+        }
+    }
+
+    pub fn allows_backspace(self) -> bool {
+        !matches!(self, Self::NoBackspace | Self::SuddenDeath)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GameSettings {
     pub timeout: String,
+    pub test_mode: String,
+    pub word_count: String,
+    pub correction: String,
+    pub fail_accuracy: String,
+    pub space_skip: String,
+    pub layout: String,
     pub text_scale: String,
+    pub lowercase_only: String,
+    pub punctuation: String,
+    pub numbers: String,
+    pub symbols: String,
     pub rhythm_speed: String,
+    pub adaptive: String,
     pub freq: String,
     pub sound_enabled: String,
+    /// Directory whose history files are shared with other machines, or
+    /// empty to keep history next to the config.
+    pub history_path: String,
 }
 
 impl Default for GameSettings {
     fn default() -> Self {
         Self {
             timeout: "60".to_string(),
+            test_mode: "time".to_string(),
+            word_count: "25".to_string(),
+            correction: "normal".to_string(),
+            fail_accuracy: "100".to_string(),
+            space_skip: "false".to_string(),
+            layout: "qwerty".to_string(),
             text_scale: "60".to_string(),
+            lowercase_only: "false".to_string(),
+            punctuation: "true".to_string(),
+            numbers: "false".to_string(),
+            symbols: "false".to_string(),
             rhythm_speed: "2".to_string(),
+            adaptive: "false".to_string(),
             freq: "80.0".to_string(),
             sound_enabled: "false".to_string(),
+            history_path: String::new(),
         }
     }
 }
@@ -39,14 +185,109 @@ impl GameSettings {
         self.timeout.trim().parse().unwrap_or(60)
     }
 
+    pub fn test_mode_value(&self) -> TestMode {
+        TestMode::parse(&self.test_mode).unwrap_or(TestMode::Time)
+    }
+
+    pub fn word_count_value(&self) -> usize {
+        self.word_count.trim().parse().unwrap_or(25).clamp(1, 500)
+    }
+
+    pub fn correction_value(&self) -> CorrectionPolicy {
+        CorrectionPolicy::parse(&self.correction).unwrap_or(CorrectionPolicy::Normal)
+    }
+
+    pub fn cycle_correction(&mut self) {
+        self.correction = self.correction_value().next().label().to_string();
+    }
+
+    /// Sudden death fails a session whose accuracy drops below this percentage.
+    /// The default of 100 fails on the first error.
+    pub fn fail_accuracy_value(&self) -> f64 {
+        self.fail_accuracy
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .unwrap_or(100.0)
+            .clamp(0.0, 100.0)
+    }
+
+    /// When enabled, typing a space inside a word jumps to the next word and
+    /// counts the rest of the skipped word as missed.
+    pub fn space_skip_value(&self) -> bool {
+        self.space_skip.trim().eq_ignore_ascii_case("true")
+    }
+
+    pub fn toggle_space_skip(&mut self) {
+        toggle_flag(&mut self.space_skip);
+    }
+
+    pub fn layout_value(&self) -> LayoutKind {
+        LayoutKind::parse(&self.layout).unwrap_or(LayoutKind::Qwerty)
+    }
+
+    pub fn cycle_layout(&mut self) {
+        self.layout = self.layout_value().next().label().to_string();
+    }
+
+    pub fn test_length(&self) -> TestLength {
+        match self.test_mode_value() {
+            TestMode::Time => TestLength::Time(self.timeout_value()),
+            TestMode::Words => TestLength::Words(self.word_count_value()),
+            TestMode::Passage => TestLength::Passage(self.text_scale_value()),
+        }
+    }
+
+    pub fn cycle_test_mode(&mut self) {
+        self.test_mode = self.test_mode_value().next().label().to_string();
+    }
+
     pub fn text_scale_value(&self) -> usize {
         self.text_scale.trim().parse().unwrap_or(60)
     }
 
+    /// Character classes chosen for generated text from every source.
+    pub fn text_content(&self) -> TextContent {
+        TextContent {
+            capitals: !flag_value(&self.lowercase_only),
+            punctuation: flag_value(&self.punctuation),
+            numbers: flag_value(&self.numbers),
+            symbols: flag_value(&self.symbols),
+            ..TextContent::FULL
+        }
+    }
+
+    pub fn toggle_lowercase_only(&mut self) {
+        toggle_flag(&mut self.lowercase_only);
+    }
+
+    pub fn toggle_punctuation(&mut self) {
+        toggle_flag(&mut self.punctuation);
+    }
+
+    pub fn toggle_numbers(&mut self) {
+        toggle_flag(&mut self.numbers);
+    }
+
+    pub fn toggle_symbols(&mut self) {
+        toggle_flag(&mut self.symbols);
+    }
+
     pub fn rhythm_speed_value(&self) -> u8 {
         self.rhythm_speed.trim().parse().unwrap_or(2).clamp(1, 5)
     }
 
+    /// When enabled, text length, rhythm speed and text content follow recent
+    /// results instead of staying fixed.
+    pub fn adaptive_value(&self) -> bool {
+        self.adaptive.trim().eq_ignore_ascii_case("true")
+    }
+
+    pub fn toggle_adaptive(&mut self) {
+        toggle_flag(&mut self.adaptive);
+    }
+
     pub fn freq_value(&self) -> f32 {
         self.freq.trim().parse().unwrap_or(80.0)
     }
@@ -56,11 +297,19 @@ impl GameSettings {
     }
 
     pub fn toggle_sound(&mut self) {
-        if self.sound_enabled_value() {
-            self.sound_enabled = "false".to_string();
-        } else {
-            self.sound_enabled = "true".to_string();
-        }
+        toggle_flag(&mut self.sound_enabled);
+    }
+}
+
+fn flag_value(value: &str) -> bool {
+    value.trim().eq_ignore_ascii_case("true")
+}
+
+fn toggle_flag(value: &mut String) {
+    if flag_value(value) {
+        *value = "false".to_string();
+    } else {
+        *value = "true".to_string();
     }
 }
 
@@ -79,7 +328,32 @@ pub struct ConfigLoadReport {
 
 #[cfg(test)]
 mod tests {
-    use super::GameSettings;
+    use super::{CorrectionPolicy, GameSettings, ProviderConfig, TestMode};
+    use crate::domain::history::TestLength;
+    use crate::domain::layout::LayoutKind;
+    use crate::domain::text_content::TextContent;
+
+    #[test]
+    fn text_content_follows_the_character_toggles() {
+        let mut settings = GameSettings::default();
+        assert!(settings.text_content().is_full());
+
+        settings.toggle_lowercase_only();
+        settings.toggle_punctuation();
+        settings.toggle_numbers();
+        settings.toggle_symbols();
+
+        assert_eq!(
+            settings.text_content(),
+            TextContent {
+                capitals: false,
+                punctuation: false,
+                rare_words: true,
+                numbers: true,
+                symbols: true,
+            }
+        );
+    }
 
     #[test]
     fn rhythm_speed_defaults_to_two_for_invalid_values() {
@@ -105,4 +379,109 @@ mod tests {
         assert_eq!(low.rhythm_speed_value(), 1);
         assert_eq!(high.rhythm_speed_value(), 5);
     }
+
+    #[test]
+    fn test_length_follows_selected_test_mode() {
+        let mut settings = GameSettings {
+            timeout: "30".to_string(),
+            word_count: "50".to_string(),
+            text_scale: "80".to_string(),
+            ..GameSettings::default()
+        };
+
+        assert_eq!(settings.test_length(), TestLength::Time(30));
+        settings.cycle_test_mode();
+        assert_eq!(settings.test_mode_value(), TestMode::Words);
+        assert_eq!(settings.test_length(), TestLength::Words(50));
+        settings.cycle_test_mode();
+        assert_eq!(settings.test_length(), TestLength::Passage(80));
+        settings.cycle_test_mode();
+        assert_eq!(settings.test_mode_value(), TestMode::Time);
+    }
+
+    #[test]
+    fn invalid_test_settings_fall_back_to_defaults() {
+        let settings = GameSettings {
+            test_mode: "marathon".to_string(),
+            word_count: "0".to_string(),
+            ..GameSettings::default()
+        };
+
+        assert_eq!(settings.test_mode_value(), TestMode::Time);
+        assert_eq!(settings.word_count_value(), 1);
+    }
+
+    #[test]
+    fn correction_policy_cycles_through_all_policies() {
+        let mut settings = GameSettings::default();
+        assert_eq!(settings.correction_value(), CorrectionPolicy::Normal);
+
+        for expected in [
+            CorrectionPolicy::LetterStop,
+            CorrectionPolicy::WordStop,
+            CorrectionPolicy::NoBackspace,
+            CorrectionPolicy::SuddenDeath,
+            CorrectionPolicy::Normal,
+        ] {
+            settings.cycle_correction();
+            assert_eq!(settings.correction_value(), expected);
+        }
+    }
+
+    #[test]
+    fn layout_cycles_and_falls_back_to_qwerty() {
+        let mut settings = GameSettings {
+            layout: "azerty".to_string(),
+            ..GameSettings::default()
+        };
+        assert_eq!(settings.layout_value(), LayoutKind::Qwerty);
+
+        for expected in [
+            LayoutKind::Dvorak,
+            LayoutKind::Colemak,
+            LayoutKind::Jis,
+            LayoutKind::Qwerty,
+        ] {
+            settings.cycle_layout();
+            assert_eq!(settings.layout_value(), expected);
+        }
+    }
+
+    #[test]
+    fn fail_accuracy_is_clamped_and_defaults_to_first_error() {
+        let invalid = GameSettings {
+            fail_accuracy: "often".to_string(),
+            ..GameSettings::default()
+        };
+        let high = GameSettings {
+            fail_accuracy: "120".to_string(),
+            ..GameSettings::default()
+        };
+        let custom = GameSettings {
+            fail_accuracy: "95".to_string(),
+            ..GameSettings::default()
+        };
+
+        assert!((invalid.fail_accuracy_value() - 100.0).abs() < f64::EPSILON);
+        assert!((high.fail_accuracy_value() - 100.0).abs() < f64::EPSILON);
+        assert!((custom.fail_accuracy_value() - 95.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn profile_provider_fields_fall_back_to_the_shared_ones() {
+        let shared = ProviderConfig {
+            api_url: "https://example.com".into(),
+            api_key: "shared-key".into(),
+            model: "base".into(),
+        };
+        let own = ProviderConfig {
+            model: "large".into(),
+            ..ProviderConfig::default()
+        };
+
+        let merged = own.with_shared(&shared);
+        assert_eq!(merged.api_key, "shared-key");
+        assert_eq!(merged.model, "large");
+        assert_eq!(merged.overrides_of(&shared), own);
+    }
 }
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Words typed in each drill run.
+pub const DRILL_WORDS: usize = 20;
+/// Weak characters and bigrams practised in one drill.
+pub const DRILL_ITEM_LIMIT: usize = 4;
+const INITIAL_EASE: f64 = 2.5;
+const MIN_EASE: f64 = 1.3;
+/// Reviews graded below this start the item over.
+const PASSING_QUALITY: u8 = 3;
+const MAX_QUALITY: u8 = 5;
+
+/// Spaced-repetition state of one weak character or bigram (SM-2).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DrillCard {
+    pub ease: f64,
+    pub interval_days: u32,
+    pub repetitions: u32,
+    /// Days since the Unix epoch (UTC) on which the item is due again.
+    pub due_day: u64,
+}
+
+impl Default for DrillCard {
+    fn default() -> Self {
+        Self {
+            ease: INITIAL_EASE,
+            interval_days: 0,
+            repetitions: 0,
+            due_day: 0,
+        }
+    }
+}
+
+impl DrillCard {
+    pub fn is_due(&self, today: u64) -> bool {
+        self.due_day <= today
+    }
+
+    /// Applies an SM-2 review with `quality` from 0 (forgotten) to 5
+    /// (perfect). A passing review lengthens the interval by the ease factor;
+    /// a failing one brings the item back tomorrow.
+    pub fn review(&mut self, quality: u8, today: u64) {
+        let quality = quality.min(MAX_QUALITY);
+        if quality < PASSING_QUALITY {
+            self.repetitions = 0;
+            self.interval_days = 1;
+        } else {
+            self.interval_days = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => scaled_interval(self.interval_days, self.ease),
+            };
+            self.repetitions += 1;
+        }
+
+        let shortfall = f64::from(MAX_QUALITY - quality);
+        self.ease = (self.ease + 0.1 - shortfall * (0.08 + shortfall * 0.02)).max(MIN_EASE);
+        self.due_day = today + u64::from(self.interval_days);
+    }
+}
+
+/// Review quality of a drilled item from its error rate in the run.
+pub fn review_quality(attempts: usize, errors: usize) -> Option<u8> {
+    if attempts == 0 {
+        return None;
+    }
+
+    let rate = errors.min(attempts) * 100 / attempts;
+    Some(match rate {
+        0 => 5,
+        1..=5 => 4,
+        6..=15 => 3,
+        16..=30 => 2,
+        31..=50 => 1,
+        _ => 0,
+    })
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DrillDeck {
+    #[serde(default)]
+    pub cards: BTreeMap<String, DrillCard>,
+}
+
+impl DrillDeck {
+    /// Items to drill today: scheduled items that are due, earliest first,
+    /// then `weak` items that have never been drilled.
+    pub fn due_items(&self, weak: &[String], today: u64) -> Vec<String> {
+        let mut due = self
+            .cards
+            .iter()
+            .filter(|(_, card)| card.is_due(today))
+            .collect::<Vec<_>>();
+        due.sort_by_key(|(item, card)| (card.due_day, *item));
+
+        let mut items = due
+            .into_iter()
+            .map(|(item, _)| item.clone())
+            .collect::<Vec<_>>();
+        items.extend(
+            weak.iter()
+                .filter(|item| !self.cards.contains_key(*item))
+                .cloned(),
+        );
+        items
+    }
+
+    pub fn review(&mut self, item: &str, quality: u8, today: u64) {
+        self.cards
+            .entry(item.to_string())
+            .or_default()
+            .review(quality, today);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DrillLoadReport {
+    pub deck: DrillDeck,
+    pub warnings: Vec<String>,
+}
+
+fn scaled_interval(interval_days: u32, ease: f64) -> u32 {
+    let scaled = (f64::from(interval_days) * ease).round();
+    if scaled >= f64::from(u32::MAX) {
+        return u32::MAX;
+    }
+
+    // `scaled` is a non-negative whole number below `u32::MAX`.
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let days = scaled as u32;
+    days.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passing_reviews_follow_the_sm2_intervals() {
+        let mut card = DrillCard::default();
+
+        card.review(5, 100);
+        assert_eq!((card.interval_days, card.due_day), (1, 101));
+        card.review(5, 101);
+        assert_eq!((card.interval_days, card.due_day), (6, 107));
+        card.review(4, 107);
+        assert_eq!(card.interval_days, 16);
+        assert_eq!(card.repetitions, 3);
+        assert!((card.ease - 2.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn failing_review_restarts_the_item_and_lowers_ease() {
+        let mut card = DrillCard {
+            ease: 1.4,
+            interval_days: 20,
+            repetitions: 4,
+            due_day: 50,
+        };
+
+        card.review(0, 50);
+
+        assert_eq!(card.repetitions, 0);
+        assert_eq!(card.due_day, 51);
+        assert!((card.ease - MIN_EASE).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn review_quality_grades_error_rate() {
+        assert_eq!(review_quality(0, 0), None);
+        assert_eq!(review_quality(10, 0), Some(5));
+        assert_eq!(review_quality(10, 1), Some(3));
+        assert_eq!(review_quality(10, 6), Some(0));
+    }
+
+    #[test]
+    fn due_items_list_scheduled_items_before_new_weak_items() {
+        let mut deck = DrillDeck::default();
+        deck.review("th", 5, 10);
+        deck.review("q", 0, 9);
+        deck.review("z", 5, 30);
+        let weak = ["q".to_string(), ";".to_string()];
+
+        assert_eq!(deck.due_items(&weak, 11), ["q", "th", ";"]);
+        assert_eq!(deck.due_items(&[], 9), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,129 @@
+use super::history::{HistoryEntry, Keystroke};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GhostGap {
+    /// Positive when the player is ahead of the ghost.
+    pub chars: isize,
+    /// Positive when the ghost reached the player's position later than now.
+    /// `None` once the player has passed the furthest point the ghost reached.
+    pub seconds: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ghost {
+    label: String,
+    keystrokes: Vec<Keystroke>,
+}
+
+impl Ghost {
+    pub fn from_entry(label: impl Into<String>, entry: &HistoryEntry) -> Option<Self> {
+        if entry.keystrokes.is_empty() || entry.target_text.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            label: label.into(),
+            keystrokes: entry.keystrokes.clone(),
+        })
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn position_at(&self, elapsed_ms: u64) -> usize {
+        self.keystrokes
+            .iter()
+            .take_while(|keystroke| keystroke.elapsed_ms <= elapsed_ms)
+            .last()
+            .map_or(0, |keystroke| keystroke.position)
+    }
+
+    /// Returns when the ghost first reached `position`.
+    pub fn reached_at(&self, position: usize) -> Option<u64> {
+        if position == 0 {
+            return Some(0);
+        }
+
+        self.keystrokes
+            .iter()
+            .find(|keystroke| keystroke.position >= position)
+            .map(|keystroke| keystroke.elapsed_ms)
+    }
+
+    pub fn gap(&self, position: usize, elapsed_ms: u64) -> GhostGap {
+        let ghost_position = self.position_at(elapsed_ms);
+        let chars = position.cast_signed() - ghost_position.cast_signed();
+        #[expect(clippy::cast_precision_loss)]
+        let seconds = self
+            .reached_at(position)
+            .map(|reached_ms| (reached_ms as f64 - elapsed_ms as f64) / 1000.0);
+
+        GhostGap { chars, seconds }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+
+    fn keystroke(elapsed_ms: u64, position: usize) -> Keystroke {
+        Keystroke {
+            elapsed_ms,
+            key: Some('a'),
+            position,
+        }
+    }
+
+    fn ghost() -> Ghost {
+        let entry = HistoryEntry {
+            target_text: "abcd".to_string(),
+            keystrokes: vec![
+                keystroke(1000, 1),
+                keystroke(2000, 2),
+                keystroke(2500, 1),
+                keystroke(3000, 2),
+                keystroke(4000, 3),
+            ],
+            ..HistoryEntry::default()
+        };
+        Ghost::from_entry("best", &entry).expect("entry has a timeline")
+    }
+
+    #[test]
+    fn from_entry_requires_a_recorded_timeline() {
+        assert!(Ghost::from_entry("empty", &HistoryEntry::default()).is_none());
+    }
+
+    #[test]
+    fn position_at_follows_the_timeline_including_deletions() {
+        let ghost = ghost();
+
+        assert_eq!(ghost.position_at(0), 0);
+        assert_eq!(ghost.position_at(1500), 1);
+        assert_eq!(ghost.position_at(2600), 1);
+        assert_eq!(ghost.position_at(9000), 3);
+    }
+
+    #[test]
+    fn gap_reports_lead_in_chars_and_seconds() {
+        let ghost = ghost();
+
+        let gap = ghost.gap(2, 1500);
+        assert_eq!(gap.chars, 1);
+        assert_eq!(gap.seconds, Some(0.5));
+
+        let gap = ghost.gap(1, 3000);
+        assert_eq!(gap.chars, -1);
+        assert_eq!(gap.seconds, Some(-2.0));
+    }
+
+    #[test]
+    fn gap_has_no_seconds_past_the_ghost_finish() {
+        let gap = ghost().gap(4, 5000);
+
+        assert_eq!(gap.chars, 1);
+        assert_eq!(gap.seconds, None);
+    }
+}
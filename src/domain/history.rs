@@ -1,7 +1,25 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+use super::text_content::TextContent;
+
+/// Format version of newly saved history entries. Older entries are migrated
+/// to it when history is loaded.
+pub const HISTORY_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct HistoryEntry {
+    #[serde(default)]
+    pub schema_version: u32,
+    /// Unique within and across history files, so runs can be deduplicated.
+    #[serde(default)]
+    pub id: String,
+    /// Seconds since the Unix epoch (UTC) when the run finished. `None` for
+    /// runs saved before timestamps were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
     pub wpm: f64,
     pub accuracy: f64,
     pub miss_count: usize,
@@ -9,16 +27,554 @@ pub struct HistoryEntry {
     pub generation_source: String,
     pub mode: HistoryMode,
     pub missed_chars: Vec<char>,
+    /// Key presses and misses for each expected character, counted with the
+    /// same rule as `missed_chars`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub char_attempts: BTreeMap<char, AttemptCount>,
+    /// Finished words, lowercased without surrounding punctuation, and how
+    /// many times each had a mistake.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub word_attempts: BTreeMap<String, AttemptCount>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<TestLength>,
+    #[serde(default)]
+    pub failed: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub target_text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keystrokes: Vec<Keystroke>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub analytics: Option<SpeedAnalytics>,
+    /// Adaptive level the run was played at, when adaptive mode was on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_step: Option<u8>,
+    /// Difficulty score of the target text, from 0 to 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<f64>,
+    /// Character options applied to generated text, when any were.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_content: Option<TextContent>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl HistoryEntry {
+    /// Entries saved before test lengths were recorded were all timed runs, so
+    /// their elapsed time is the closest available length.
+    pub fn test_length(&self) -> TestLength {
+        self.test.unwrap_or(TestLength::Time(self.elapsed_seconds))
+    }
+}
+
+/// The kind of session an entry records. Speed and accuracy summaries,
+/// adaptive levels, and weak-key drills use timed runs only, since practice
+/// and rhythm sessions are typed under different rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum HistoryMode {
+    #[default]
     Timed,
+    Practice {
+        /// Distinct characters of the text that were reached, in order.
+        keys_practiced: Vec<char>,
+    },
+    Rhythm(RhythmRecord),
+}
+
+impl HistoryMode {
+    pub fn is_timed(&self) -> bool {
+        matches!(self, Self::Timed)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Timed => "timed",
+            Self::Practice { .. } => "practice",
+            Self::Rhythm(_) => "rhythm",
+        }
+    }
+}
+
+/// Note judgements of a rhythm session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RhythmRecord {
+    pub hit: usize,
+    pub ok: usize,
+    pub miss: usize,
+    pub max_combo: usize,
+    /// Scroll speed the notes moved at.
+    pub speed: u8,
+}
+
+/// How often a character or word came up and how often it was missed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttemptCount {
+    pub attempts: usize,
+    pub misses: usize,
+}
+
+impl AttemptCount {
+    pub fn record(&mut self, missed: bool) {
+        self.attempts += 1;
+        self.misses += usize::from(missed);
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        self.attempts += other.attempts;
+        self.misses += other.misses;
+    }
+
+    /// Misses per attempt, in percent.
+    pub fn error_rate(self) -> f64 {
+        if self.attempts == 0 {
+            return 0.0;
+        }
+        let to_f64 = |value: usize| f64::from(u32::try_from(value).unwrap_or(u32::MAX));
+        to_f64(self.misses) * 100.0 / to_f64(self.attempts)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "length", rename_all = "snake_case")]
+pub enum TestLength {
+    Time(i32),
+    Words(usize),
+    Passage(usize),
+}
+
+impl TestLength {
+    pub fn label(self) -> String {
+        match self {
+            Self::Time(seconds) if seconds <= 0 => "time unlimited".to_string(),
+            Self::Time(seconds) => format!("time {seconds}s"),
+            Self::Words(words) => format!("words {words}"),
+            Self::Passage(scale) => format!("passage x{scale}"),
+        }
+    }
+
+    pub fn timeout(self) -> i32 {
+        match self {
+            Self::Time(seconds) => seconds.max(0),
+            Self::Words(_) | Self::Passage(_) => 0,
+        }
+    }
+}
+
+/// One key press during a run. `position` is the input length after the key
+/// was applied, so deletions move it back and rejected keys leave it unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystroke {
+    pub elapsed_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<char>,
+    pub position: usize,
+}
+
+/// Marks each keystroke that counted as a miss, using the same rule as live
+/// typing: a key is wrong when it does not match the target at the cursor, and
+/// a skipped word counts as one miss. Deletions are never misses.
+pub fn keystroke_misses(target: &str, keystrokes: &[Keystroke]) -> Vec<bool> {
+    let target_chars = target.chars().collect::<Vec<_>>();
+    let mut position = 0;
+
+    keystrokes
+        .iter()
+        .map(|keystroke| {
+            let miss = keystroke.key.is_some_and(|key| {
+                keystroke.position > position + 1 || target_chars.get(position) != Some(&key)
+            });
+            position = keystroke.position;
+            miss
+        })
+        .collect()
+}
+
+/// Speed figures derived from a run's keystroke timeline.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpeedAnalytics {
+    /// Correct keys in each second of the run, as WPM. The last second may be
+    /// partial.
+    pub per_second_wpm: Vec<u64>,
+    /// Coefficient of variation of the per-second speed, in percent. `None`
+    /// when the run is too short to measure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed_cv: Option<f64>,
+    /// Fastest average speed over any five-second window.
+    pub burst_wpm: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error_seconds: Vec<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slowest_passage: Option<Passage>,
+    #[serde(default)]
+    pub key_events: KeyEventMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hold_times: Option<HoldTimes>,
+}
+
+impl SpeedAnalytics {
+    /// Consistency as a score out of 100, where 100 means an even pace.
+    pub fn consistency(&self) -> Option<f64> {
+        self.speed_cv.map(|cv| (100.0 - cv).clamp(0.0, 100.0))
+    }
+}
+
+/// Whether the terminal reported key releases during a run. Runs saved before
+/// releases were tracked are treated as press only.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyEventMode {
+    #[default]
+    PressOnly,
+    PressRelease,
+}
+
+impl KeyEventMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::PressOnly => "press only",
+            Self::PressRelease => "press + release",
+        }
+    }
+}
+
+/// One key release during a run, on the same clock as [`Keystroke`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyRelease {
+    pub elapsed_ms: u64,
+    pub key: char,
+}
+
+/// How long keys were held (dwell) and the gap from releasing one key to
+/// pressing the next (flight). Flight is negative when keys overlap.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HoldTimes {
+    pub average_dwell_ms: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub average_flight_ms: Option<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub longest_holds: Vec<KeyHold>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KeyHold {
+    pub key: char,
+    pub dwell_ms: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flight_ms: Option<f64>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// A range of target characters, `start..end`, and how long it took to type.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Passage {
+    pub start: usize,
+    pub end: usize,
+    pub seconds: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct HistoryLoadReport {
     pub entries: Vec<HistoryEntry>,
     pub warnings: Vec<String>,
+    /// Files that could not be read in full. They are left untouched until
+    /// the user chooses how to recover.
+    pub damage: Vec<HistoryDamage>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryFile {
+    /// `history.jsonl`, one entry per line.
+    Log,
+    /// `history.json`, the single array written by earlier versions.
+    Legacy,
+}
+
+impl HistoryFile {
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Log => "history.jsonl",
+            Self::Legacy => "history.json",
+        }
+    }
+}
+
+/// A history file that could not be read in full.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryDamage {
+    pub file: HistoryFile,
+    /// 1-based numbers of the lines, or of the entries in the legacy file,
+    /// that could not be read. Empty when the file could not be read at all.
+    pub unreadable: Vec<usize>,
+}
+
+impl HistoryDamage {
+    pub fn describe(&self) -> String {
+        const LISTED: usize = 5;
+
+        let file = self.file.file_name();
+        if self.unreadable.is_empty() {
+            return format!("{file}: the file could not be read");
+        }
+        let item = match self.file {
+            HistoryFile::Log => "line",
+            HistoryFile::Legacy => "entry",
+        };
+        let mut numbers = self
+            .unreadable
+            .iter()
+            .take(LISTED)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if self.unreadable.len() > LISTED {
+            numbers.push("...".to_string());
+        }
+        let plural = if self.unreadable.len() == 1 { "" } else { "s" };
+        format!(
+            "{file}: {} unreadable {item}{plural} ({})",
+            self.unreadable.len(),
+            numbers.join(", ")
+        )
+    }
+}
+
+/// A rotated copy of the history log. Slot 1 is the newest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryBackup {
+    pub slot: usize,
+    /// Seconds since the Unix epoch when the copy was taken.
+    pub modified: Option<u64>,
+    pub entries: usize,
+}
+
+/// File formats history can be exported to and imported from. JSON keeps
+/// every field; CSV has one row of summary columns per run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryFormat {
+    Csv,
+    Json,
+}
+
+impl HistoryFormat {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// The format named by a file's extension.
+    pub fn of_path(path: &Path) -> Option<Self> {
+        Self::parse(path.extension()?.to_str()?)
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+        }
+    }
+}
+
+/// What an import added to the saved history.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryImportReport {
+    pub added: usize,
+    /// Runs whose ID was already saved.
+    pub duplicates: usize,
+    /// Rows or values that are not runs.
+    pub skipped: usize,
+}
+
+impl HistoryImportReport {
+    pub fn describe(self) -> String {
+        format!(
+            "Imported {} runs ({} already saved, {} unreadable)",
+            self.added, self.duplicates, self.skipped
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn legacy_entry_without_test_length_falls_back_to_elapsed_time() {
+        let entry: HistoryEntry = serde_json::from_str(
+            r#"{"wpm":40.0,"accuracy":95.0,"miss_count":1,"elapsed_seconds":30,"generation_source":"Local","mode":"timed","missed_chars":["a"]}"#,
+        )
+        .expect("legacy entry should parse");
+
+        assert_eq!(entry.test, None);
+        assert_eq!(entry.test_length(), TestLength::Time(30));
+    }
+
+    #[test]
+    fn attempt_counts_round_trip_keyed_by_character_and_word() {
+        let mut entry = HistoryEntry::default();
+        entry.char_attempts.entry('e').or_default().record(true);
+        entry.char_attempts.entry(' ').or_default().record(false);
+        entry.word_attempts.insert(
+            "the".to_string(),
+            AttemptCount {
+                attempts: 4,
+                misses: 1,
+            },
+        );
+
+        let json = serde_json::to_string(&entry).expect("entry should serialize");
+        assert!(json.contains(
+            r#""char_attempts":{" ":{"attempts":1,"misses":0},"e":{"attempts":1,"misses":1}}"#
+        ));
+        let restored: HistoryEntry = serde_json::from_str(&json).expect("entry should parse");
+        assert_eq!(restored, entry);
+        assert!(
+            (AttemptCount {
+                attempts: 4,
+                misses: 1
+            }
+            .error_rate()
+                - 25.0)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_length_round_trips_with_kind_and_length() {
+        let entry = HistoryEntry {
+            test: Some(TestLength::Words(25)),
+            ..HistoryEntry::default()
+        };
+
+        let json = serde_json::to_string(&entry).expect("entry should serialize");
+        assert!(json.contains(r#""test":{"kind":"words","length":25}"#));
+        let restored: HistoryEntry = serde_json::from_str(&json).expect("entry should parse");
+        assert_eq!(restored.test_length(), TestLength::Words(25));
+    }
+
+    #[test]
+    fn session_modes_round_trip_and_timed_stays_a_plain_string() {
+        let modes = [
+            HistoryMode::Timed,
+            HistoryMode::Practice {
+                keys_practiced: vec!['a', 'b'],
+            },
+            HistoryMode::Rhythm(RhythmRecord {
+                hit: 3,
+                ok: 2,
+                miss: 1,
+                max_combo: 4,
+                speed: 5,
+            }),
+        ];
+
+        for mode in modes {
+            let json = serde_json::to_string(&mode).expect("mode should serialize");
+            let restored: HistoryMode = serde_json::from_str(&json).expect("mode should parse");
+            assert_eq!(restored, mode);
+        }
+        assert_eq!(
+            serde_json::to_string(&HistoryMode::Timed).expect("mode should serialize"),
+            r#""timed""#
+        );
+    }
+
+    #[test]
+    fn keystrokes_round_trip_and_default_to_empty() {
+        let entry = HistoryEntry {
+            target_text: "ab".to_string(),
+            keystrokes: vec![
+                Keystroke {
+                    elapsed_ms: 120,
+                    key: Some('a'),
+                    position: 1,
+                },
+                Keystroke {
+                    elapsed_ms: 300,
+                    key: None,
+                    position: 0,
+                },
+            ],
+            ..HistoryEntry::default()
+        };
+
+        let json = serde_json::to_string(&entry).expect("entry should serialize");
+        let restored: HistoryEntry = serde_json::from_str(&json).expect("entry should parse");
+        assert_eq!(restored, entry);
+
+        let plain =
+            serde_json::to_string(&HistoryEntry::default()).expect("entry should serialize");
+        assert!(!plain.contains("keystrokes"));
+        assert!(!plain.contains("target_text"));
+    }
+
+    #[test]
+    fn keystroke_misses_flag_mismatches_and_skips_but_not_deletions() {
+        let keystroke = |elapsed_ms, key, position| Keystroke {
+            elapsed_ms,
+            key,
+            position,
+        };
+        let misses = keystroke_misses(
+            "ab cd",
+            &[
+                keystroke(100, Some('a'), 1),
+                keystroke(200, Some('x'), 2),
+                keystroke(300, None, 1),
+                keystroke(400, Some('b'), 2),
+                keystroke(500, Some(' '), 3),
+                keystroke(600, Some(' '), 5),
+            ],
+        );
+
+        assert_eq!(misses, vec![false, true, false, false, false, true]);
+    }
+
+    #[test]
+    fn consistency_inverts_the_coefficient_of_variation() {
+        let analytics = SpeedAnalytics {
+            speed_cv: Some(18.0),
+            ..SpeedAnalytics::default()
+        };
+        assert_eq!(analytics.consistency(), Some(82.0));
+
+        let erratic = SpeedAnalytics {
+            speed_cv: Some(140.0),
+            ..SpeedAnalytics::default()
+        };
+        assert_eq!(erratic.consistency(), Some(0.0));
+        assert_eq!(SpeedAnalytics::default().consistency(), None);
+    }
+
+    #[test]
+    fn damage_description_lists_the_first_unreadable_lines() {
+        let lines = HistoryDamage {
+            file: HistoryFile::Log,
+            unreadable: vec![2, 4, 6, 8, 10, 12],
+        };
+        let file = HistoryDamage {
+            file: HistoryFile::Legacy,
+            unreadable: Vec::new(),
+        };
+
+        assert_eq!(
+            lines.describe(),
+            "history.jsonl: 6 unreadable lines (2, 4, 6, 8, 10, ...)"
+        );
+        assert_eq!(file.describe(), "history.json: the file could not be read");
+    }
+
+    #[test]
+    fn only_time_tests_have_a_timeout() {
+        assert_eq!(TestLength::Time(45).timeout(), 45);
+        assert_eq!(TestLength::Words(25).timeout(), 0);
+        assert_eq!(TestLength::Passage(60).timeout(), 0);
+    }
 }
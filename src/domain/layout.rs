@@ -0,0 +1,295 @@
+/// Position of a key on the on-screen keyboard. Row 4 is the space bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyPosition {
+    pub row: usize,
+    pub column: usize,
+}
+
+pub const SPACE_ROW: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Hand {
+    Left,
+    Right,
+}
+
+impl Hand {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Left => "Left hand",
+            Self::Right => "Right hand",
+        }
+    }
+}
+
+/// Fingers in keyboard order from the left pinky to the right pinky. Both
+/// thumbs share the space bar and are counted together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Finger {
+    LeftPinky,
+    LeftRing,
+    LeftMiddle,
+    LeftIndex,
+    Thumb,
+    RightIndex,
+    RightMiddle,
+    RightRing,
+    RightPinky,
+}
+
+impl Finger {
+    pub const ALL: [Finger; 9] = [
+        Finger::LeftPinky,
+        Finger::LeftRing,
+        Finger::LeftMiddle,
+        Finger::LeftIndex,
+        Finger::Thumb,
+        Finger::RightIndex,
+        Finger::RightMiddle,
+        Finger::RightRing,
+        Finger::RightPinky,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::LeftPinky => "L pinky",
+            Self::LeftRing => "L ring",
+            Self::LeftMiddle => "L middle",
+            Self::LeftIndex => "L index",
+            Self::Thumb => "Thumbs",
+            Self::RightIndex => "R index",
+            Self::RightMiddle => "R middle",
+            Self::RightRing => "R ring",
+            Self::RightPinky => "R pinky",
+        }
+    }
+
+    /// `None` for the thumbs, which sit between both hands.
+    pub fn hand(self) -> Option<Hand> {
+        match self {
+            Self::LeftPinky | Self::LeftRing | Self::LeftMiddle | Self::LeftIndex => {
+                Some(Hand::Left)
+            }
+            Self::Thumb => None,
+            Self::RightIndex | Self::RightMiddle | Self::RightRing | Self::RightPinky => {
+                Some(Hand::Right)
+            }
+        }
+    }
+
+    /// Standard touch-typing assignment for a key counted from the first
+    /// letter-aligned column of its row.
+    fn for_column(column: usize) -> Self {
+        match column {
+            0 => Self::LeftPinky,
+            1 => Self::LeftRing,
+            2 => Self::LeftMiddle,
+            3 | 4 => Self::LeftIndex,
+            5 | 6 => Self::RightIndex,
+            7 => Self::RightMiddle,
+            8 => Self::RightRing,
+            _ => Self::RightPinky,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutKind {
+    Qwerty,
+    Dvorak,
+    Colemak,
+    Jis,
+}
+
+impl LayoutKind {
+    pub const ALL: [LayoutKind; 4] = [
+        LayoutKind::Qwerty,
+        LayoutKind::Dvorak,
+        LayoutKind::Colemak,
+        LayoutKind::Jis,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Qwerty => "qwerty",
+            Self::Dvorak => "dvorak",
+            Self::Colemak => "colemak",
+            Self::Jis => "jis",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.label().eq_ignore_ascii_case(value.trim()))
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Qwerty => Self::Dvorak,
+            Self::Dvorak => Self::Colemak,
+            Self::Colemak => Self::Jis,
+            Self::Jis => Self::Qwerty,
+        }
+    }
+
+    pub fn layout(self) -> &'static KeyboardLayout {
+        match self {
+            Self::Qwerty => &QWERTY,
+            Self::Dvorak => &DVORAK,
+            Self::Colemak => &COLEMAK,
+            Self::Jis => &JIS,
+        }
+    }
+}
+
+/// A keyboard layout as rows of keys, each row given as the unshifted and
+/// shifted characters in key order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyboardLayout {
+    pub name: &'static str,
+    rows: [(&'static str, &'static str); 4],
+    /// Keys left of `1` on the number row, which shift its finger columns.
+    number_row_lead: usize,
+}
+
+pub const QWERTY: KeyboardLayout = KeyboardLayout {
+    name: "QWERTY",
+    rows: [
+        ("`1234567890-=", "~!@#$%^&*()_+"),
+        ("qwertyuiop[]\\", "QWERTYUIOP{}|"),
+        ("asdfghjkl;'", "ASDFGHJKL:\""),
+        ("zxcvbnm,./", "ZXCVBNM<>?"),
+    ],
+    number_row_lead: 1,
+};
+
+pub const DVORAK: KeyboardLayout = KeyboardLayout {
+    name: "Dvorak",
+    rows: [
+        ("`1234567890[]", "~!@#$%^&*(){}"),
+        ("',.pyfgcrl/=\\", "\"<>PYFGCRL?+|"),
+        ("aoeuidhtns-", "AOEUIDHTNS_"),
+        (";qjkxbmwvz", ":QJKXBMWVZ"),
+    ],
+    number_row_lead: 1,
+};
+
+pub const COLEMAK: KeyboardLayout = KeyboardLayout {
+    name: "Colemak",
+    rows: [
+        ("`1234567890-=", "~!@#$%^&*()_+"),
+        ("qwfpgjluy;[]\\", "QWFPGJLUY:{}|"),
+        ("arstdhneio'", "ARSTDHNEIO\""),
+        ("zxcvbkm,./", "ZXCVBKM<>?"),
+    ],
+    number_row_lead: 1,
+};
+
+/// Japanese JIS layout. `0` has no shifted character, so it repeats itself.
+pub const JIS: KeyboardLayout = KeyboardLayout {
+    name: "JIS",
+    rows: [
+        ("1234567890-^¥", "!\"#$%&'()0=~|"),
+        ("qwertyuiop@[", "QWERTYUIOP`{"),
+        ("asdfghjkl;:]", "ASDFGHJKL+*}"),
+        ("zxcvbnm,./\\", "ZXCVBNM<>?_"),
+    ],
+    number_row_lead: 0,
+};
+
+impl KeyboardLayout {
+    /// Unshifted key labels of each row, excluding the space bar.
+    pub fn rows(&self) -> impl Iterator<Item = &'static str> {
+        self.rows.iter().map(|(base, _)| *base)
+    }
+
+    /// Finds the key that types `ch`, with or without shift.
+    pub fn key_position(&self, ch: char) -> Option<KeyPosition> {
+        if ch == ' ' {
+            return Some(KeyPosition {
+                row: SPACE_ROW,
+                column: 0,
+            });
+        }
+
+        self.rows
+            .iter()
+            .enumerate()
+            .find_map(|(row, (base, shifted))| {
+                base.chars()
+                    .position(|key| key == ch)
+                    .or_else(|| shifted.chars().position(|key| key == ch))
+                    .map(|column| KeyPosition { row, column })
+            })
+    }
+
+    pub fn finger(&self, position: KeyPosition) -> Finger {
+        match position.row {
+            SPACE_ROW => Finger::Thumb,
+            0 => Finger::for_column(position.column.saturating_sub(self.number_row_lead)),
+            _ => Finger::for_column(position.column),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn finger_for(&self, ch: char) -> Option<Finger> {
+        self.key_position(ch).map(|position| self.finger(position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_position_maps_shifted_characters_to_the_same_key() {
+        assert_eq!(QWERTY.key_position('a'), QWERTY.key_position('A'));
+        assert_eq!(
+            QWERTY.key_position('?'),
+            Some(KeyPosition { row: 3, column: 9 })
+        );
+        assert_eq!(QWERTY.key_position(' ').map(|key| key.row), Some(SPACE_ROW));
+        assert_eq!(QWERTY.key_position('é'), None);
+    }
+
+    #[test]
+    fn fingers_follow_touch_typing_columns_in_every_layout() {
+        assert_eq!(QWERTY.finger_for('a'), Some(Finger::LeftPinky));
+        assert_eq!(QWERTY.finger_for('g'), Some(Finger::LeftIndex));
+        assert_eq!(QWERTY.finger_for('`'), Some(Finger::LeftPinky));
+        assert_eq!(QWERTY.finger_for('5'), Some(Finger::LeftIndex));
+        assert_eq!(QWERTY.finger_for('P'), Some(Finger::RightPinky));
+        assert_eq!(QWERTY.finger_for(' '), Some(Finger::Thumb));
+
+        assert_eq!(DVORAK.finger_for('e'), Some(Finger::LeftMiddle));
+        assert_eq!(DVORAK.finger_for('s'), Some(Finger::RightPinky));
+        assert_eq!(COLEMAK.finger_for('t'), Some(Finger::LeftIndex));
+        assert_eq!(COLEMAK.finger_for('n'), Some(Finger::RightIndex));
+        assert_eq!(JIS.finger_for('1'), Some(Finger::LeftPinky));
+        assert_eq!(JIS.finger_for('@'), Some(Finger::RightPinky));
+    }
+
+    #[test]
+    fn every_layout_places_each_letter_exactly_once() {
+        for kind in LayoutKind::ALL {
+            let layout = kind.layout();
+            for letter in 'a'..='z' {
+                let count = layout
+                    .rows()
+                    .flat_map(str::chars)
+                    .filter(|ch| *ch == letter)
+                    .count();
+                assert_eq!(count, 1, "{} places {letter} {count} times", layout.name);
+            }
+        }
+    }
+
+    #[test]
+    fn layout_kind_parses_labels_and_cycles() {
+        assert_eq!(LayoutKind::parse(" Dvorak "), Some(LayoutKind::Dvorak));
+        assert_eq!(LayoutKind::Jis.next(), LayoutKind::Qwerty);
+        assert_eq!(Finger::LeftIndex.hand(), Some(Hand::Left));
+        assert_eq!(Finger::Thumb.hand(), None);
+    }
+}
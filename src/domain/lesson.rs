@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::layout::KeyboardLayout;
+
+/// Words typed in each lesson run.
+pub const LESSON_WORDS: usize = 20;
+/// Punctuation taught in the symbols lesson, when the layout has the key.
+const LESSON_SYMBOLS: &str = ".,;:'\"-?!()";
+
+/// Which keys a lesson introduces, resolved against the configured layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LessonKeys {
+    /// Letters on one letter row, counted from the top (1 = top, 2 = home,
+    /// 3 = bottom).
+    LetterRow(usize),
+    Digits,
+    Symbols,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lesson {
+    /// Stable key for saved progress.
+    pub id: &'static str,
+    pub title: &'static str,
+    pub keys: LessonKeys,
+    pub target_wpm: f64,
+    pub target_accuracy: f64,
+}
+
+impl Lesson {
+    pub fn new_keys(&self, layout: &KeyboardLayout) -> Vec<char> {
+        match self.keys {
+            LessonKeys::LetterRow(row) => layout
+                .rows()
+                .nth(row)
+                .unwrap_or_default()
+                .chars()
+                .filter(char::is_ascii_alphabetic)
+                .collect(),
+            LessonKeys::Digits => layout
+                .rows()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .filter(char::is_ascii_digit)
+                .collect(),
+            LessonKeys::Symbols => LESSON_SYMBOLS
+                .chars()
+                .filter(|ch| layout.key_position(*ch).is_some())
+                .collect(),
+        }
+    }
+
+    pub fn passes(&self, wpm: f64, accuracy: f64) -> bool {
+        wpm >= self.target_wpm && accuracy >= self.target_accuracy
+    }
+}
+
+pub const CURRICULUM: [Lesson; 5] = [
+    Lesson {
+        id: "home-row",
+        title: "Home row",
+        keys: LessonKeys::LetterRow(2),
+        target_wpm: 15.0,
+        target_accuracy: 95.0,
+    },
+    Lesson {
+        id: "top-row",
+        title: "Top row",
+        keys: LessonKeys::LetterRow(1),
+        target_wpm: 18.0,
+        target_accuracy: 95.0,
+    },
+    Lesson {
+        id: "bottom-row",
+        title: "Bottom row",
+        keys: LessonKeys::LetterRow(3),
+        target_wpm: 20.0,
+        target_accuracy: 95.0,
+    },
+    Lesson {
+        id: "numbers",
+        title: "Numbers",
+        keys: LessonKeys::Digits,
+        target_wpm: 20.0,
+        target_accuracy: 95.0,
+    },
+    Lesson {
+        id: "symbols",
+        title: "Symbols",
+        keys: LessonKeys::Symbols,
+        target_wpm: 20.0,
+        target_accuracy: 95.0,
+    },
+];
+
+/// Keys unlocked up to and including the lesson at `index`.
+pub fn unlocked_keys(index: usize, layout: &KeyboardLayout) -> Vec<char> {
+    CURRICULUM
+        .iter()
+        .take(index + 1)
+        .flat_map(|lesson| lesson.new_keys(layout))
+        .collect()
+}
+
+/// Best result of a lesson, saved whether or not it passed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LessonRecord {
+    pub best_wpm: f64,
+    pub best_accuracy: f64,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LessonProgress {
+    #[serde(default)]
+    pub lessons: BTreeMap<String, LessonRecord>,
+}
+
+impl LessonProgress {
+    pub fn record(&self, lesson: &Lesson) -> Option<&LessonRecord> {
+        self.lessons.get(lesson.id)
+    }
+
+    pub fn is_passed(&self, lesson: &Lesson) -> bool {
+        self.record(lesson).is_some_and(|record| record.passed)
+    }
+
+    /// The first lesson is always open; each later one opens once the lesson
+    /// before it is passed.
+    pub fn is_unlocked(&self, index: usize) -> bool {
+        index == 0
+            || CURRICULUM
+                .get(index - 1)
+                .is_some_and(|previous| self.is_passed(previous))
+    }
+
+    /// Keeps the best speed and accuracy separately and remembers a pass.
+    /// Returns whether this run passed.
+    pub fn record_run(&mut self, lesson: &Lesson, wpm: f64, accuracy: f64) -> bool {
+        let passed = lesson.passes(wpm, accuracy);
+        let record = self.lessons.entry(lesson.id.to_string()).or_default();
+        record.best_wpm = record.best_wpm.max(wpm);
+        record.best_accuracy = record.best_accuracy.max(accuracy);
+        record.passed |= passed;
+        passed
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LessonLoadReport {
+    pub progress: LessonProgress,
+    pub warnings: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::layout::{DVORAK, QWERTY};
+
+    #[test]
+    fn lesson_keys_follow_the_layout() {
+        let home_row = CURRICULUM.first().map(|lesson| lesson.new_keys(&QWERTY));
+        assert_eq!(home_row, Some("asdfghjkl".chars().collect()));
+        let dvorak_home = CURRICULUM.first().map(|lesson| lesson.new_keys(&DVORAK));
+        assert_eq!(dvorak_home, Some("aoeuidhtns".chars().collect()));
+        let digits = CURRICULUM.get(3).map(|lesson| lesson.new_keys(&QWERTY));
+        assert_eq!(digits, Some("1234567890".chars().collect()));
+    }
+
+    #[test]
+    fn unlocked_keys_accumulate_earlier_lessons() {
+        let keys = unlocked_keys(1, &QWERTY);
+
+        assert!(keys.contains(&'a'));
+        assert!(keys.contains(&'q'));
+        assert!(!keys.contains(&'z'));
+    }
+
+    #[test]
+    fn passing_a_lesson_unlocks_the_next_and_keeps_best_results() {
+        let mut progress = LessonProgress::default();
+        let [home_row, ..] = CURRICULUM;
+        assert!(progress.is_unlocked(0));
+        assert!(!progress.is_unlocked(1));
+
+        assert!(!progress.record_run(&home_row, 30.0, 80.0));
+        assert!(!progress.is_unlocked(1));
+        assert!(progress.record_run(&home_row, 16.0, 97.0));
+        assert!(!progress.record_run(&home_row, 10.0, 90.0));
+
+        assert!(progress.is_unlocked(1));
+        assert!(!progress.is_unlocked(2));
+        let record = progress.record(&home_row).copied().unwrap_or_default();
+        assert!(record.passed);
+        assert!((record.best_wpm - 30.0).abs() < f64::EPSILON);
+        assert!((record.best_accuracy - 97.0).abs() < f64::EPSILON);
+    }
+}
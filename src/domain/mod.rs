@@ -1,4 +1,13 @@
+pub mod adaptive;
 pub mod config;
+pub mod drill;
 pub mod entity;
+pub mod ghost;
 pub mod history;
+pub mod layout;
+pub mod lesson;
+pub mod profile;
+pub mod replay;
 pub mod rhythm;
+pub mod text_content;
+pub mod timestamp;
@@ -0,0 +1,59 @@
+//! Named profiles, so people sharing a machine keep their own settings and
+//! history.
+
+/// Name shown for the settings and history kept directly in the config
+/// directory, which is used when no profile is chosen.
+pub const DEFAULT_PROFILE: &str = "default";
+/// Longest profile name, in characters.
+pub const MAX_NAME_LEN: usize = 32;
+
+/// The name shown for `profile`, where `None` is the default profile.
+pub fn label(profile: Option<&str>) -> &str {
+    profile.unwrap_or(DEFAULT_PROFILE)
+}
+
+/// Checks a new profile name and returns it trimmed. Names become directory
+/// names, so they are limited to ASCII letters, digits, `-`, `_` and `.`.
+pub fn validate_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Profile name is empty".into());
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(format!(
+            "Profile names are at most {MAX_NAME_LEN} characters"
+        ));
+    }
+    if name.eq_ignore_ascii_case(DEFAULT_PROFILE) {
+        return Err(format!("`{DEFAULT_PROFILE}` is the profile without a name"));
+    }
+    if name.starts_with('.')
+        || !name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+    {
+        return Err(
+            "Profile names use letters, digits, `-`, `_` and `.`, and do not start with `.`".into(),
+        );
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_must_be_safe_directory_names() {
+        assert_eq!(validate_name("  alice "), Ok("alice"));
+        assert_eq!(validate_name("team-b_2.0"), Ok("team-b_2.0"));
+        assert!(validate_name("").is_err());
+        assert!(validate_name("Default").is_err());
+        assert!(validate_name("../bob").is_err());
+        assert!(validate_name(".hidden").is_err());
+        assert!(validate_name("two words").is_err());
+        assert!(validate_name(&"a".repeat(MAX_NAME_LEN + 1)).is_err());
+        assert_eq!(label(None), "default");
+        assert_eq!(label(Some("alice")), "alice");
+    }
+}
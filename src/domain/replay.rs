@@ -0,0 +1,263 @@
+use super::history::{Keystroke, keystroke_misses};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplaySpeed {
+    Normal,
+    Double,
+    Quadruple,
+}
+
+impl ReplaySpeed {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "1×",
+            Self::Double => "2×",
+            Self::Quadruple => "4×",
+        }
+    }
+
+    pub fn factor(self) -> u64 {
+        match self {
+            Self::Normal => 1,
+            Self::Double => 2,
+            Self::Quadruple => 4,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Normal => Self::Double,
+            Self::Double => Self::Quadruple,
+            Self::Quadruple => Self::Normal,
+        }
+    }
+}
+
+/// Plays a recorded keystroke timeline back against its target text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    target: String,
+    keystrokes: Vec<Keystroke>,
+    elapsed_ms: u64,
+    speed: ReplaySpeed,
+    paused: bool,
+}
+
+impl Replay {
+    pub fn new(target: &str, keystrokes: &[Keystroke]) -> Option<Self> {
+        if target.is_empty() || keystrokes.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            target: target.to_string(),
+            keystrokes: keystrokes.to_vec(),
+            elapsed_ms: 0,
+            speed: ReplaySpeed::Normal,
+            paused: false,
+        })
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Moves the replay clock forward by `real_ms` of wall-clock time, scaled
+    /// by the playback speed.
+    pub fn advance(&mut self, real_ms: u64) {
+        if self.paused {
+            return;
+        }
+
+        let scaled = real_ms.saturating_mul(self.speed.factor());
+        self.elapsed_ms = self
+            .elapsed_ms
+            .saturating_add(scaled)
+            .min(self.duration_ms());
+    }
+
+    pub fn elapsed_ms(&self) -> u64 {
+        self.elapsed_ms
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.keystrokes
+            .last()
+            .map_or(0, |keystroke| keystroke.elapsed_ms)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed_ms >= self.duration_ms()
+    }
+
+    pub fn speed(&self) -> ReplaySpeed {
+        self.speed
+    }
+
+    pub fn set_speed(&mut self, speed: ReplaySpeed) {
+        self.speed = speed;
+    }
+
+    pub fn cycle_speed(&mut self) {
+        self.speed = self.speed.next();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pausing at the end restarts playback from the beginning instead.
+    pub fn toggle_pause(&mut self) {
+        if self.is_finished() {
+            self.restart();
+        } else {
+            self.paused = !self.paused;
+        }
+    }
+
+    pub fn restart(&mut self) {
+        self.elapsed_ms = 0;
+        self.paused = false;
+    }
+
+    /// Fraction of the recording that has been played, from 0.0 to 1.0.
+    pub fn progress(&self) -> f64 {
+        let duration = self.duration_ms();
+        if duration == 0 {
+            return 1.0;
+        }
+
+        #[expect(clippy::cast_precision_loss)]
+        let progress = self.elapsed_ms as f64 / duration as f64;
+        progress.clamp(0.0, 1.0)
+    }
+
+    /// Rebuilds the input as it was at the current replay time. Positions that
+    /// jump forward by more than one character came from skipping a word and
+    /// are filled with whitespace, as they were while typing.
+    pub fn inputs(&self) -> Vec<char> {
+        let mut inputs = Vec::new();
+        for keystroke in self.played() {
+            if keystroke.position <= inputs.len() {
+                inputs.truncate(keystroke.position);
+            } else if keystroke.position == inputs.len() + 1 {
+                inputs.push(keystroke.key.unwrap_or(' '));
+            } else {
+                inputs.resize(keystroke.position, ' ');
+            }
+        }
+        inputs
+    }
+
+    /// Counts typed keys and misses up to `elapsed_ms`.
+    pub fn counts_until(&self, elapsed_ms: u64) -> (usize, usize) {
+        let misses = keystroke_misses(&self.target, &self.keystrokes);
+        self.keystrokes
+            .iter()
+            .zip(misses)
+            .take_while(|(keystroke, _)| keystroke.elapsed_ms <= elapsed_ms)
+            .filter(|(keystroke, _)| keystroke.key.is_some())
+            .fold((0, 0), |(typed, total), (_, miss)| {
+                (typed + 1, total + usize::from(miss))
+            })
+    }
+
+    fn played(&self) -> impl Iterator<Item = &Keystroke> {
+        self.keystrokes
+            .iter()
+            .take_while(|keystroke| keystroke.elapsed_ms <= self.elapsed_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+
+    fn keystroke(elapsed_ms: u64, key: Option<char>, position: usize) -> Keystroke {
+        Keystroke {
+            elapsed_ms,
+            key,
+            position,
+        }
+    }
+
+    fn replay() -> Replay {
+        Replay::new(
+            "ab cde",
+            &[
+                keystroke(100, Some('a'), 1),
+                keystroke(200, Some('x'), 2),
+                keystroke(300, None, 1),
+                keystroke(400, Some('b'), 2),
+                keystroke(500, Some(' '), 3),
+                keystroke(600, Some('c'), 4),
+                keystroke(700, Some(' '), 6),
+            ],
+        )
+        .expect("timeline should be replayable")
+    }
+
+    #[test]
+    fn new_requires_text_and_keystrokes() {
+        assert!(Replay::new("ab", &[]).is_none());
+        assert!(Replay::new("", &[keystroke(1, Some('a'), 1)]).is_none());
+    }
+
+    #[test]
+    fn inputs_include_mistakes_and_backspaces_at_each_point() {
+        let mut replay = replay();
+
+        replay.advance(200);
+        assert_eq!(replay.inputs(), vec!['a', 'x']);
+
+        replay.advance(100);
+        assert_eq!(replay.inputs(), vec!['a']);
+
+        replay.advance(400);
+        assert_eq!(replay.inputs(), vec!['a', 'b', ' ', 'c', ' ', ' ']);
+        assert!(replay.is_finished());
+    }
+
+    #[test]
+    fn speed_scales_advance_and_clamps_to_duration() {
+        let mut replay = replay();
+        replay.set_speed(ReplaySpeed::Quadruple);
+
+        replay.advance(100);
+        assert_eq!(replay.elapsed_ms(), 400);
+
+        replay.advance(1000);
+        assert_eq!(replay.elapsed_ms(), 700);
+        assert!((replay.progress() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn pause_stops_the_clock_and_restarts_when_finished() {
+        let mut replay = replay();
+        replay.toggle_pause();
+        replay.advance(300);
+        assert_eq!(replay.elapsed_ms(), 0);
+
+        replay.toggle_pause();
+        replay.advance(5000);
+        replay.toggle_pause();
+        assert_eq!(replay.elapsed_ms(), 0);
+        assert!(!replay.is_paused());
+    }
+
+    #[test]
+    fn counts_until_matches_live_miss_rules() {
+        let replay = replay();
+
+        assert_eq!(replay.counts_until(250), (2, 1));
+        assert_eq!(replay.counts_until(700), (6, 2));
+    }
+
+    #[test]
+    fn speed_cycles_through_supported_rates() {
+        assert_eq!(ReplaySpeed::Normal.next(), ReplaySpeed::Double);
+        assert_eq!(ReplaySpeed::Quadruple.next(), ReplaySpeed::Normal);
+        assert_eq!(ReplaySpeed::Double.label(), "2×");
+    }
+}
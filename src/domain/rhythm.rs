@@ -20,6 +20,8 @@ pub struct RhythmStats {
     pub hit: usize,
     pub ok: usize,
     pub miss: usize,
+    /// Longest run of hit or OK notes without a miss.
+    pub max_combo: usize,
     pub accuracy: f64,
 }
 
@@ -48,6 +50,7 @@ pub struct RhythmSession {
     ok: usize,
     miss: usize,
     combo: usize,
+    max_combo: usize,
     last_judgement: Option<RhythmJudgement>,
     elapsed_seconds: f64,
     speed: u8,
@@ -68,6 +71,7 @@ impl RhythmSession {
             ok: 0,
             miss: 0,
             combo: 0,
+            max_combo: 0,
             last_judgement: None,
             elapsed_seconds: 0.0,
             speed,
@@ -110,6 +114,7 @@ impl RhythmSession {
                 self.combo = 0;
             }
         }
+        self.max_combo = self.max_combo.max(self.combo);
         self.last_judgement = Some(judgement);
         judgement
     }
@@ -148,6 +153,7 @@ impl RhythmSession {
             hit: self.hit,
             ok: self.ok,
             miss: self.miss,
+            max_combo: self.max_combo,
             accuracy,
         }
     }
@@ -156,6 +162,14 @@ impl RhythmSession {
         self.last_judgement
     }
 
+    pub fn elapsed_seconds(&self) -> f64 {
+        self.elapsed_seconds
+    }
+
+    pub fn speed(&self) -> u8 {
+        self.speed
+    }
+
     pub fn combo(&self) -> usize {
         self.combo
     }
@@ -335,6 +349,7 @@ mod tests {
         assert_eq!(session.push_char('x'), RhythmJudgement::Miss);
 
         assert_eq!(session.combo(), 0);
+        assert_eq!(session.stats().max_combo, 1);
     }
 
     #[test]
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+/// Which kinds of characters and words generated text may contain. Text is
+/// generated as usual and then reduced to the allowed content, after which
+/// numbers and symbols are added when requested.
+#[expect(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextContent {
+    #[serde(default = "allowed")]
+    pub capitals: bool,
+    #[serde(default = "allowed")]
+    pub punctuation: bool,
+    /// Words that are uncommon in the sample text.
+    #[serde(default = "allowed")]
+    pub rare_words: bool,
+    /// Adds numbers between words.
+    #[serde(default)]
+    pub numbers: bool,
+    /// Adds symbols around words.
+    #[serde(default)]
+    pub symbols: bool,
+}
+
+impl Default for TextContent {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+impl TextContent {
+    /// Text as generated, with nothing removed or added.
+    pub const FULL: Self = Self {
+        capitals: true,
+        punctuation: true,
+        rare_words: true,
+        numbers: false,
+        symbols: false,
+    };
+
+    pub fn is_full(self) -> bool {
+        self == Self::FULL
+    }
+
+    /// Whether characters or words are removed from the generated text.
+    pub fn restricts(self) -> bool {
+        !(self.capitals && self.punctuation && self.rare_words)
+    }
+
+    /// Applies the removals of `limit` on top of these options. Symbols are
+    /// dropped along with punctuation, since they are harder still.
+    pub fn limited_to(self, limit: Self) -> Self {
+        Self {
+            capitals: self.capitals && limit.capitals,
+            punctuation: self.punctuation && limit.punctuation,
+            rare_words: self.rare_words && limit.rare_words,
+            numbers: self.numbers,
+            symbols: self.symbols && limit.punctuation,
+        }
+    }
+
+    pub fn label(self) -> String {
+        if self.is_full() {
+            return "full text".to_string();
+        }
+
+        let mut parts = [
+            (self.capitals, "capitals", "lowercase"),
+            (self.punctuation, "punctuation", "no punctuation"),
+            (self.rare_words, "rare words", "common words"),
+        ]
+        .into_iter()
+        .map(|(allowed, on, off)| if allowed { on } else { off })
+        .collect::<Vec<_>>();
+        parts.extend(
+            [(self.numbers, "numbers"), (self.symbols, "symbols")]
+                .into_iter()
+                .filter_map(|(added, label)| added.then_some(label)),
+        );
+        parts.join(", ")
+    }
+}
+
+fn allowed() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_names_each_restriction() {
+        assert_eq!(TextContent::FULL.label(), "full text");
+        assert_eq!(
+            TextContent {
+                capitals: false,
+                rare_words: false,
+                ..TextContent::FULL
+            }
+            .label(),
+            "lowercase, punctuation, common words"
+        );
+        assert_eq!(
+            TextContent {
+                numbers: true,
+                symbols: true,
+                ..TextContent::FULL
+            }
+            .label(),
+            "capitals, punctuation, rare words, numbers, symbols"
+        );
+    }
+
+    #[test]
+    fn limited_to_keeps_the_stricter_removals() {
+        let chosen = TextContent {
+            capitals: false,
+            numbers: true,
+            symbols: true,
+            ..TextContent::FULL
+        };
+        let limit = TextContent {
+            punctuation: false,
+            rare_words: false,
+            ..TextContent::FULL
+        };
+
+        assert_eq!(
+            chosen.limited_to(limit),
+            TextContent {
+                capitals: false,
+                punctuation: false,
+                rare_words: false,
+                numbers: true,
+                symbols: false,
+            }
+        );
+        assert!(!TextContent::FULL.limited_to(TextContent::FULL).restricts());
+    }
+}
@@ -0,0 +1,66 @@
+//! UTC calendar dates for the Unix timestamps saved with history entries.
+
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Days since the Unix epoch of the UTC day containing `seconds`.
+pub fn day_number(seconds: u64) -> u64 {
+    seconds / SECONDS_PER_DAY
+}
+
+/// Year, month and day of a day number, using the proleptic Gregorian
+/// calendar.
+pub fn civil_date(day_number: u64) -> (u64, u64, u64) {
+    // Shift the epoch to 0000-03-01 so leap days fall at the end of each
+    // 400-year era.
+    let days = day_number + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// `YYYY-MM-DD` of a day number.
+pub fn format_date(day_number: u64) -> String {
+    let (year, month, day) = civil_date(day_number);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC.
+pub fn format_date_time(seconds: u64) -> String {
+    let minutes = seconds % SECONDS_PER_DAY / 60;
+    format!(
+        "{} {:02}:{:02}",
+        format_date(day_number(seconds)),
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_date_handles_the_epoch_and_leap_days() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(11_016), (2000, 2, 29));
+        assert_eq!(civil_date(11_017), (2000, 3, 1));
+        assert_eq!(civil_date(20_745), (2026, 10, 19));
+    }
+
+    #[test]
+    fn format_date_time_pads_each_part() {
+        assert_eq!(format_date_time(1_792_389_143), "2026-10-19 05:52");
+        assert_eq!(format_date(0), "1970-01-01");
+    }
+}
@@ -3,6 +3,7 @@
 //! This application provides a typing game with countdown timer,
 //! real-time WPM calculation, and optional background music.
 
+mod cli;
 mod config;
 mod domain;
 mod presentation;
@@ -11,52 +12,60 @@ mod usecase;
 
 use crossterm::{
     cursor::SetCursorStyle,
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{
+        DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+    terminal::{
+        EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+        supports_keyboard_enhancement,
+    },
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 use rodio::DeviceSinkBuilder;
+use std::env;
 use std::io::{self, stdout};
+use std::process;
 use std::sync::{Arc, Mutex, mpsc};
 
 use domain::config::AppConfig;
+use domain::history::KeyEventMode;
 use presentation::bgm_handler::BgmHandler;
 use presentation::ui::app::App;
 
 fn main() -> io::Result<()> {
-    let (loaded_config, config_message) = match config::load_config() {
-        Ok(report) => {
-            let message = if report.warnings.is_empty() {
-                None
-            } else {
-                Some(format!("Config warning: {}", report.warnings.join(" / ")))
-            };
-            (report.config, message)
+    let invocation = match cli::parse(env::args().skip(1)) {
+        Ok(invocation) => invocation,
+        Err(message) => {
+            eprintln!("rtyping: {message}\n\n{}", cli::USAGE);
+            process::exit(2);
         }
-        Err(err) => (
-            AppConfig::default(),
-            Some(format!("Failed to load config: {err}")),
-        ),
     };
-    let (history_entries, history_message) = match config::load_history() {
-        Ok(report) => {
-            let message = if report.warnings.is_empty() {
-                None
-            } else {
-                Some(format!("History warning: {}", report.warnings.join(" / ")))
-            };
-            (report.entries, message)
+    let profile = match config::find_profile(invocation.profile.as_deref()) {
+        Ok(profile) => profile,
+        Err(err) => {
+            eprintln!("rtyping: {err}");
+            process::exit(1);
         }
-        Err(err) => (Vec::new(), Some(format!("Failed to load history: {err}"))),
     };
+    if invocation.command != cli::Command::Play {
+        if let Err(err) = cli::run(profile.as_deref(), invocation.command) {
+            eprintln!("rtyping: {err}");
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let mut app = App::new(AppConfig::default());
+    runtime::load_profile(&mut app, profile);
 
     let mut audio_sink = DeviceSinkBuilder::open_default_sink()
         .map_err(|err| io::Error::other(format!("failed to open audio device: {err}")))?;
     audio_sink.log_on_drop(false);
     let (snd_sender, snd_receiver) = mpsc::channel();
 
-    if loaded_config.game.sound_enabled_value() {
+    if app.config().game.sound_enabled_value() {
         let bgm_handler = BgmHandler::new(snd_receiver);
         bgm_handler.start();
     }
@@ -64,17 +73,23 @@ fn main() -> io::Result<()> {
     enable_raw_mode()?;
     let mut stdout = stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Terminals implementing the kitty keyboard protocol report key releases,
+    // which hold-time analytics need. Others keep working with presses only.
+    let keyboard_enhanced = matches!(supports_keyboard_enhancement(), Ok(true))
+        && execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(
+                KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                    | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+                    | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
+            )
+        )
+        .is_ok();
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new(loaded_config);
-    app.set_history_entries(history_entries);
-    let startup_messages = [config_message, history_message]
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>();
-    if !startup_messages.is_empty() {
-        app.set_status_message(startup_messages.join(" / "));
+    if keyboard_enhanced {
+        app.set_key_event_mode(KeyEventMode::PressRelease);
     }
 
     let timer = Arc::new(Mutex::new(0i32));
@@ -92,6 +107,9 @@ fn main() -> io::Result<()> {
         &timeout_rx,
     );
 
+    if keyboard_enhanced {
+        execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags)?;
+    }
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -65,8 +65,20 @@ impl App {
         }
     }
 
-    pub fn toggle_sound_enabled(&mut self) {
-        self.config.game.toggle_sound();
+    pub fn cycle_config_choice(&mut self) {
+        match self.config_field {
+            ConfigField::GameTestMode => self.config.game.cycle_test_mode(),
+            ConfigField::GameCorrection => self.config.game.cycle_correction(),
+            ConfigField::GameSpaceSkip => self.config.game.toggle_space_skip(),
+            ConfigField::GameLayout => self.config.game.cycle_layout(),
+            ConfigField::GameLowercaseOnly => self.config.game.toggle_lowercase_only(),
+            ConfigField::GamePunctuation => self.config.game.toggle_punctuation(),
+            ConfigField::GameNumbers => self.config.game.toggle_numbers(),
+            ConfigField::GameSymbols => self.config.game.toggle_symbols(),
+            ConfigField::GameAdaptive => self.config.game.toggle_adaptive(),
+            ConfigField::GameSoundEnabled => self.config.game.toggle_sound(),
+            _ => {}
+        }
     }
 
     fn selected_config_field_mut(&mut self) -> &mut String {
@@ -78,10 +90,22 @@ impl App {
             ConfigField::GroqApiKey => &mut self.config.groq.api_key,
             ConfigField::GroqModel => &mut self.config.groq.model,
             ConfigField::GameTimeout => &mut self.config.game.timeout,
+            ConfigField::GameTestMode => &mut self.config.game.test_mode,
+            ConfigField::GameWordCount => &mut self.config.game.word_count,
+            ConfigField::GameCorrection => &mut self.config.game.correction,
+            ConfigField::GameFailAccuracy => &mut self.config.game.fail_accuracy,
+            ConfigField::GameSpaceSkip => &mut self.config.game.space_skip,
+            ConfigField::GameLayout => &mut self.config.game.layout,
             ConfigField::GameTextScale => &mut self.config.game.text_scale,
+            ConfigField::GameLowercaseOnly => &mut self.config.game.lowercase_only,
+            ConfigField::GamePunctuation => &mut self.config.game.punctuation,
+            ConfigField::GameNumbers => &mut self.config.game.numbers,
+            ConfigField::GameSymbols => &mut self.config.game.symbols,
             ConfigField::GameRhythmSpeed => &mut self.config.game.rhythm_speed,
+            ConfigField::GameAdaptive => &mut self.config.game.adaptive,
             ConfigField::GameFreq => &mut self.config.game.freq,
             ConfigField::GameSoundEnabled => &mut self.config.game.sound_enabled,
+            ConfigField::GameHistoryPath => &mut self.config.game.history_path,
         }
     }
 
@@ -94,10 +118,22 @@ impl App {
             ConfigField::GroqApiKey => &self.config.groq.api_key,
             ConfigField::GroqModel => &self.config.groq.model,
             ConfigField::GameTimeout => &self.config.game.timeout,
+            ConfigField::GameTestMode => &self.config.game.test_mode,
+            ConfigField::GameWordCount => &self.config.game.word_count,
+            ConfigField::GameCorrection => &self.config.game.correction,
+            ConfigField::GameFailAccuracy => &self.config.game.fail_accuracy,
+            ConfigField::GameSpaceSkip => &self.config.game.space_skip,
+            ConfigField::GameLayout => &self.config.game.layout,
             ConfigField::GameTextScale => &self.config.game.text_scale,
+            ConfigField::GameLowercaseOnly => &self.config.game.lowercase_only,
+            ConfigField::GamePunctuation => &self.config.game.punctuation,
+            ConfigField::GameNumbers => &self.config.game.numbers,
+            ConfigField::GameSymbols => &self.config.game.symbols,
             ConfigField::GameRhythmSpeed => &self.config.game.rhythm_speed,
+            ConfigField::GameAdaptive => &self.config.game.adaptive,
             ConfigField::GameFreq => &self.config.game.freq,
             ConfigField::GameSoundEnabled => &self.config.game.sound_enabled,
+            ConfigField::GameHistoryPath => &self.config.game.history_path,
         }
     }
 
@@ -207,7 +243,7 @@ mod tests {
     fn sound_enabled_ignores_text_cursor_editing() {
         let mut app = App::new(app_config());
         app.open_config();
-        for _ in 0..10 {
+        for _ in 0..21 {
             app.move_config_down();
         }
 
@@ -219,4 +255,20 @@ mod tests {
         assert_eq!(app.config().game.sound_enabled, "false");
         assert_eq!(app.config_cursor_index(), 0);
     }
+
+    #[test]
+    fn test_mode_cycles_with_choice_input_and_ignores_text_editing() {
+        let mut app = App::new(app_config());
+        app.open_config();
+        for _ in 0..7 {
+            app.move_config_down();
+        }
+
+        app.edit_config_char('x');
+        app.cycle_config_choice();
+
+        assert_eq!(app.config_field(), ConfigField::GameTestMode);
+        assert_eq!(app.config().game.test_mode, "words");
+        assert_eq!(app.config_cursor_index(), 0);
+    }
 }
@@ -0,0 +1,134 @@
+use crate::domain::drill::{DRILL_ITEM_LIMIT, DRILL_WORDS, DrillCard, DrillDeck};
+use crate::domain::history::TestLength;
+use crate::usecase::drill;
+use crate::usecase::generate_sentence::{self, GenerationSource};
+
+use super::{App, GameMode};
+
+impl App {
+    pub fn drill_deck(&self) -> &DrillDeck {
+        &self.drill_deck
+    }
+
+    pub fn set_drill_deck(&mut self, deck: DrillDeck) {
+        self.drill_deck = deck;
+    }
+
+    /// Whether there is anything to drill, now or later.
+    pub fn has_drills(&self) -> bool {
+        !self.drill_deck.cards.is_empty() || !self.weak_drill_items.is_empty()
+    }
+
+    pub fn due_drill_items(&self) -> Vec<String> {
+        self.drill_deck
+            .due_items(&self.weak_drill_items, drill::today())
+    }
+
+    /// Prepares a words test on the items due today, most overdue first.
+    /// Returns `false` when nothing is due.
+    pub fn start_drill(&mut self) -> bool {
+        let mut items = self.due_drill_items();
+        items.truncate(DRILL_ITEM_LIMIT);
+        if items.is_empty() {
+            return false;
+        }
+
+        let text = generate_sentence::generate_drill(&items, DRILL_WORDS);
+        self.set_practice_mode(false);
+        self.set_next_game_mode(GameMode::Standard);
+        self.set_generation_source(GenerationSource::Local);
+        self.prepare_new_game(text);
+        self.test_length = TestLength::Words(DRILL_WORDS);
+        self.active_drill = Some(items);
+        true
+    }
+
+    pub fn active_drill(&self) -> Option<&[String]> {
+        self.active_drill.as_deref()
+    }
+
+    /// Grades each drilled item from this run and reschedules it. Returns
+    /// `false` when no drill is active or no item came up.
+    pub fn record_drill_result(&mut self) -> bool {
+        let Some(items) = &self.active_drill else {
+            return false;
+        };
+
+        self.drill_grades = drill::grade(&self.target_string, &self.keystrokes, items);
+        let today = drill::today();
+        for (item, quality) in &self.drill_grades {
+            self.drill_deck.review(item, *quality, today);
+        }
+        !self.drill_grades.is_empty()
+    }
+
+    /// Graded items of the finished drill with their new schedule.
+    pub fn drill_results(&self) -> Vec<(&str, u8, Option<&DrillCard>)> {
+        self.drill_grades
+            .iter()
+            .map(|(item, quality)| (item.as_str(), *quality, self.drill_deck.cards.get(item)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::domain::config::AppConfig;
+    use crate::domain::history::{AttemptCount, HistoryEntry};
+
+    fn app_with_mistakes() -> App {
+        let mut app = App::new(AppConfig::default());
+        app.set_history_entries(vec![HistoryEntry {
+            char_attempts: BTreeMap::from([(
+                'q',
+                AttemptCount {
+                    attempts: 20,
+                    misses: 4,
+                },
+            )]),
+            ..HistoryEntry::default()
+        }]);
+        app
+    }
+
+    #[test]
+    fn missed_characters_are_due_until_drilled_well() {
+        let mut app = app_with_mistakes();
+        assert!(app.has_drills());
+        assert_eq!(app.due_drill_items(), ["q"]);
+
+        assert!(app.start_drill());
+        assert!(
+            app.target_string()
+                .split_whitespace()
+                .all(|word| word.contains('q'))
+        );
+        assert!(app.build_history_entry().is_none());
+
+        app.start_typing();
+        let target = app.target_string().to_string();
+        for ch in target.chars() {
+            app.push_char(ch);
+        }
+        assert!(app.record_drill_result());
+
+        let results = app.drill_results();
+        assert_eq!(
+            results.first().map(|(item, quality, _)| (*item, *quality)),
+            Some(("q", 5))
+        );
+        assert!(app.due_drill_items().is_empty());
+    }
+
+    #[test]
+    fn nothing_due_does_not_start_a_drill() {
+        let mut app = App::new(AppConfig::default());
+
+        assert!(!app.has_drills());
+        assert!(!app.start_drill());
+        assert!(!app.record_drill_result());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::domain::ghost::{Ghost, GhostGap};
+use crate::usecase::generate_sentence::GenerationSource;
+use crate::usecase::personal_best::{self, BestCategory};
+
+use super::{App, AppState, GameMode};
+
+/// Only the most recent recorded runs are offered as ghosts.
+const MAX_GHOST_CANDIDATES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GhostCandidate {
+    pub entry_index: usize,
+    pub personal_best: bool,
+}
+
+impl App {
+    pub fn open_ghost_select(&mut self) {
+        self.state = AppState::GhostSelect;
+        self.ghost_selected = 0;
+        self.clear_status_message();
+    }
+
+    /// Lists runs that can be raced: the personal best of the configured test
+    /// length and current source first, followed by the latest recorded runs.
+    /// Only timed runs are raced; failed runs and entries saved without a
+    /// keystroke timeline are skipped.
+    pub fn ghost_candidates(&self) -> Vec<GhostCandidate> {
+        let raceable = self
+            .history_entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                entry.mode.is_timed()
+                    && !entry.failed
+                    && !entry.keystrokes.is_empty()
+                    && !entry.target_text.is_empty()
+            })
+            .collect::<Vec<_>>();
+        let category =
+            BestCategory::timed(self.planned_test_length(), self.generation_source.label());
+        let best_index = personal_best::fastest(raceable.iter().copied(), &category);
+
+        let mut candidates = best_index
+            .map(|entry_index| GhostCandidate {
+                entry_index,
+                personal_best: true,
+            })
+            .into_iter()
+            .collect::<Vec<_>>();
+        candidates.extend(
+            raceable
+                .iter()
+                .rev()
+                .filter(|(index, _)| Some(*index) != best_index)
+                .take(MAX_GHOST_CANDIDATES - candidates.len())
+                .map(|(index, _)| GhostCandidate {
+                    entry_index: *index,
+                    personal_best: false,
+                }),
+        );
+        candidates
+    }
+
+    pub fn ghost_selected(&self) -> usize {
+        self.ghost_selected
+    }
+
+    pub fn move_ghost_selection_up(&mut self) {
+        self.ghost_selected = self.ghost_selected.saturating_sub(1);
+    }
+
+    pub fn move_ghost_selection_down(&mut self) {
+        let last = self.ghost_candidates().len().saturating_sub(1);
+        self.ghost_selected = (self.ghost_selected + 1).min(last);
+    }
+
+    /// Prepares a standard game on the selected run's text with the same test
+    /// length, and attaches the run as a ghost. Returns `false` when nothing is
+    /// selected.
+    pub fn start_ghost_race(&mut self) -> bool {
+        let Some(candidate) = self.ghost_candidates().get(self.ghost_selected).copied() else {
+            return false;
+        };
+        let Some(entry) = self.history_entries.get(candidate.entry_index).cloned() else {
+            return false;
+        };
+        let label = if candidate.personal_best {
+            format!("Personal best {:.1} WPM", entry.wpm)
+        } else {
+            format!("{:.1} WPM run", entry.wpm)
+        };
+
+        self.set_practice_mode(false);
+        self.set_next_game_mode(GameMode::Standard);
+        self.set_generation_source(
+            GenerationSource::from_label(&entry.generation_source)
+                .unwrap_or(GenerationSource::Local),
+        );
+        self.prepare_new_game(entry.target_text.clone());
+        self.test_length = entry.test_length();
+        self.ghost = Ghost::from_entry(label, &entry);
+        self.ghost.is_some()
+    }
+
+    pub fn ghost(&self) -> Option<&Ghost> {
+        self.ghost.as_ref()
+    }
+
+    pub fn ghost_position(&self) -> Option<usize> {
+        self.ghost
+            .as_ref()
+            .map(|ghost| ghost.position_at(self.typing_elapsed_ms()))
+    }
+
+    pub fn ghost_gap(&self) -> Option<GhostGap> {
+        self.ghost
+            .as_ref()
+            .map(|ghost| ghost.gap(self.inputs.len(), self.typing_elapsed_ms()))
+    }
+
+    /// Compares against the ghost at the moment of the last keystroke, so the
+    /// result does not drift while the result screen is open.
+    pub fn final_ghost_gap(&self) -> Option<GhostGap> {
+        let finished_ms = self
+            .keystrokes
+            .last()
+            .map_or(0, |keystroke| keystroke.elapsed_ms);
+        self.ghost
+            .as_ref()
+            .map(|ghost| ghost.gap(self.inputs.len(), finished_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::{AppConfig, GameSettings};
+    use crate::domain::history::{HistoryEntry, Keystroke, TestLength};
+
+    fn recorded_entry(wpm: f64, text: &str) -> HistoryEntry {
+        HistoryEntry {
+            wpm,
+            generation_source: "Groq".to_string(),
+            test: Some(TestLength::Words(2)),
+            target_text: text.to_string(),
+            keystrokes: vec![Keystroke {
+                elapsed_ms: 500,
+                key: text.chars().next(),
+                position: 1,
+            }],
+            ..HistoryEntry::default()
+        }
+    }
+
+    fn two_word_app() -> App {
+        let mut app = App::new(AppConfig {
+            game: GameSettings {
+                test_mode: "words".to_string(),
+                word_count: "2".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        });
+        app.set_generation_source(GenerationSource::Groq);
+        app
+    }
+
+    fn app_with_history() -> App {
+        let mut app = two_word_app();
+        app.set_history_entries(vec![
+            recorded_entry(40.0, "old run"),
+            HistoryEntry {
+                wpm: 99.0,
+                ..HistoryEntry::default()
+            },
+            recorded_entry(70.0, "best run"),
+            HistoryEntry {
+                failed: true,
+                ..recorded_entry(120.0, "failed run")
+            },
+            recorded_entry(50.0, "new run"),
+        ]);
+        app
+    }
+
+    #[test]
+    fn ghost_candidates_put_personal_best_first_then_latest_runs() {
+        let app = app_with_history();
+
+        assert_eq!(
+            app.ghost_candidates(),
+            vec![
+                GhostCandidate {
+                    entry_index: 2,
+                    personal_best: true,
+                },
+                GhostCandidate {
+                    entry_index: 4,
+                    personal_best: false,
+                },
+                GhostCandidate {
+                    entry_index: 0,
+                    personal_best: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ghost_candidates_take_the_personal_best_of_the_current_category() {
+        let mut app = two_word_app();
+        app.set_history_entries(vec![
+            recorded_entry(60.0, "two words"),
+            HistoryEntry {
+                test: Some(TestLength::Time(30)),
+                ..recorded_entry(90.0, "thirty seconds")
+            },
+            HistoryEntry {
+                generation_source: "Local".to_string(),
+                ..recorded_entry(80.0, "local words")
+            },
+        ]);
+
+        assert_eq!(
+            app.ghost_candidates().first(),
+            Some(&GhostCandidate {
+                entry_index: 0,
+                personal_best: true,
+            })
+        );
+
+        app.set_generation_source(GenerationSource::Local);
+        assert_eq!(
+            app.ghost_candidates().first(),
+            Some(&GhostCandidate {
+                entry_index: 2,
+                personal_best: true,
+            })
+        );
+    }
+
+    #[test]
+    fn ghost_candidates_list_latest_runs_without_a_personal_best() {
+        let mut app = App::new(AppConfig::default());
+        app.set_history_entries(vec![recorded_entry(60.0, "two words")]);
+
+        assert_eq!(
+            app.ghost_candidates(),
+            vec![GhostCandidate {
+                entry_index: 0,
+                personal_best: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn ghost_candidates_are_empty_without_recorded_timelines() {
+        let mut app = App::new(AppConfig::default());
+        app.set_history_entries(vec![HistoryEntry::default()]);
+
+        assert!(app.ghost_candidates().is_empty());
+    }
+
+    #[test]
+    fn start_ghost_race_reuses_text_and_test_length_of_the_run() {
+        let mut app = app_with_history();
+        app.open_ghost_select();
+        app.move_ghost_selection_down();
+
+        assert!(app.start_ghost_race());
+
+        assert_eq!(app.target_string(), "new run");
+        assert_eq!(app.test_length(), TestLength::Words(2));
+        assert_eq!(app.generation_source(), GenerationSource::Groq);
+        assert_eq!(app.ghost().map(Ghost::label), Some("50.0 WPM run"));
+        assert_eq!(app.ghost_position(), Some(0));
+    }
+
+    #[test]
+    fn ghost_selection_stays_within_candidates() {
+        let mut app = app_with_history();
+        app.open_ghost_select();
+        for _ in 0..5 {
+            app.move_ghost_selection_down();
+        }
+        assert_eq!(app.ghost_selected(), 2);
+
+        for _ in 0..5 {
+            app.move_ghost_selection_up();
+        }
+        assert_eq!(app.ghost_selected(), 0);
+    }
+
+    #[test]
+    fn new_game_clears_the_ghost() {
+        let mut app = app_with_history();
+        app.open_ghost_select();
+        app.start_ghost_race();
+
+        app.prepare_new_game("fresh".to_string());
+
+        assert!(app.ghost().is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use crate::domain::history::TestLength;
+use crate::domain::lesson::{self, CURRICULUM, LESSON_WORDS, Lesson, LessonProgress};
+use crate::usecase::generate_sentence::{self, GenerationSource};
+
+use super::{App, AppState, GameMode};
+
+impl App {
+    /// Opens the lesson list on the first lesson that has not been passed.
+    pub fn open_lessons(&mut self) {
+        self.state = AppState::Lessons;
+        self.lesson_selected = CURRICULUM
+            .iter()
+            .position(|lesson| !self.lesson_progress.is_passed(lesson))
+            .unwrap_or(CURRICULUM.len() - 1);
+        self.clear_status_message();
+    }
+
+    pub fn lesson_progress(&self) -> &LessonProgress {
+        &self.lesson_progress
+    }
+
+    pub fn set_lesson_progress(&mut self, progress: LessonProgress) {
+        self.lesson_progress = progress;
+    }
+
+    pub fn lesson_selected(&self) -> usize {
+        self.lesson_selected
+    }
+
+    pub fn move_lesson_selection_up(&mut self) {
+        self.lesson_selected = self.lesson_selected.saturating_sub(1);
+        self.clear_status_message();
+    }
+
+    pub fn move_lesson_selection_down(&mut self) {
+        self.lesson_selected = (self.lesson_selected + 1).min(CURRICULUM.len() - 1);
+        self.clear_status_message();
+    }
+
+    /// Prepares a words test on text limited to the keys unlocked so far.
+    /// Returns `false` when the selected lesson is still locked.
+    pub fn start_selected_lesson(&mut self) -> bool {
+        let index = self.lesson_selected;
+        let Some(lesson) = CURRICULUM.get(index) else {
+            return false;
+        };
+        if !self.lesson_progress.is_unlocked(index) {
+            return false;
+        }
+
+        let layout = self.keyboard_layout();
+        let text = generate_sentence::generate_lesson(
+            &lesson::unlocked_keys(index, layout),
+            &lesson.new_keys(layout),
+            LESSON_WORDS,
+        );
+        self.set_practice_mode(false);
+        self.set_next_game_mode(GameMode::Standard);
+        self.set_generation_source(GenerationSource::Local);
+        self.prepare_new_game(text);
+        self.test_length = TestLength::Words(LESSON_WORDS);
+        self.active_lesson = Some(index);
+        true
+    }
+
+    pub fn active_lesson(&self) -> Option<&'static Lesson> {
+        self.active_lesson.and_then(|index| CURRICULUM.get(index))
+    }
+
+    pub fn active_lesson_number(&self) -> Option<usize> {
+        self.active_lesson.map(|index| index + 1)
+    }
+
+    pub fn lesson_new_keys(&self) -> Vec<char> {
+        self.active_lesson()
+            .map(|lesson| lesson.new_keys(self.keyboard_layout()))
+            .unwrap_or_default()
+    }
+
+    /// Whether the finished lesson run met its targets. Failed runs never pass.
+    pub fn lesson_run_passed(&self) -> Option<bool> {
+        let (wpm, accuracy) = self.run_wpm_and_accuracy();
+        self.active_lesson()
+            .map(|lesson| !self.failed && lesson.passes(wpm, accuracy))
+    }
+
+    /// Adds the finished lesson run to the saved progress. Returns `false`
+    /// when no lesson is active or the run failed.
+    pub fn record_lesson_result(&mut self) -> bool {
+        let Some(lesson) = self.active_lesson() else {
+            return false;
+        };
+        if self.failed {
+            return false;
+        }
+
+        let (wpm, accuracy) = self.run_wpm_and_accuracy();
+        self.lesson_progress.record_run(lesson, wpm, accuracy);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::AppConfig;
+
+    fn new_app() -> App {
+        App::new(AppConfig::default())
+    }
+
+    fn type_target(app: &mut App) {
+        let target = app.target_string().to_string();
+        for ch in target.chars() {
+            app.push_char(ch);
+        }
+    }
+
+    #[test]
+    fn locked_lessons_cannot_start() {
+        let mut app = new_app();
+        app.open_lessons();
+        assert_eq!(app.lesson_selected(), 0);
+
+        app.move_lesson_selection_down();
+        assert!(!app.start_selected_lesson());
+        assert_eq!(app.state(), AppState::Lessons);
+    }
+
+    #[test]
+    fn lesson_runs_use_unlocked_keys_and_are_not_saved_to_history() {
+        let mut app = new_app();
+        app.open_lessons();
+
+        assert!(app.start_selected_lesson());
+        assert_eq!(app.test_length(), TestLength::Words(LESSON_WORDS));
+        assert!(
+            app.target_string()
+                .chars()
+                .all(|ch| ch == ' ' || "asdfghjkl".contains(ch))
+        );
+        assert_eq!(
+            app.lesson_new_keys(),
+            "asdfghjkl".chars().collect::<Vec<_>>()
+        );
+        assert!(app.build_history_entry().is_none());
+    }
+
+    #[test]
+    fn passing_a_lesson_is_recorded_and_selects_the_next_one() {
+        let mut app = new_app();
+        app.open_lessons();
+        app.start_selected_lesson();
+        app.start_typing();
+        type_target(&mut app);
+        app.update_timer(10);
+
+        assert_eq!(app.lesson_run_passed(), Some(true));
+        assert!(app.record_lesson_result());
+        app.open_lessons();
+        assert_eq!(app.lesson_selected(), 1);
+        assert!(app.lesson_progress().is_unlocked(1));
+    }
+
+    #[test]
+    fn other_games_clear_the_active_lesson() {
+        let mut app = new_app();
+        app.open_lessons();
+        app.start_selected_lesson();
+
+        app.prepare_new_game("abc".to_string());
+
+        assert!(app.active_lesson().is_none());
+        assert!(!app.record_lesson_result());
+    }
+}
@@ -13,7 +13,18 @@ impl App {
         if self.config.groq.is_ready() {
             items.push(MenuItem::StartGameGroq);
         }
-        items.extend([MenuItem::Stats, MenuItem::Config]);
+        if !self.ghost_candidates().is_empty() {
+            items.push(MenuItem::RaceGhost);
+        }
+        if self.has_drills() {
+            items.push(MenuItem::DrillWeakKeys);
+        }
+        items.extend([
+            MenuItem::Lessons,
+            MenuItem::Stats,
+            MenuItem::Config,
+            MenuItem::Profiles,
+        ]);
         items
     }
 
@@ -49,6 +60,7 @@ impl App {
 mod tests {
     use super::*;
     use crate::domain::config::AppConfig;
+    use crate::domain::history::{HistoryEntry, Keystroke};
 
     fn new_app() -> App {
         App::new(AppConfig::default())
@@ -75,8 +87,10 @@ mod tests {
                 MenuItem::StartGame,
                 MenuItem::PracticeMode,
                 MenuItem::StartGameRhythm,
+                MenuItem::Lessons,
                 MenuItem::Stats,
                 MenuItem::Config,
+                MenuItem::Profiles,
             ]
         );
     }
@@ -99,12 +113,30 @@ mod tests {
                 MenuItem::StartGameRhythm,
                 MenuItem::StartGameGoogle,
                 MenuItem::StartGameGroq,
+                MenuItem::Lessons,
                 MenuItem::Stats,
                 MenuItem::Config,
+                MenuItem::Profiles,
             ]
         );
     }
 
+    #[test]
+    fn visible_menu_items_offer_ghost_race_once_a_run_is_recorded() {
+        let mut app = new_app();
+        app.set_history_entries(vec![HistoryEntry {
+            target_text: "ab".to_string(),
+            keystrokes: vec![Keystroke {
+                elapsed_ms: 100,
+                key: Some('a'),
+                position: 1,
+            }],
+            ..HistoryEntry::default()
+        }]);
+
+        assert!(app.visible_menu_items().contains(&MenuItem::RaceGhost));
+    }
+
     #[test]
     fn menu_navigation_skips_incomplete_provider_entries() {
         let mut app = new_app();
@@ -115,6 +147,9 @@ mod tests {
         app.move_menu_down();
         assert_eq!(app.menu_selected(), MenuItem::StartGameRhythm);
 
+        app.move_menu_down();
+        assert_eq!(app.menu_selected(), MenuItem::Lessons);
+
         app.move_menu_down();
         assert_eq!(app.menu_selected(), MenuItem::Stats);
     }
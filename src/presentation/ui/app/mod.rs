@@ -1,24 +1,59 @@
 mod config_editor;
+mod drills;
+mod ghost;
+mod lessons;
 mod menu;
+mod profiles;
+mod recovery;
+mod replay;
+mod stats;
 mod typing;
 
-use crate::domain::config::AppConfig;
-use crate::domain::history::{HistoryEntry, HistoryMode};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use crate::domain::adaptive::AdaptivePlan;
+use crate::domain::config::{AppConfig, CorrectionPolicy};
+use crate::domain::drill::DrillDeck;
+use crate::domain::ghost::Ghost;
+use crate::domain::history::{
+    AttemptCount, HISTORY_SCHEMA_VERSION, HistoryEntry, HistoryMode, KeyEventMode, KeyRelease,
+    Keystroke, RhythmRecord, TestLength,
+};
+use crate::domain::lesson::LessonProgress;
+use crate::domain::replay::Replay;
 use crate::domain::rhythm::{RhythmJudgement, RhythmSession, RhythmStats};
+use crate::domain::text_content::TextContent;
 use crate::usecase::accuracy;
-use crate::usecase::generate_sentence::GenerationSource;
+use crate::usecase::adaptive;
+use crate::usecase::drill;
+use crate::usecase::generate_sentence::{GenerationSource, TextTarget};
+use crate::usecase::history_filter::HistoryFilter;
+use crate::usecase::history_record;
 use crate::usecase::history_stats::{self, HistoryStats};
+use crate::usecase::personal_best;
+use crate::usecase::progress::ProgressView;
 use crate::usecase::wpm;
 
+pub use profiles::ProfilePrompt;
+pub use recovery::HistoryRecovery;
+use stats::StatsReports;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AppState {
     Menu,
     Config,
     Stats,
+    HistoryBrowser,
+    GhostSelect,
+    Lessons,
+    Profiles,
     Loading,
     Typing,
     RhythmTyping,
     Result,
+    Replay,
+    HistoryRecovery,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,8 +63,77 @@ pub enum MenuItem {
     StartGameRhythm,
     StartGameGoogle,
     StartGameGroq,
+    RaceGhost,
+    DrillWeakKeys,
+    Lessons,
     Stats,
     Config,
+    Profiles,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPage {
+    Summary,
+    Progress,
+    Bests,
+    Ngrams,
+    Keyboard,
+    Fingers,
+}
+
+impl StatsPage {
+    pub const ALL: [StatsPage; 6] = [
+        StatsPage::Summary,
+        StatsPage::Progress,
+        StatsPage::Bests,
+        StatsPage::Ngrams,
+        StatsPage::Keyboard,
+        StatsPage::Fingers,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Summary => "Summary",
+            Self::Progress => "Progress",
+            Self::Bests => "Bests",
+            Self::Ngrams => "N-grams",
+            Self::Keyboard => "Keyboard",
+            Self::Fingers => "Fingers",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Summary => Self::Progress,
+            Self::Progress => Self::Bests,
+            Self::Bests => Self::Ngrams,
+            Self::Ngrams => Self::Keyboard,
+            Self::Keyboard => Self::Fingers,
+            Self::Fingers => Self::Summary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatmapMetric {
+    ErrorRate,
+    Latency,
+}
+
+impl HeatmapMetric {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ErrorRate => "error rate",
+            Self::Latency => "latency",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::ErrorRate => Self::Latency,
+            Self::Latency => Self::ErrorRate,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,14 +145,26 @@ pub enum ConfigField {
     GroqApiKey,
     GroqModel,
     GameTimeout,
+    GameTestMode,
+    GameWordCount,
+    GameCorrection,
+    GameFailAccuracy,
+    GameSpaceSkip,
+    GameLayout,
     GameTextScale,
+    GameLowercaseOnly,
+    GamePunctuation,
+    GameNumbers,
+    GameSymbols,
     GameRhythmSpeed,
+    GameAdaptive,
     GameFreq,
     GameSoundEnabled,
+    GameHistoryPath,
 }
 
 impl ConfigField {
-    pub const ALL: [ConfigField; 11] = [
+    pub const ALL: [ConfigField; 23] = [
         ConfigField::GoogleApiUrl,
         ConfigField::GoogleApiKey,
         ConfigField::GoogleModel,
@@ -56,14 +172,38 @@ impl ConfigField {
         ConfigField::GroqApiKey,
         ConfigField::GroqModel,
         ConfigField::GameTimeout,
+        ConfigField::GameTestMode,
+        ConfigField::GameWordCount,
+        ConfigField::GameCorrection,
+        ConfigField::GameFailAccuracy,
+        ConfigField::GameSpaceSkip,
+        ConfigField::GameLayout,
         ConfigField::GameTextScale,
+        ConfigField::GameLowercaseOnly,
+        ConfigField::GamePunctuation,
+        ConfigField::GameNumbers,
+        ConfigField::GameSymbols,
         ConfigField::GameRhythmSpeed,
+        ConfigField::GameAdaptive,
         ConfigField::GameFreq,
         ConfigField::GameSoundEnabled,
+        ConfigField::GameHistoryPath,
     ];
 
     pub fn accepts_text(self) -> bool {
-        self != ConfigField::GameSoundEnabled
+        !matches!(
+            self,
+            ConfigField::GameTestMode
+                | ConfigField::GameCorrection
+                | ConfigField::GameSpaceSkip
+                | ConfigField::GameLayout
+                | ConfigField::GameLowercaseOnly
+                | ConfigField::GamePunctuation
+                | ConfigField::GameNumbers
+                | ConfigField::GameSymbols
+                | ConfigField::GameAdaptive
+                | ConfigField::GameSoundEnabled
+        )
     }
 }
 
@@ -81,11 +221,20 @@ pub struct App {
     typed_count: usize,
     incorrects: usize,
     missed_chars: Vec<char>,
+    char_attempts: BTreeMap<char, AttemptCount>,
+    mistake_positions: Vec<usize>,
+    keystrokes: Vec<Keystroke>,
+    key_releases: Vec<KeyRelease>,
+    key_event_mode: KeyEventMode,
+    typing_started_at: Option<Instant>,
     wpm_history: Vec<u64>,
     wpm_activity_revision: u64,
     last_wpm_activity_timer: Option<i32>,
     last_wpm_sample: Option<(i32, usize, usize, u64)>,
     timer: i32,
+    test_length: TestLength,
+    failed: bool,
+    personal_best_gain: Option<f64>,
     practice_mode: bool,
     should_quit: bool,
     time_started: bool,
@@ -98,13 +247,53 @@ pub struct App {
     status_message: Option<String>,
     generation_source: GenerationSource,
     history_entries: Vec<HistoryEntry>,
+    /// The `history_path` setting `history_entries` were loaded with. New
+    /// runs are saved there until history is loaded again.
+    history_path: String,
+    /// The timed runs of `history_entries`, which the statistics describe.
+    timed_history: Vec<HistoryEntry>,
+    stats_filter: HistoryFilter,
+    /// The entries of `history_entries` that match `stats_filter`.
+    stats_history: Vec<HistoryEntry>,
+    stats_reports: StatsReports,
+    history_recovery: Option<HistoryRecovery>,
     next_game_mode: GameMode,
     active_game_mode: GameMode,
     rhythm_session: Option<RhythmSession>,
+    ghost: Option<Ghost>,
+    ghost_selected: usize,
+    replay: Option<Replay>,
+    replay_return: AppState,
+    history_selected: usize,
+    stats_page: StatsPage,
+    progress_view: ProgressView,
+    heatmap_metric: HeatmapMetric,
+    lesson_progress: LessonProgress,
+    lesson_selected: usize,
+    active_lesson: Option<usize>,
+    drill_deck: DrillDeck,
+    weak_drill_items: Vec<String>,
+    active_drill: Option<Vec<String>>,
+    drill_grades: Vec<(String, u8)>,
+    adaptive_plan: Option<AdaptivePlan>,
+    text_difficulty: Option<f64>,
+    text_content: TextContent,
+    /// The profile whose settings and history are loaded, or `None` for the
+    /// default profile.
+    profile: Option<String>,
+    profile_names: Vec<String>,
+    profile_selected: usize,
+    profile_prompt: Option<ProfilePrompt>,
 }
 
 impl App {
     pub fn new(config: AppConfig) -> Self {
+        let history_path = config.game.history_path.clone();
+        let stats_reports = StatsReports::compute(
+            &[],
+            ProgressView::default(),
+            config.game.layout_value().layout(),
+        );
         Self {
             state: AppState::Menu,
             target_string: String::new(),
@@ -112,11 +301,20 @@ impl App {
             typed_count: 0,
             incorrects: 0,
             missed_chars: Vec::new(),
+            char_attempts: BTreeMap::new(),
+            mistake_positions: Vec::new(),
+            keystrokes: Vec::new(),
+            key_releases: Vec::new(),
+            key_event_mode: KeyEventMode::PressOnly,
+            typing_started_at: None,
             wpm_history: Vec::new(),
             wpm_activity_revision: 0,
             last_wpm_activity_timer: None,
             last_wpm_sample: None,
             timer: 0,
+            test_length: TestLength::Time(0),
+            failed: false,
+            personal_best_gain: None,
             practice_mode: false,
             should_quit: false,
             time_started: false,
@@ -129,9 +327,37 @@ impl App {
             status_message: None,
             generation_source: GenerationSource::Local,
             history_entries: Vec::new(),
+            history_path,
+            timed_history: Vec::new(),
+            stats_filter: HistoryFilter::default(),
+            stats_history: Vec::new(),
+            stats_reports,
+            history_recovery: None,
             next_game_mode: GameMode::Standard,
             active_game_mode: GameMode::Standard,
             rhythm_session: None,
+            ghost: None,
+            ghost_selected: 0,
+            replay: None,
+            replay_return: AppState::Result,
+            history_selected: 0,
+            stats_page: StatsPage::Summary,
+            progress_view: ProgressView::default(),
+            heatmap_metric: HeatmapMetric::ErrorRate,
+            lesson_progress: LessonProgress::default(),
+            lesson_selected: 0,
+            active_lesson: None,
+            drill_deck: DrillDeck::default(),
+            weak_drill_items: Vec::new(),
+            active_drill: None,
+            drill_grades: Vec::new(),
+            adaptive_plan: None,
+            text_difficulty: None,
+            text_content: TextContent::FULL,
+            profile: None,
+            profile_names: Vec::new(),
+            profile_selected: 0,
+            profile_prompt: None,
         }
     }
 
@@ -160,8 +386,17 @@ impl App {
         self.status_message = None;
     }
 
+    /// Generated games start here, so this is where adaptive mode picks the
+    /// level for the run and the text content is settled.
     pub fn enter_loading(&mut self) {
         self.state = AppState::Loading;
+        self.adaptive_plan = (self.config.game.adaptive_value() && !self.practice_mode)
+            .then(|| adaptive::plan(&self.timed_history));
+        let chosen = self.config.game.text_content();
+        self.text_content = self
+            .adaptive_plan
+            .as_ref()
+            .map_or(chosen, |plan| chosen.limited_to(plan.content()));
         self.clear_status_message();
     }
 
@@ -172,11 +407,16 @@ impl App {
 
     pub fn open_stats(&mut self) {
         self.state = AppState::Stats;
+        self.stats_page = StatsPage::Summary;
+        self.progress_view = ProgressView::default();
+        self.refresh_stats_history();
         self.clear_status_message();
     }
 
     pub fn return_to_menu(&mut self) {
         self.state = AppState::Menu;
+        self.adaptive_plan = None;
+        self.text_content = TextContent::FULL;
         self.hide_help();
     }
 
@@ -224,10 +464,14 @@ impl App {
         if self.practice_mode {
             0
         } else {
-            self.config.game.timeout_value()
+            self.test_length.timeout()
         }
     }
 
+    pub fn test_length(&self) -> TestLength {
+        self.test_length
+    }
+
     pub fn set_practice_mode(&mut self, practice_mode: bool) {
         self.practice_mode = practice_mode;
     }
@@ -236,6 +480,24 @@ impl App {
         self.practice_mode
     }
 
+    /// Practice mode always keeps wrong characters out of the input, which is
+    /// the letter-stop policy regardless of the configured one.
+    pub fn correction_policy(&self) -> CorrectionPolicy {
+        if self.practice_mode {
+            CorrectionPolicy::LetterStop
+        } else {
+            self.config.game.correction_value()
+        }
+    }
+
+    pub fn fail_accuracy(&self) -> f64 {
+        self.config.game.fail_accuracy_value()
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+
     pub fn is_quit_requested(&self) -> bool {
         self.should_quit
     }
@@ -249,7 +511,33 @@ impl App {
     }
 
     pub fn rhythm_speed(&self) -> u8 {
-        self.config.game.rhythm_speed_value()
+        let configured = self.config.game.rhythm_speed_value();
+        self.adaptive_plan
+            .as_ref()
+            .map_or(configured, |plan| plan.rhythm_speed(configured))
+    }
+
+    pub fn adaptive_plan(&self) -> Option<&AdaptivePlan> {
+        self.adaptive_plan.as_ref()
+    }
+
+    /// Difficulty score of the current target text.
+    pub fn text_difficulty(&self) -> Option<f64> {
+        self.text_difficulty
+    }
+
+    /// The configured test length, with word and passage lengths scaled by
+    /// the adaptive level.
+    fn planned_test_length(&self) -> TestLength {
+        let configured = self.config.game.test_length();
+        let Some(plan) = &self.adaptive_plan else {
+            return configured;
+        };
+        match configured {
+            TestLength::Words(words) => TestLength::Words(plan.scaled_length(words)),
+            TestLength::Passage(scale) => TestLength::Passage(plan.scaled_length(scale)),
+            TestLength::Time(_) => configured,
+        }
     }
 
     pub fn generation_source(&self) -> GenerationSource {
@@ -310,47 +598,211 @@ impl App {
             .is_some_and(RhythmSession::is_complete)
     }
 
-    pub fn generation_settings(&self) -> (usize, GenerationSource, AppConfig) {
+    pub fn generation_settings(&self) -> (TextTarget, GenerationSource, AppConfig, TextContent) {
+        let text_scale = self.config.game.text_scale_value();
+        let target = match self.planned_test_length() {
+            TestLength::Words(words) if !self.practice_mode => TextTarget::Words(words),
+            TestLength::Passage(scale) => TextTarget::Scale(scale),
+            _ => TextTarget::Scale(
+                self.adaptive_plan
+                    .as_ref()
+                    .map_or(text_scale, |plan| plan.scaled_length(text_scale)),
+            ),
+        };
         (
-            self.config.game.text_scale_value(),
+            target,
             self.generation_source,
             self.config.clone(),
+            self.text_content,
         )
     }
 
     pub fn set_history_entries(&mut self, entries: Vec<HistoryEntry>) {
+        self.timed_history = entries
+            .iter()
+            .filter(|entry| entry.mode.is_timed())
+            .cloned()
+            .collect();
+        self.weak_drill_items = drill::weak_items(&self.timed_history);
         self.history_entries = entries;
+        self.refresh_stats_history();
+    }
+
+    /// Adds a session that was just saved, noting by how much it beat the
+    /// personal best of its category.
+    pub fn record_history_entry(&mut self, entry: HistoryEntry) {
+        self.personal_best_gain = personal_best::improvement(&self.history_entries, &entry);
+        let mut entries = std::mem::take(&mut self.history_entries);
+        entries.push(entry);
+        self.set_history_entries(entries);
+    }
+
+    /// WPM gained over the previous personal best by the finished session.
+    pub fn personal_best_gain(&self) -> Option<f64> {
+        self.personal_best_gain
     }
 
     pub fn history_entries(&self) -> &[HistoryEntry] {
         &self.history_entries
     }
 
-    pub fn history_stats(&self) -> HistoryStats {
-        history_stats::summarize(&self.history_entries)
+    pub fn history_path(&self) -> &str {
+        &self.history_path
     }
 
-    pub fn build_history_entry(&self) -> Option<HistoryEntry> {
-        if self.practice_mode {
+    /// Whether the settings point history somewhere other than where it was
+    /// loaded from.
+    pub fn history_path_changed(&self) -> bool {
+        self.history_path.trim() != self.config.game.history_path.trim()
+    }
+
+    /// Replaces the history with the one loaded with another `history_path`.
+    pub fn replace_history(&mut self, history_path: String, entries: Vec<HistoryEntry>) {
+        self.history_path = history_path;
+        self.set_history_entries(entries);
+    }
+
+    /// Numbers of saved practice and rhythm sessions, which the timed
+    /// statistics leave out.
+    pub fn untimed_session_counts(&self) -> (usize, usize) {
+        self.history_entries
+            .iter()
+            .fold((0, 0), |(practice, rhythm), entry| match entry.mode {
+                HistoryMode::Timed => (practice, rhythm),
+                HistoryMode::Practice { .. } => (practice + 1, rhythm),
+                HistoryMode::Rhythm(_) => (practice, rhythm + 1),
+            })
+    }
+
+    /// Average difficulty of the latest texts from the current source, shown
+    /// while the next one is generated.
+    pub fn recent_text_difficulty(&self) -> Option<f64> {
+        const RECENT_LIMIT: usize = 10;
+
+        let scores = self
+            .history_entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.generation_source == self.generation_source.label())
+            .filter_map(|entry| entry.difficulty)
+            .take(RECENT_LIMIT)
+            .collect::<Vec<_>>();
+        if scores.is_empty() {
             return None;
         }
+        let count = f64::from(u32::try_from(scores.len()).unwrap_or(u32::MAX));
+        Some(scores.iter().sum::<f64>() / count)
+    }
 
-        let elapsed = self.timer.max(1);
-        Some(HistoryEntry {
-            wpm: wpm::calc_wpm(
+    /// Summarizes only the runs that used the same test kind and length as the
+    /// current session, so the Result screen compares like with like.
+    pub fn history_stats_for_current_test(&self) -> HistoryStats {
+        let entries = self
+            .timed_history
+            .iter()
+            .filter(|entry| entry.test_length() == self.test_length)
+            .cloned()
+            .collect::<Vec<_>>();
+        history_stats::summarize(&entries)
+    }
+
+    /// Final WPM and accuracy of the run, as shown on the result screen.
+    fn run_wpm_and_accuracy(&self) -> (f64, f64) {
+        (
+            wpm::calc_wpm(
                 self.typed_count(),
-                elapsed,
+                self.timer.max(1),
                 i32::try_from(self.incorrects()).unwrap_or(i32::MAX),
             ),
-            accuracy: accuracy::calc_accuracy(self.typed_count(), self.incorrects()),
+            accuracy::calc_accuracy(self.typed_count(), self.incorrects()),
+        )
+    }
+
+    /// Lessons and drills are not saved to history; they keep their own
+    /// progress.
+    pub fn build_history_entry(&self) -> Option<HistoryEntry> {
+        if self.active_lesson.is_some() || self.active_drill.is_some() {
+            return None;
+        }
+        if self.active_game_mode == GameMode::Rhythm {
+            return self.build_rhythm_history_entry();
+        }
+
+        let (wpm, accuracy) = self.run_wpm_and_accuracy();
+        let (mode, test) = if self.practice_mode {
+            let mode = HistoryMode::Practice {
+                keys_practiced: self.keys_practiced(),
+            };
+            (mode, None)
+        } else {
+            (HistoryMode::Timed, Some(self.test_length))
+        };
+        Some(HistoryEntry {
+            wpm,
+            accuracy,
             miss_count: self.incorrects(),
-            elapsed_seconds: elapsed,
-            generation_source: self.generation_source.label().into(),
-            mode: HistoryMode::Timed,
+            elapsed_seconds: self.timer.max(1),
+            mode,
             missed_chars: self.missed_chars.clone(),
+            char_attempts: self.char_attempts.clone(),
+            word_attempts: self.word_attempts(),
+            test,
+            failed: self.failed,
+            keystrokes: self.keystrokes.clone(),
+            analytics: (!self.keystrokes.is_empty()).then(|| self.speed_analytics()),
+            ..self.history_entry_base()
         })
     }
 
+    /// Rhythm sessions have no timer or keystroke timeline; speed counts the
+    /// notes played in time over the length of the session.
+    fn build_rhythm_history_entry(&self) -> Option<HistoryEntry> {
+        let session = self.rhythm_session.as_ref()?;
+        let stats = session.stats();
+        let elapsed = rounded_seconds(session.elapsed_seconds()).max(1);
+        Some(HistoryEntry {
+            wpm: wpm::calc_wpm(stats.correct, elapsed, 0),
+            accuracy: stats.accuracy,
+            miss_count: stats.miss,
+            elapsed_seconds: elapsed,
+            mode: HistoryMode::Rhythm(RhythmRecord {
+                hit: stats.hit,
+                ok: stats.ok,
+                miss: stats.miss,
+                max_combo: stats.max_combo,
+                speed: session.speed(),
+            }),
+            ..self.history_entry_base()
+        })
+    }
+
+    /// Fields every saved session shares.
+    fn history_entry_base(&self) -> HistoryEntry {
+        HistoryEntry {
+            schema_version: HISTORY_SCHEMA_VERSION,
+            id: history_record::new_id(),
+            timestamp: Some(history_record::now()),
+            generation_source: self.generation_source.label().into(),
+            target_text: self.target_string.clone(),
+            adaptive_step: self.adaptive_plan.as_ref().map(|plan| plan.step),
+            difficulty: self.text_difficulty,
+            text_content: (!self.text_content.is_full()).then_some(self.text_content),
+            ..HistoryEntry::default()
+        }
+    }
+
+    /// Distinct characters of the target up to the cursor, spaces aside, in
+    /// the order they first appear.
+    fn keys_practiced(&self) -> Vec<char> {
+        let mut keys = Vec::new();
+        for ch in self.target_string.chars().take(self.inputs.len()) {
+            if !ch.is_whitespace() && !keys.contains(&ch) {
+                keys.push(ch);
+            }
+        }
+        keys
+    }
+
     pub fn is_help_visible(&self) -> bool {
         self.show_help
     }
@@ -430,7 +882,13 @@ impl App {
     }
 }
 
+/// Whole seconds, rounded up so a session that ran at all counts.
+#[expect(clippy::cast_possible_truncation)]
+fn rounded_seconds(seconds: f64) -> i32 {
+    seconds.ceil().clamp(0.0, f64::from(i32::MAX)) as i32
+}
+
 #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-fn rounded_wpm_sample(current_wpm: f64) -> u64 {
+pub(super) fn rounded_wpm_sample(current_wpm: f64) -> u64 {
     current_wpm.round().clamp(0.0, f64::from(u32::MAX)) as u64
 }
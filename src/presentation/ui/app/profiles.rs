@@ -0,0 +1,229 @@
+use crate::domain::config::AppConfig;
+use crate::domain::profile;
+
+use super::{App, AppState};
+
+/// An action on the profile list that waits for a name or a confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfilePrompt {
+    Create { name: String },
+    Rename { from: String, name: String },
+    Delete { name: String },
+}
+
+impl App {
+    /// The profile in use, or `None` for the default profile.
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    pub fn profile_label(&self) -> &str {
+        profile::label(self.profile())
+    }
+
+    /// Starts over with another profile's config. Everything loaded for the
+    /// previous profile is dropped; only what was learned about the terminal
+    /// is kept.
+    pub fn switch_profile(&mut self, profile: Option<String>, config: AppConfig) {
+        let key_event_mode = self.key_event_mode;
+        *self = Self::new(config);
+        self.key_event_mode = key_event_mode;
+        self.profile = profile;
+    }
+
+    /// Follows a rename of the profile in use.
+    pub fn rename_active_profile(&mut self, name: String) {
+        self.profile = Some(name);
+    }
+
+    /// Opens the profile list on the profile in use. The default profile
+    /// comes first, followed by `names`.
+    pub fn open_profiles(&mut self, names: Vec<String>) {
+        self.state = AppState::Profiles;
+        self.profile_prompt = None;
+        let active = self.profile.clone();
+        self.set_profile_names(names, active.as_deref());
+        self.clear_status_message();
+    }
+
+    pub fn profile_names(&self) -> &[String] {
+        &self.profile_names
+    }
+
+    /// Replaces the listed profiles and selects `selected`.
+    pub fn set_profile_names(&mut self, names: Vec<String>, selected: Option<&str>) {
+        self.profile_names = names;
+        self.profile_selected = selected
+            .and_then(|name| self.profile_names.iter().position(|known| known == name))
+            .map_or(0, |index| index + 1);
+    }
+
+    /// Index of the selected row, where 0 is the default profile.
+    pub fn profile_selected(&self) -> usize {
+        self.profile_selected
+    }
+
+    /// The selected profile, or `None` when the default profile is selected.
+    pub fn selected_profile_name(&self) -> Option<&str> {
+        self.profile_selected
+            .checked_sub(1)
+            .and_then(|index| self.profile_names.get(index))
+            .map(String::as_str)
+    }
+
+    pub fn move_profile_selection_up(&mut self) {
+        self.profile_selected = self.profile_selected.saturating_sub(1);
+        self.clear_status_message();
+    }
+
+    pub fn move_profile_selection_down(&mut self) {
+        self.profile_selected = (self.profile_selected + 1).min(self.profile_names.len());
+        self.clear_status_message();
+    }
+
+    pub fn profile_prompt(&self) -> Option<&ProfilePrompt> {
+        self.profile_prompt.as_ref()
+    }
+
+    pub fn start_profile_create(&mut self) {
+        self.profile_prompt = Some(ProfilePrompt::Create {
+            name: String::new(),
+        });
+        self.clear_status_message();
+    }
+
+    /// Asks for a new name for the selected profile. Returns `false` when
+    /// the default profile is selected, which has no name to change.
+    pub fn start_profile_rename(&mut self) -> bool {
+        let Some(from) = self.selected_profile_name().map(str::to_string) else {
+            return false;
+        };
+        self.profile_prompt = Some(ProfilePrompt::Rename {
+            name: from.clone(),
+            from,
+        });
+        self.clear_status_message();
+        true
+    }
+
+    /// Asks to confirm deleting the selected profile. Returns `false` for
+    /// the default profile and the profile in use.
+    pub fn start_profile_delete(&mut self) -> bool {
+        let Some(name) = self.selected_profile_name().map(str::to_string) else {
+            return false;
+        };
+        if self.profile() == Some(name.as_str()) {
+            return false;
+        }
+        self.profile_prompt = Some(ProfilePrompt::Delete { name });
+        self.clear_status_message();
+        true
+    }
+
+    pub fn push_profile_prompt_char(&mut self, ch: char) {
+        if let Some(ProfilePrompt::Create { name } | ProfilePrompt::Rename { name, .. }) =
+            &mut self.profile_prompt
+            && name.chars().count() < profile::MAX_NAME_LEN
+        {
+            name.push(ch);
+        }
+    }
+
+    pub fn pop_profile_prompt_char(&mut self) {
+        if let Some(ProfilePrompt::Create { name } | ProfilePrompt::Rename { name, .. }) =
+            &mut self.profile_prompt
+        {
+            name.pop();
+        }
+    }
+
+    pub fn take_profile_prompt(&mut self) -> Option<ProfilePrompt> {
+        self.profile_prompt.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::GameSettings;
+
+    fn app_with_profiles(active: Option<&str>) -> App {
+        let mut app = App::new(AppConfig::default());
+        app.profile = active.map(str::to_string);
+        app.open_profiles(vec!["alice".into(), "bob".into()]);
+        app
+    }
+
+    #[test]
+    fn profile_list_opens_on_the_active_profile() {
+        let mut app = app_with_profiles(Some("bob"));
+        assert_eq!(app.state(), AppState::Profiles);
+        assert_eq!(app.profile_selected(), 2);
+        assert_eq!(app.selected_profile_name(), Some("bob"));
+
+        app.move_profile_selection_down();
+        assert_eq!(app.profile_selected(), 2);
+        app.move_profile_selection_up();
+        app.move_profile_selection_up();
+        assert_eq!(app.selected_profile_name(), None);
+        assert_eq!(app_with_profiles(None).profile_selected(), 0);
+    }
+
+    #[test]
+    fn default_and_active_profiles_are_protected() {
+        let mut app = app_with_profiles(Some("bob"));
+        assert!(!app.start_profile_delete());
+
+        app.move_profile_selection_up();
+        assert!(app.start_profile_delete());
+        assert_eq!(
+            app.take_profile_prompt(),
+            Some(ProfilePrompt::Delete {
+                name: "alice".into()
+            })
+        );
+
+        app.move_profile_selection_up();
+        assert!(!app.start_profile_rename());
+        assert!(!app.start_profile_delete());
+        assert_eq!(app.profile_prompt(), None);
+    }
+
+    #[test]
+    fn rename_prompt_starts_from_the_current_name() {
+        let mut app = app_with_profiles(None);
+        app.move_profile_selection_down();
+        assert!(app.start_profile_rename());
+        app.pop_profile_prompt_char();
+        app.push_profile_prompt_char('x');
+
+        assert_eq!(
+            app.profile_prompt(),
+            Some(&ProfilePrompt::Rename {
+                from: "alice".into(),
+                name: "alicx".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn switching_profiles_starts_from_the_new_config() {
+        let mut app = app_with_profiles(None);
+        app.set_status_message("old");
+        let config = AppConfig {
+            game: GameSettings {
+                timeout: "15".into(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        };
+
+        app.switch_profile(Some("alice".into()), config.clone());
+
+        assert_eq!(app.state(), AppState::Menu);
+        assert_eq!(app.profile_label(), "alice");
+        assert_eq!(app.config(), &config);
+        assert_eq!(app.status_message(), None);
+        assert!(app.profile_names().is_empty());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::domain::history::{HistoryBackup, HistoryDamage};
+
+use super::{App, AppState};
+
+/// History that could not be read in full at startup, and the backups it
+/// can be restored from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryRecovery {
+    pub damage: Vec<HistoryDamage>,
+    pub backups: Vec<HistoryBackup>,
+}
+
+impl App {
+    /// Asks how to recover before anything else, so a damaged history is
+    /// never overwritten unnoticed.
+    pub fn open_history_recovery(&mut self, recovery: HistoryRecovery) {
+        self.state = AppState::HistoryRecovery;
+        self.history_recovery = Some(recovery);
+    }
+
+    pub fn history_recovery(&self) -> Option<&HistoryRecovery> {
+        self.history_recovery.as_ref()
+    }
+
+    pub fn close_history_recovery(&mut self) {
+        self.history_recovery = None;
+        self.return_to_menu();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::AppConfig;
+    use crate::domain::history::HistoryFile;
+
+    #[test]
+    fn recovery_prompt_opens_over_the_menu_and_closes_back_to_it() {
+        let mut app = App::new(AppConfig::default());
+        let recovery = HistoryRecovery {
+            damage: vec![HistoryDamage {
+                file: HistoryFile::Log,
+                unreadable: vec![3],
+            }],
+            backups: Vec::new(),
+        };
+
+        app.open_history_recovery(recovery.clone());
+        assert_eq!(app.state(), AppState::HistoryRecovery);
+        assert_eq!(app.history_recovery(), Some(&recovery));
+
+        app.close_history_recovery();
+        assert_eq!(app.state(), AppState::Menu);
+        assert_eq!(app.history_recovery(), None);
+    }
+}
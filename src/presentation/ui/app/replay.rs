@@ -0,0 +1,202 @@
+use crate::domain::replay::{Replay, ReplaySpeed};
+use crate::usecase::wpm;
+
+use super::{App, AppState};
+
+impl App {
+    /// Replays the session that just finished. Returns `false` when nothing
+    /// was typed.
+    pub fn start_result_replay(&mut self) -> bool {
+        let Some(replay) = Replay::new(&self.target_string, &self.keystrokes) else {
+            return false;
+        };
+        self.open_replay(replay, AppState::Result);
+        true
+    }
+
+    pub fn open_history_browser(&mut self) {
+        self.state = AppState::HistoryBrowser;
+        self.history_selected = 0;
+        self.clear_status_message();
+    }
+
+    pub fn history_selected(&self) -> usize {
+        self.history_selected
+    }
+
+    pub fn move_history_selection_up(&mut self) {
+        self.history_selected = self.history_selected.saturating_sub(1);
+    }
+
+    pub fn move_history_selection_down(&mut self) {
+        let last = self.history_entries.len().saturating_sub(1);
+        self.history_selected = (self.history_selected + 1).min(last);
+    }
+
+    /// Maps a browser row, newest first, to its index in saved order.
+    pub fn history_entry_index(&self, row: usize) -> Option<usize> {
+        self.history_entries.len().checked_sub(row + 1)
+    }
+
+    /// Replays the selected saved run. Entries saved before keystroke
+    /// timelines were recorded leave a status message instead.
+    pub fn start_history_replay(&mut self) -> bool {
+        let replay = self
+            .history_entry_index(self.history_selected)
+            .and_then(|index| self.history_entries.get(index))
+            .and_then(|entry| Replay::new(&entry.target_text, &entry.keystrokes));
+        let Some(replay) = replay else {
+            self.set_status_message("This run has no recorded keystrokes to replay");
+            return false;
+        };
+        self.clear_status_message();
+        self.open_replay(replay, AppState::HistoryBrowser);
+        true
+    }
+
+    fn open_replay(&mut self, replay: Replay, return_state: AppState) {
+        self.replay = Some(replay);
+        self.replay_return = return_state;
+        self.state = AppState::Replay;
+    }
+
+    pub fn close_replay(&mut self) {
+        self.replay = None;
+        self.state = self.replay_return;
+    }
+
+    pub fn replay(&self) -> Option<&Replay> {
+        self.replay.as_ref()
+    }
+
+    pub fn advance_replay(&mut self, real_ms: u64) {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.advance(real_ms);
+        }
+    }
+
+    pub fn toggle_replay_pause(&mut self) {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.toggle_pause();
+        }
+    }
+
+    pub fn cycle_replay_speed(&mut self) {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.cycle_speed();
+        }
+    }
+
+    pub fn set_replay_speed(&mut self, speed: ReplaySpeed) {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.set_speed(speed);
+        }
+    }
+
+    pub fn restart_replay(&mut self) {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.restart();
+        }
+    }
+
+    /// One WPM sample per second of the recording, computed the same way as
+    /// the live header.
+    pub fn replay_wpm_samples(&self) -> Vec<u64> {
+        let Some(replay) = self.replay.as_ref() else {
+            return Vec::new();
+        };
+
+        let seconds = replay.duration_ms().div_ceil(1000).max(1);
+        (1..=seconds)
+            .map(|second| {
+                let (typed, misses) = replay.counts_until(second * 1000);
+                let elapsed = i32::try_from(second).unwrap_or(i32::MAX);
+                let score =
+                    wpm::calc_wpm(typed, elapsed, i32::try_from(misses).unwrap_or(i32::MAX));
+                super::rounded_wpm_sample(score)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::AppConfig;
+    use crate::domain::history::{HistoryEntry, Keystroke};
+
+    fn recorded(text: &str) -> HistoryEntry {
+        HistoryEntry {
+            target_text: text.to_string(),
+            keystrokes: vec![
+                Keystroke {
+                    elapsed_ms: 400,
+                    key: Some('a'),
+                    position: 1,
+                },
+                Keystroke {
+                    elapsed_ms: 1500,
+                    key: Some('b'),
+                    position: 2,
+                },
+            ],
+            ..HistoryEntry::default()
+        }
+    }
+
+    #[test]
+    fn result_replay_returns_to_result() {
+        let mut app = App::new(AppConfig::default());
+        app.prepare_new_game("ab".to_string());
+        app.start_typing();
+        app.push_char('a');
+        app.finish_typing();
+
+        assert!(app.start_result_replay());
+        assert_eq!(app.state(), AppState::Replay);
+        assert_eq!(app.replay().map(Replay::target), Some("ab"));
+
+        app.close_replay();
+        assert_eq!(app.state(), AppState::Result);
+        assert!(app.replay().is_none());
+    }
+
+    #[test]
+    fn result_replay_needs_keystrokes() {
+        let mut app = App::new(AppConfig::default());
+        app.prepare_new_game("ab".to_string());
+
+        assert!(!app.start_result_replay());
+        assert_eq!(app.state(), AppState::Menu);
+    }
+
+    #[test]
+    fn history_browser_lists_newest_first_and_replays_selection() {
+        let mut app = App::new(AppConfig::default());
+        app.set_history_entries(vec![recorded("old"), HistoryEntry::default()]);
+        app.open_history_browser();
+
+        assert_eq!(app.history_entry_index(0), Some(1));
+        assert!(!app.start_history_replay());
+        assert!(app.status_message().is_some());
+
+        app.move_history_selection_down();
+        app.move_history_selection_down();
+        assert_eq!(app.history_selected(), 1);
+        assert!(app.start_history_replay());
+        assert_eq!(app.replay().map(Replay::target), Some("old"));
+
+        app.close_replay();
+        assert_eq!(app.state(), AppState::HistoryBrowser);
+    }
+
+    #[test]
+    fn replay_wpm_samples_cover_each_second() {
+        let mut app = App::new(AppConfig::default());
+        app.set_history_entries(vec![recorded("ab")]);
+        app.open_history_browser();
+        app.start_history_replay();
+
+        assert_eq!(app.replay_wpm_samples(), vec![12, 12]);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::domain::history::HistoryEntry;
+use crate::domain::layout::KeyboardLayout;
+use crate::usecase::finger_stats::{self, FingerReport};
+use crate::usecase::history_filter::HistoryFilter;
+use crate::usecase::history_record;
+use crate::usecase::history_stats::{self, HistoryStats};
+use crate::usecase::key_stats::{self, KeyReport};
+use crate::usecase::ngram_stats::{self, NgramReport};
+use crate::usecase::personal_best::{self, PersonalBest};
+use crate::usecase::progress::{self, ProgressPlot, ProgressView};
+
+use super::{App, HeatmapMetric, StatsPage};
+
+/// What the Stats screen shows of the filtered history. It is computed when
+/// the filtered history or the chart view changes, not on every frame.
+#[derive(Debug, Clone)]
+pub(super) struct StatsReports {
+    summary: HistoryStats,
+    bests: Vec<PersonalBest>,
+    ngrams: NgramReport,
+    keys: KeyReport,
+    fingers: FingerReport,
+    progress: ProgressPlot,
+}
+
+impl StatsReports {
+    pub(super) fn compute(
+        entries: &[HistoryEntry],
+        view: ProgressView,
+        layout: &KeyboardLayout,
+    ) -> Self {
+        let keys = key_stats::aggregate(entries);
+        Self {
+            summary: history_stats::summarize(entries),
+            bests: personal_best::personal_bests(entries),
+            ngrams: ngram_stats::aggregate(entries),
+            fingers: finger_stats::aggregate(entries, &keys, layout),
+            keys,
+            progress: progress::plot(entries, view),
+        }
+    }
+}
+
+impl App {
+    pub fn stats_page(&self) -> StatsPage {
+        self.stats_page
+    }
+
+    pub fn cycle_stats_page(&mut self) {
+        self.stats_page = self.stats_page.next();
+    }
+
+    pub fn stats_filter(&self) -> HistoryFilter {
+        self.stats_filter
+    }
+
+    pub fn cycle_stats_source(&mut self) {
+        self.stats_filter.cycle_source();
+        self.refresh_stats_history();
+    }
+
+    pub fn cycle_stats_mode(&mut self) {
+        self.stats_filter.mode = self.stats_filter.mode.next();
+        self.refresh_stats_history();
+    }
+
+    pub fn cycle_stats_duration(&mut self) {
+        self.stats_filter.duration = self.stats_filter.duration.next();
+        self.refresh_stats_history();
+    }
+
+    pub fn cycle_stats_range(&mut self) {
+        self.stats_filter.range = self.stats_filter.range.next();
+        self.refresh_stats_history();
+    }
+
+    /// The saved runs that match the Stats filter.
+    pub fn stats_history(&self) -> &[HistoryEntry] {
+        &self.stats_history
+    }
+
+    /// Reapplies the Stats filter, which also moves the date range forward,
+    /// and recomputes the reports.
+    pub(super) fn refresh_stats_history(&mut self) {
+        self.stats_history = self
+            .stats_filter
+            .apply(&self.history_entries, history_record::now());
+        self.stats_reports = StatsReports::compute(
+            &self.stats_history,
+            self.progress_view,
+            self.keyboard_layout(),
+        );
+    }
+
+    fn refresh_progress_plot(&mut self) {
+        self.stats_reports.progress = progress::plot(&self.stats_history, self.progress_view);
+    }
+
+    pub fn history_stats(&self) -> &HistoryStats {
+        &self.stats_reports.summary
+    }
+
+    pub fn progress_view(&self) -> ProgressView {
+        self.progress_view
+    }
+
+    pub fn progress_plot(&self) -> &ProgressPlot {
+        &self.stats_reports.progress
+    }
+
+    pub fn cycle_progress_chart(&mut self) {
+        self.progress_view.cycle_chart();
+        self.refresh_progress_plot();
+    }
+
+    pub fn scroll_progress_back(&mut self) {
+        let len = self.progress_plot().total;
+        self.progress_view.scroll_back(len);
+        self.refresh_progress_plot();
+    }
+
+    pub fn scroll_progress_forward(&mut self) {
+        let len = self.progress_plot().total;
+        self.progress_view.scroll_forward(len);
+        self.refresh_progress_plot();
+    }
+
+    pub fn zoom_progress_in(&mut self) {
+        self.progress_view.zoom_in();
+        self.refresh_progress_plot();
+    }
+
+    pub fn zoom_progress_out(&mut self) {
+        self.progress_view.zoom_out();
+        self.refresh_progress_plot();
+    }
+
+    pub fn personal_bests(&self) -> &[PersonalBest] {
+        &self.stats_reports.bests
+    }
+
+    pub fn ngram_report(&self) -> &NgramReport {
+        &self.stats_reports.ngrams
+    }
+
+    pub fn heatmap_metric(&self) -> HeatmapMetric {
+        self.heatmap_metric
+    }
+
+    pub fn toggle_heatmap_metric(&mut self) {
+        self.heatmap_metric = self.heatmap_metric.next();
+    }
+
+    pub fn history_key_report(&self) -> &KeyReport {
+        &self.stats_reports.keys
+    }
+
+    pub fn session_key_report(&self) -> KeyReport {
+        key_stats::collect(&self.target_string, &self.keystrokes)
+    }
+
+    pub fn keyboard_layout(&self) -> &'static KeyboardLayout {
+        self.config.game.layout_value().layout()
+    }
+
+    pub fn finger_report(&self) -> &FingerReport {
+        &self.stats_reports.fingers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::AppConfig;
+    use crate::domain::history::HistoryMode;
+    use crate::presentation::ui::app::AppState;
+    use crate::usecase::history_filter::ModeFilter;
+
+    #[test]
+    fn stats_pages_cycle_and_reset_when_reopened() {
+        let mut app = App::new(AppConfig::default());
+        app.open_stats();
+        assert_eq!(app.state(), AppState::Stats);
+        assert_eq!(app.stats_page(), StatsPage::Summary);
+
+        app.cycle_stats_page();
+        assert_eq!(app.stats_page(), StatsPage::Progress);
+        app.cycle_stats_page();
+        assert_eq!(app.stats_page(), StatsPage::Bests);
+        app.cycle_stats_page();
+        assert_eq!(app.stats_page(), StatsPage::Ngrams);
+        app.cycle_stats_page();
+        assert_eq!(app.stats_page(), StatsPage::Keyboard);
+        app.cycle_stats_page();
+        assert_eq!(app.stats_page(), StatsPage::Fingers);
+
+        app.return_to_menu();
+        app.open_stats();
+        assert_eq!(app.stats_page(), StatsPage::Summary);
+    }
+
+    #[test]
+    fn stats_filters_recompute_the_summary() {
+        let mut app = App::new(AppConfig::default());
+        let run = |source: &str| HistoryEntry {
+            generation_source: source.to_string(),
+            ..HistoryEntry::default()
+        };
+        let practice = HistoryEntry {
+            mode: HistoryMode::Practice {
+                keys_practiced: Vec::new(),
+            },
+            ..run("Local")
+        };
+        app.set_history_entries(vec![run("Local"), run("Groq"), practice]);
+        assert_eq!(app.history_stats().count, 2);
+
+        app.cycle_stats_source();
+        assert_eq!(app.history_stats().count, 1);
+
+        app.cycle_stats_mode();
+        assert_eq!(app.stats_filter().mode, ModeFilter::Practice);
+        assert_eq!(app.history_stats().count, 1);
+        app.cycle_stats_range();
+        assert_eq!(app.history_stats().count, 0);
+    }
+
+    #[test]
+    fn stats_reports_follow_the_history_and_the_chart_view() {
+        let mut app = App::new(AppConfig::default());
+        app.open_stats();
+        assert_eq!(app.progress_plot().total, 0);
+
+        app.set_history_entries(vec![HistoryEntry::default(); 3]);
+        assert_eq!(app.history_stats().count, 3);
+        assert_eq!(app.progress_plot().total, 3);
+        assert_eq!(app.personal_bests().len(), 1);
+
+        app.cycle_progress_chart();
+        assert_eq!(app.progress_plot().chart, app.progress_view().chart);
+    }
+
+    #[test]
+    fn heatmap_metric_toggles_between_error_rate_and_latency() {
+        let mut app = App::new(AppConfig::default());
+        assert_eq!(app.heatmap_metric(), HeatmapMetric::ErrorRate);
+
+        app.toggle_heatmap_metric();
+        assert_eq!(app.heatmap_metric(), HeatmapMetric::Latency);
+        app.toggle_heatmap_metric();
+        assert_eq!(app.heatmap_metric(), HeatmapMetric::ErrorRate);
+    }
+}
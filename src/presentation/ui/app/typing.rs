@@ -1,12 +1,28 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use crate::domain::config::CorrectionPolicy;
+use crate::domain::history::{AttemptCount, KeyEventMode, KeyRelease, Keystroke, SpeedAnalytics};
 use crate::domain::rhythm::RhythmSession;
+use crate::usecase::accuracy;
+use crate::usecase::key_timing;
+use crate::usecase::speed_analytics;
+use crate::usecase::text_difficulty;
+use crate::usecase::word_errors::{self, WordErrorSummary};
 
 use super::{App, AppState, GameMode};
 
+/// Accuracy thresholds below 100% are only enforced after this many keystrokes,
+/// so a single early mistake does not end the session immediately.
+const SUDDEN_DEATH_MIN_SAMPLE: usize = 10;
+const MISSED_WORD_LIMIT: usize = 5;
+
 impl App {
     pub fn start_typing(&mut self) {
         self.state = AppState::Typing;
         self.active_game_mode = GameMode::Standard;
         self.time_started = true;
+        self.typing_started_at = Some(Instant::now());
         self.record_wpm_snapshot();
     }
 
@@ -21,19 +37,32 @@ impl App {
     }
 
     pub fn prepare_new_game(&mut self, target: String) {
+        self.text_difficulty = text_difficulty::score(&target);
         self.target_string = target;
         self.inputs.clear();
         self.typed_count = 0;
         self.incorrects = 0;
         self.missed_chars.clear();
+        self.char_attempts.clear();
+        self.mistake_positions.clear();
+        self.keystrokes.clear();
+        self.key_releases.clear();
+        self.typing_started_at = None;
+        self.ghost = None;
         self.wpm_history.clear();
         self.wpm_activity_revision = 0;
         self.last_wpm_activity_timer = None;
         self.last_wpm_sample = None;
         self.timer = 0;
+        self.test_length = self.planned_test_length();
+        self.failed = false;
+        self.personal_best_gain = None;
         self.time_started = false;
         self.active_game_mode = GameMode::Standard;
         self.rhythm_session = None;
+        self.active_lesson = None;
+        self.active_drill = None;
+        self.drill_grades.clear();
     }
 
     pub fn prepare_rhythm_game(&mut self, target: &str) {
@@ -51,40 +80,265 @@ impl App {
         let position = self.inputs.len();
         let expected_char = self.target_string.chars().nth(position);
         let is_correct = expected_char == Some(c);
+        let policy = self.correction_policy();
+        if c == ' ' && !is_correct && self.skips_word_on_space(policy) {
+            return self.skip_current_word();
+        }
         self.typed_count += 1;
         self.wpm_activity_revision += 1;
         self.last_wpm_activity_timer = Some(self.timer);
 
-        if is_correct || !self.practice_mode {
+        let accepted = match policy {
+            CorrectionPolicy::LetterStop => is_correct,
+            CorrectionPolicy::WordStop => {
+                let leaves_word = expected_char.is_none_or(char::is_whitespace);
+                !leaves_word || (is_correct && self.current_word_is_clean())
+            }
+            CorrectionPolicy::Normal
+            | CorrectionPolicy::NoBackspace
+            | CorrectionPolicy::SuddenDeath => true,
+        };
+        if accepted {
             self.inputs.push(c);
         }
 
+        if let Some(expected_char) = expected_char {
+            self.char_attempts
+                .entry(expected_char)
+                .or_default()
+                .record(!is_correct);
+        }
         if !is_correct {
             self.incorrects += 1;
+            self.mistake_positions.push(position);
             if let Some(expected_char) = expected_char {
                 self.missed_chars.push(expected_char);
             }
         }
+        if policy == CorrectionPolicy::SuddenDeath && self.breaks_sudden_death() {
+            self.failed = true;
+        }
+        self.record_keystroke(Some(c));
         self.record_wpm_snapshot();
         is_correct
     }
 
+    /// Space skipping only applies to policies that let mistakes advance the
+    /// cursor.
+    fn skips_word_on_space(&self, policy: CorrectionPolicy) -> bool {
+        let allows_skip = matches!(
+            policy,
+            CorrectionPolicy::Normal
+                | CorrectionPolicy::NoBackspace
+                | CorrectionPolicy::SuddenDeath
+        );
+        allows_skip && self.config.game.space_skip_value()
+    }
+
+    /// Fills the rest of the current word with whitespace placeholders and
+    /// moves past the following space. A space typed at the start of a word is
+    /// ignored.
+    fn skip_current_word(&mut self) -> bool {
+        let target_chars = self.target_string.chars().collect::<Vec<_>>();
+        let position = self.inputs.len();
+        let at_word_start = position == 0
+            || target_chars
+                .get(position - 1)
+                .is_some_and(|ch| ch.is_whitespace());
+        if at_word_start {
+            return false;
+        }
+
+        let skipped = target_chars
+            .iter()
+            .skip(position)
+            .take_while(|ch| !ch.is_whitespace())
+            .copied()
+            .collect::<Vec<_>>();
+        self.typed_count += 1;
+        self.incorrects += 1;
+        self.mistake_positions.push(position);
+        self.wpm_activity_revision += 1;
+        self.last_wpm_activity_timer = Some(self.timer);
+        self.inputs.extend(skipped.iter().map(|_| ' '));
+        for ch in &skipped {
+            self.char_attempts.entry(*ch).or_default().record(true);
+        }
+        self.missed_chars.extend(skipped);
+        if target_chars.get(self.inputs.len()).is_some() {
+            self.inputs.push(' ');
+        }
+        if self.correction_policy() == CorrectionPolicy::SuddenDeath && self.breaks_sudden_death() {
+            self.failed = true;
+        }
+        self.record_keystroke(Some(' '));
+        self.record_wpm_snapshot();
+        false
+    }
+
+    fn record_keystroke(&mut self, key: Option<char>) {
+        let elapsed_ms = self.typing_elapsed_ms();
+        self.keystrokes.push(Keystroke {
+            elapsed_ms,
+            key,
+            position: self.inputs.len(),
+        });
+    }
+
+    /// Milliseconds since the typing screen started, used for keystroke
+    /// timelines and ghost positions.
+    pub fn typing_elapsed_ms(&self) -> u64 {
+        self.typing_started_at.map_or(0, |started_at| {
+            u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX)
+        })
+    }
+
     pub fn pop_char(&mut self) -> Option<char> {
+        if !self.correction_policy().allows_backspace() {
+            return None;
+        }
+
         let removed = self.inputs.pop();
+        self.record_keystroke(None);
         self.wpm_activity_revision += 1;
         self.last_wpm_activity_timer = Some(self.timer);
         self.record_wpm_snapshot();
         removed
     }
 
+    /// Deletes back to the start of the current word. When the cursor already
+    /// sits at a word start, the preceding space and word are deleted instead.
+    pub fn delete_word(&mut self) -> usize {
+        if !self.correction_policy().allows_backspace() {
+            return 0;
+        }
+
+        let target_chars = self.target_string.chars().collect::<Vec<_>>();
+        let target_is_whitespace =
+            |index: usize| target_chars.get(index).is_some_and(|ch| ch.is_whitespace());
+        let mut removed = 0;
+        while !self.inputs.is_empty() && target_is_whitespace(self.inputs.len() - 1) {
+            self.inputs.pop();
+            removed += 1;
+        }
+        while !self.inputs.is_empty() && !target_is_whitespace(self.inputs.len() - 1) {
+            self.inputs.pop();
+            removed += 1;
+        }
+
+        if removed > 0 {
+            self.record_keystroke(None);
+            self.wpm_activity_revision += 1;
+            self.last_wpm_activity_timer = Some(self.timer);
+            self.record_wpm_snapshot();
+        }
+        removed
+    }
+
+    pub fn word_error_summary(&self) -> WordErrorSummary {
+        word_errors::summarize(&self.target_string, &self.inputs, &self.mistake_positions)
+    }
+
+    pub(super) fn word_attempts(&self) -> BTreeMap<String, AttemptCount> {
+        word_errors::attempts(&self.target_string, &self.inputs, &self.mistake_positions)
+    }
+
+    /// Words this run missed most, shown on the result screen.
+    pub fn missed_words(&self) -> Vec<(String, AttemptCount)> {
+        word_errors::most_missed(&self.word_attempts(), MISSED_WORD_LIMIT)
+    }
+
+    pub fn speed_analytics(&self) -> SpeedAnalytics {
+        SpeedAnalytics {
+            key_events: self.key_event_mode,
+            hold_times: key_timing::analyze(
+                &self.keystrokes,
+                &self.key_releases,
+                self.keyboard_layout(),
+            ),
+            ..speed_analytics::analyze(&self.target_string, &self.keystrokes)
+        }
+    }
+
+    pub fn set_key_event_mode(&mut self, mode: KeyEventMode) {
+        self.key_event_mode = mode;
+    }
+
+    /// Records a key release during a standard run. Seeing one at all means
+    /// the terminal reports releases, even without enhancement flags.
+    pub fn record_key_release(&mut self, key: char) {
+        self.key_event_mode = KeyEventMode::PressRelease;
+        if self.state == AppState::Typing {
+            self.key_releases.push(KeyRelease {
+                elapsed_ms: self.typing_elapsed_ms(),
+                key,
+            });
+        }
+    }
+
     pub fn is_complete(&self) -> bool {
-        self.inputs.len() >= self.target_string.len()
+        let reached_end = self.inputs.len() >= self.target_string.len();
+        if self.correction_policy() == CorrectionPolicy::WordStop {
+            reached_end && self.current_word_is_clean()
+        } else {
+            reached_end
+        }
+    }
+
+    /// Returns whether every character typed since the start of the current
+    /// word matches the target.
+    fn current_word_is_clean(&self) -> bool {
+        let target_chars = self.target_string.chars().collect::<Vec<_>>();
+        let word_start = target_chars
+            .iter()
+            .take(self.inputs.len())
+            .rposition(|ch| ch.is_whitespace())
+            .map_or(0, |index| index + 1);
+
+        self.inputs
+            .iter()
+            .enumerate()
+            .skip(word_start)
+            .all(|(index, input)| target_chars.get(index) == Some(input))
+    }
+
+    fn breaks_sudden_death(&self) -> bool {
+        let threshold = self.fail_accuracy();
+        if self.incorrects == 0 {
+            return false;
+        }
+        if threshold >= 100.0 {
+            return true;
+        }
+
+        self.typed_count >= SUDDEN_DEATH_MIN_SAMPLE
+            && accuracy::calc_accuracy(self.typed_count, self.incorrects) < threshold
     }
 
     pub fn typed_count(&self) -> usize {
         self.typed_count
     }
 
+    pub fn target_word_count(&self) -> usize {
+        self.target_string.split_whitespace().count()
+    }
+
+    /// Counts target words whose last character has been reached by the input.
+    pub fn completed_word_count(&self) -> usize {
+        let target_chars = self.target_string.chars().collect::<Vec<_>>();
+        target_chars
+            .iter()
+            .enumerate()
+            .take(self.inputs.len())
+            .filter(|(index, ch)| {
+                !ch.is_whitespace()
+                    && target_chars
+                        .get(index + 1)
+                        .is_none_or(|next| next.is_whitespace())
+            })
+            .count()
+    }
+
     #[cfg(test)]
     pub fn current_input_count(&self) -> usize {
         self.inputs.len()
@@ -94,7 +348,13 @@ impl App {
 #[cfg(test)]
 mod tests {
     use super::App;
-    use crate::domain::config::AppConfig;
+    use crate::domain::config::{AppConfig, GameSettings};
+    use crate::domain::history::{
+        AttemptCount, HISTORY_SCHEMA_VERSION, HistoryEntry, HistoryMode, KeyEventMode,
+        RhythmRecord, TestLength,
+    };
+    use crate::domain::text_content::TextContent;
+    use crate::usecase::generate_sentence::TextTarget;
 
     fn new_app() -> App {
         App::new(AppConfig::default())
@@ -156,15 +416,50 @@ mod tests {
     }
 
     #[test]
-    fn build_history_entry_skips_practice_mode() {
+    fn build_history_entry_records_practice_keys_without_a_test_length() {
         let mut app = new_app();
-        app.prepare_new_game("ab".to_string());
+        app.prepare_new_game("aba cd".to_string());
         app.set_practice_mode(true);
         app.start_typing();
         app.update_timer(1);
-        app.push_char('a');
+        for ch in "aba c".chars() {
+            app.push_char(ch);
+        }
 
-        assert!(app.build_history_entry().is_none());
+        let entry = app.build_history_entry().unwrap_or_default();
+
+        assert_eq!(
+            entry.mode,
+            HistoryMode::Practice {
+                keys_practiced: vec!['a', 'b', 'c'],
+            }
+        );
+        assert_eq!(entry.test, None);
+    }
+
+    #[test]
+    fn build_history_entry_records_rhythm_judgements() {
+        let mut app = new_app();
+        app.prepare_rhythm_game("a b");
+        app.update_rhythm_elapsed_seconds(5.5);
+        app.push_rhythm_char('a');
+        app.update_rhythm_elapsed_seconds(9.5);
+
+        let entry = app.build_history_entry().unwrap_or_default();
+
+        assert_eq!(
+            entry.mode,
+            HistoryMode::Rhythm(RhythmRecord {
+                hit: 1,
+                ok: 0,
+                miss: 1,
+                max_combo: 1,
+                speed: app.rhythm_speed(),
+            })
+        );
+        assert_eq!(entry.elapsed_seconds, 10);
+        assert!((entry.accuracy - 100.0).abs() < f64::EPSILON);
+        assert!(entry.keystrokes.is_empty());
     }
 
     #[test]
@@ -177,20 +472,473 @@ mod tests {
 
         let entry = app.build_history_entry();
         assert!(entry.is_some());
-        let entry = entry.unwrap_or(crate::domain::history::HistoryEntry {
-            wpm: 0.0,
-            accuracy: 0.0,
-            miss_count: 0,
-            elapsed_seconds: 0,
-            generation_source: String::new(),
-            mode: crate::domain::history::HistoryMode::Timed,
-            missed_chars: Vec::new(),
-        });
+        let entry = entry.unwrap_or_default();
 
         assert_eq!(entry.miss_count, 1);
         assert_eq!(entry.elapsed_seconds, 1);
         assert_eq!(entry.generation_source, "Local");
         assert_eq!(entry.missed_chars, vec!['a']);
+        assert_eq!(entry.test, Some(TestLength::Time(60)));
+        assert_eq!(entry.schema_version, HISTORY_SCHEMA_VERSION);
+        assert_eq!(entry.id.len(), 16);
+        assert!(entry.timestamp.is_some());
+    }
+
+    #[test]
+    fn build_history_entry_records_attempts_per_character_and_word() {
+        let mut app = new_app();
+        app.prepare_new_game("ab cd".to_string());
+        app.start_typing();
+        for c in "ab cx".chars() {
+            app.push_char(c);
+        }
+
+        let entry = app.build_history_entry().unwrap_or_default();
+        let count = |attempts, misses| AttemptCount { attempts, misses };
+
+        assert_eq!(entry.char_attempts.get(&'a'), Some(&count(1, 0)));
+        assert_eq!(entry.char_attempts.get(&'d'), Some(&count(1, 1)));
+        assert_eq!(entry.char_attempts.len(), 5);
+        assert_eq!(
+            entry.word_attempts.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("ab".to_string(), count(1, 0)),
+                ("cd".to_string(), count(1, 1))
+            ]
+        );
+        assert_eq!(app.missed_words(), vec![("cd".to_string(), count(1, 1))]);
+    }
+
+    #[test]
+    fn build_history_entry_records_text_and_keystroke_timeline() {
+        let mut app = new_app();
+        app.prepare_new_game("ab".to_string());
+        app.start_typing();
+        app.push_char('x');
+        app.pop_char();
+        app.push_char('a');
+
+        let entry = app.build_history_entry().unwrap_or_default();
+
+        assert_eq!(entry.target_text, "ab");
+        assert_eq!(entry.difficulty, app.text_difficulty());
+        assert!(entry.difficulty.is_some());
+        let timeline = entry
+            .keystrokes
+            .iter()
+            .map(|keystroke| (keystroke.key, keystroke.position))
+            .collect::<Vec<_>>();
+        assert_eq!(timeline, vec![(Some('x'), 1), (None, 0), (Some('a'), 1)]);
+        assert!(
+            entry
+                .keystrokes
+                .windows(2)
+                .all(|pair| pair.first().map(|k| k.elapsed_ms) <= pair.get(1).map(|k| k.elapsed_ms))
+        );
+    }
+
+    #[test]
+    fn key_releases_switch_the_mode_and_are_recorded_only_while_typing() {
+        let mut app = new_app();
+        app.record_key_release('x');
+        assert_eq!(app.speed_analytics().key_events, KeyEventMode::PressRelease);
+
+        app.prepare_new_game("ab".to_string());
+        app.start_typing();
+        app.push_char('a');
+        app.record_key_release('a');
+
+        let analytics = app.speed_analytics();
+        assert!(analytics.hold_times.is_some());
+        app.prepare_new_game("ab".to_string());
+        assert!(app.speed_analytics().hold_times.is_none());
+    }
+
+    #[test]
+    fn adaptive_mode_scales_the_word_count_and_records_the_level() {
+        let mut app = App::new(AppConfig {
+            game: GameSettings {
+                test_mode: "words".to_string(),
+                word_count: "25".to_string(),
+                adaptive: "true".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        });
+        let run = HistoryEntry {
+            accuracy: 96.0,
+            adaptive_step: Some(3),
+            ..HistoryEntry::default()
+        };
+        app.set_history_entries(vec![run.clone(), run]);
+
+        app.enter_loading();
+        let (target, _, _, content) = app.generation_settings();
+        assert_eq!(target, TextTarget::Words(30));
+        assert!(content.is_full());
+        app.prepare_new_game("ab cd".to_string());
+        app.start_typing();
+        app.update_timer(3);
+
+        let entry = app.build_history_entry();
+        assert_eq!(
+            entry.as_ref().and_then(|entry| entry.test),
+            Some(TestLength::Words(30))
+        );
+        assert_eq!(entry.and_then(|entry| entry.adaptive_step), Some(3));
+        app.return_to_menu();
+        assert!(app.adaptive_plan().is_none());
+    }
+
+    #[test]
+    fn character_options_reach_generation_and_are_recorded() {
+        let mut app = App::new(AppConfig {
+            game: GameSettings {
+                punctuation: "false".to_string(),
+                numbers: "true".to_string(),
+                symbols: "true".to_string(),
+                adaptive: "true".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        });
+        let run = HistoryEntry {
+            accuracy: 96.0,
+            adaptive_step: Some(0),
+            ..HistoryEntry::default()
+        };
+        app.set_history_entries(vec![run.clone(), run]);
+
+        app.enter_loading();
+        let (_, _, _, content) = app.generation_settings();
+        let expected = TextContent {
+            capitals: false,
+            punctuation: false,
+            rare_words: false,
+            numbers: true,
+            symbols: false,
+        };
+        assert_eq!(content, expected);
+        app.prepare_new_game("ab 12".to_string());
+        app.start_typing();
+        app.update_timer(3);
+
+        assert_eq!(
+            app.build_history_entry()
+                .and_then(|entry| entry.text_content),
+            Some(expected)
+        );
+        app.return_to_menu();
+        assert!(app.generation_settings().3.is_full());
+    }
+
+    #[test]
+    fn words_test_has_no_timeout_and_is_recorded_with_word_count() {
+        let mut app = App::new(AppConfig {
+            game: GameSettings {
+                test_mode: "words".to_string(),
+                word_count: "2".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        });
+        app.prepare_new_game("ab cd".to_string());
+        app.start_typing();
+        app.update_timer(3);
+
+        assert_eq!(app.timeout(), 0);
+        assert_eq!(
+            app.build_history_entry().and_then(|entry| entry.test),
+            Some(TestLength::Words(2))
+        );
+    }
+
+    #[test]
+    fn passage_test_has_no_timeout_and_is_recorded() {
+        let mut app = App::new(AppConfig {
+            game: GameSettings {
+                test_mode: "passage".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        });
+        app.prepare_new_game("ab".to_string());
+        app.start_typing();
+
+        assert_eq!(app.timeout(), 0);
+        assert_eq!(
+            app.build_history_entry().and_then(|entry| entry.test),
+            Some(TestLength::Passage(60))
+        );
+    }
+
+    #[test]
+    fn completed_word_count_counts_words_reached_by_input() {
+        let mut app = new_app();
+        app.prepare_new_game("ab cd ef".to_string());
+        assert_eq!(app.target_word_count(), 3);
+
+        app.push_char('a');
+        assert_eq!(app.completed_word_count(), 0);
+        app.push_char('b');
+        assert_eq!(app.completed_word_count(), 1);
+        for c in " cd e".chars() {
+            app.push_char(c);
+        }
+        assert_eq!(app.completed_word_count(), 2);
+    }
+
+    #[test]
+    fn history_stats_for_current_test_compares_like_with_like() {
+        let mut app = new_app();
+        app.set_history_entries(vec![
+            HistoryEntry {
+                wpm: 80.0,
+                test: Some(TestLength::Words(25)),
+                ..HistoryEntry::default()
+            },
+            HistoryEntry {
+                wpm: 40.0,
+                test: Some(TestLength::Time(60)),
+                ..HistoryEntry::default()
+            },
+        ]);
+        app.prepare_new_game("ab".to_string());
+
+        let stats = app.history_stats_for_current_test();
+
+        assert_eq!(stats.count, 1);
+        assert_eq!(stats.best_wpm, Some(40.0));
+    }
+
+    #[test]
+    fn recorded_session_notes_a_new_personal_best_until_the_next_game() {
+        let mut app = new_app();
+        let run = |wpm| HistoryEntry {
+            wpm,
+            test: Some(TestLength::Time(60)),
+            ..HistoryEntry::default()
+        };
+        app.set_history_entries(vec![run(40.0)]);
+
+        app.record_history_entry(run(43.5));
+        assert_eq!(app.personal_best_gain(), Some(3.5));
+        assert_eq!(app.history_entries().len(), 2);
+
+        app.record_history_entry(run(41.0));
+        assert_eq!(app.personal_best_gain(), None);
+        app.record_history_entry(run(44.0));
+        app.prepare_new_game("ab".to_string());
+        assert_eq!(app.personal_best_gain(), None);
+    }
+
+    fn app_with_correction(correction: &str) -> App {
+        App::new(AppConfig {
+            game: GameSettings {
+                correction: correction.to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        })
+    }
+
+    #[test]
+    fn letter_stop_policy_keeps_wrong_characters_out_of_input() {
+        let mut app = app_with_correction("letter-stop");
+        app.prepare_new_game("ab".to_string());
+
+        assert!(!app.push_char('x'));
+        assert!(app.input_chars().is_empty());
+        assert_eq!(app.incorrects(), 1);
+    }
+
+    #[test]
+    fn word_stop_policy_blocks_leaving_a_word_with_errors() {
+        let mut app = app_with_correction("word-stop");
+        app.prepare_new_game("ab cd".to_string());
+
+        app.push_char('a');
+        app.push_char('x');
+        assert_eq!(app.input_chars(), &['a', 'x']);
+
+        app.push_char(' ');
+        assert_eq!(app.input_chars(), &['a', 'x']);
+
+        app.pop_char();
+        app.push_char('b');
+        app.push_char(' ');
+        assert_eq!(app.input_chars(), &['a', 'b', ' ']);
+    }
+
+    #[test]
+    fn word_stop_policy_requires_a_clean_last_word_to_finish() {
+        let mut app = app_with_correction("word-stop");
+        app.prepare_new_game("ab".to_string());
+
+        app.push_char('a');
+        app.push_char('x');
+        assert!(!app.is_complete());
+        app.push_char('b');
+        assert_eq!(app.input_chars(), &['a', 'x']);
+
+        app.pop_char();
+        app.push_char('b');
+        assert!(app.is_complete());
+    }
+
+    #[test]
+    fn no_backspace_policy_keeps_errors_permanent() {
+        let mut app = app_with_correction("no-backspace");
+        app.prepare_new_game("ab".to_string());
+
+        app.push_char('x');
+
+        assert!(app.pop_char().is_none());
+        assert_eq!(app.input_chars(), &['x']);
+        assert!(!app.is_failed());
+    }
+
+    #[test]
+    fn sudden_death_fails_on_first_error_by_default() {
+        let mut app = app_with_correction("sudden-death");
+        app.prepare_new_game("abc".to_string());
+
+        app.push_char('a');
+        assert!(!app.is_failed());
+        app.push_char('x');
+
+        assert!(app.is_failed());
+        assert!(app.build_history_entry().is_some_and(|entry| entry.failed));
+    }
+
+    #[test]
+    fn sudden_death_with_threshold_fails_when_accuracy_drops_below_it() {
+        let mut app = App::new(AppConfig {
+            game: GameSettings {
+                correction: "sudden-death".to_string(),
+                fail_accuracy: "80".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        });
+        app.prepare_new_game("aaaaaaaaaaaaaaaaaaaa".to_string());
+
+        app.push_char('x');
+        assert!(!app.is_failed());
+        for _ in 0..8 {
+            app.push_char('a');
+        }
+        app.push_char('x');
+        assert!(!app.is_failed());
+        app.push_char('x');
+        assert!(app.is_failed());
+    }
+
+    fn app_with_space_skip(correction: &str) -> App {
+        App::new(AppConfig {
+            game: GameSettings {
+                correction: correction.to_string(),
+                space_skip: "true".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        })
+    }
+
+    #[test]
+    fn delete_word_removes_back_to_word_start() {
+        let mut app = new_app();
+        app.prepare_new_game("ab cd".to_string());
+        for c in "ab c".chars() {
+            app.push_char(c);
+        }
+
+        assert_eq!(app.delete_word(), 1);
+        assert_eq!(app.input_chars(), &['a', 'b', ' ']);
+
+        assert_eq!(app.delete_word(), 3);
+        assert!(app.input_chars().is_empty());
+        assert_eq!(app.delete_word(), 0);
+    }
+
+    #[test]
+    fn delete_word_respects_backspace_policy() {
+        let mut app = app_with_correction("no-backspace");
+        app.prepare_new_game("ab".to_string());
+        app.push_char('a');
+
+        assert_eq!(app.delete_word(), 0);
+        assert_eq!(app.input_chars(), &['a']);
+    }
+
+    #[test]
+    fn space_skip_marks_rest_of_word_as_missed() {
+        let mut app = app_with_space_skip("normal");
+        app.prepare_new_game("abc de".to_string());
+        app.push_char('a');
+
+        assert!(!app.push_char(' '));
+
+        assert_eq!(app.input_chars(), &['a', ' ', ' ', ' ']);
+        assert_eq!(app.missed_chars(), &['b', 'c']);
+        assert_eq!(app.typed_count(), 2);
+        assert_eq!(app.incorrects(), 1);
+        assert_eq!(app.completed_word_count(), 1);
+    }
+
+    #[test]
+    fn space_skip_ignores_space_at_word_start_and_finishes_last_word() {
+        let mut app = app_with_space_skip("normal");
+        app.prepare_new_game("ab".to_string());
+
+        app.push_char(' ');
+        assert!(app.input_chars().is_empty());
+        assert_eq!(app.typed_count(), 0);
+
+        app.push_char('a');
+        app.push_char(' ');
+        assert!(app.is_complete());
+    }
+
+    #[test]
+    fn space_skip_is_not_applied_by_stopping_policies() {
+        let mut app = app_with_space_skip("letter-stop");
+        app.prepare_new_game("abc de".to_string());
+        app.push_char('a');
+        app.push_char(' ');
+
+        assert_eq!(app.input_chars(), &['a']);
+    }
+
+    #[test]
+    fn word_error_summary_reports_skipped_and_corrected_words() {
+        let mut app = app_with_space_skip("normal");
+        app.prepare_new_game("ab cd ef".to_string());
+        for c in "ax".chars() {
+            app.push_char(c);
+        }
+        app.pop_char();
+        for c in "b c ef".chars() {
+            app.push_char(c);
+        }
+
+        let summary = app.word_error_summary();
+
+        assert_eq!(summary.typed, 3);
+        assert_eq!(summary.with_errors, 1);
+        assert_eq!(summary.corrected, 1);
+        assert_eq!(summary.skipped, 1);
+    }
+
+    #[test]
+    fn prepare_new_game_clears_failed_state() {
+        let mut app = app_with_correction("sudden-death");
+        app.prepare_new_game("ab".to_string());
+        app.push_char('x');
+
+        app.prepare_new_game("cd".to_string());
+
+        assert!(!app.is_failed());
     }
 
     #[test]
@@ -47,6 +47,14 @@ pub fn render_decoration_block(frame: &mut Frame, area: Rect) {
     }
 }
 
+/// Places a box of at most `width` by `height` cells at the top of `area`,
+/// centered horizontally.
+pub fn top_centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(area.x + (area.width - width) / 2, area.y, width, height)
+}
+
 pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
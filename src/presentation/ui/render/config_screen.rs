@@ -10,13 +10,17 @@ use crate::presentation::ui::app::{App, ConfigField};
 
 pub fn render_config(frame: &mut Frame, app: &App) {
     let area = frame.area();
+    let game_fields = game_settings_fields(app);
+    let game_height = u16::try_from(game_fields.len())
+        .unwrap_or(u16::MAX)
+        .saturating_add(2);
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),
             Constraint::Length(10),
             Constraint::Length(10),
-            Constraint::Length(12),
+            Constraint::Length(game_height),
             Constraint::Length(3),
             Constraint::Min(0),
         ])
@@ -33,7 +37,7 @@ pub fn render_config(frame: &mut Frame, app: &App) {
         return;
     };
 
-    let header = Paragraph::new("Edit settings. Enter saves. Esc discards. Space toggles Sound.")
+    let header = Paragraph::new("Edit settings. Enter saves. Esc discards. Space cycles choices.")
         .block(
             Block::default()
                 .borders(Borders::ALL)
@@ -89,7 +93,7 @@ pub fn render_config(frame: &mut Frame, app: &App) {
         app.config_cursor_index(),
     );
 
-    let game_cursor_position = render_game_settings_block(frame, *game_area, app);
+    let game_cursor_position = render_game_settings_block(frame, *game_area, app, &game_fields);
     let cursor_position = google_cursor_position
         .or(groq_cursor_position)
         .or(game_cursor_position);
@@ -107,36 +111,109 @@ pub fn render_config(frame: &mut Frame, app: &App) {
     }
 }
 
-fn render_game_settings_block(frame: &mut Frame, area: Rect, app: &App) -> Option<Position> {
+fn game_settings_fields(app: &App) -> Vec<(ConfigField, &'static str, String)> {
     let game = &app.config().game;
-    let focused = app.config_field();
+    let content = game.text_content();
 
-    let fields: [(ConfigField, &str, String); 5] = [
+    vec![
         (ConfigField::GameTimeout, "Timeout", game.timeout.clone()),
+        (
+            ConfigField::GameTestMode,
+            "TestMode",
+            game.test_mode_value().label().to_string(),
+        ),
+        (
+            ConfigField::GameWordCount,
+            "WordCount",
+            game.word_count.clone(),
+        ),
+        (
+            ConfigField::GameCorrection,
+            "Correction",
+            game.correction_value().label().to_string(),
+        ),
+        (
+            ConfigField::GameFailAccuracy,
+            "FailBelow%",
+            game.fail_accuracy.clone(),
+        ),
+        (
+            ConfigField::GameSpaceSkip,
+            "SpaceSkip",
+            enabled_label(game.space_skip_value()),
+        ),
+        (
+            ConfigField::GameLayout,
+            "Layout",
+            game.layout_value().label().to_string(),
+        ),
         (
             ConfigField::GameTextScale,
             "TextScale",
             game.text_scale.clone(),
         ),
+        (
+            ConfigField::GameLowercaseOnly,
+            "LowercaseOnly",
+            enabled_label(!content.capitals),
+        ),
+        (
+            ConfigField::GamePunctuation,
+            "Punctuation",
+            enabled_label(content.punctuation),
+        ),
+        (
+            ConfigField::GameNumbers,
+            "Numbers",
+            enabled_label(content.numbers),
+        ),
+        (
+            ConfigField::GameSymbols,
+            "Symbols",
+            enabled_label(content.symbols),
+        ),
         (
             ConfigField::GameRhythmSpeed,
             "RhythmSpeed",
             game.rhythm_speed.clone(),
         ),
+        (
+            ConfigField::GameAdaptive,
+            "Adaptive",
+            enabled_label(game.adaptive_value()),
+        ),
         (ConfigField::GameFreq, "Freq", game.freq.clone()),
         (
             ConfigField::GameSoundEnabled,
             "Sound",
-            if game.sound_enabled_value() {
-                "enabled".to_string()
-            } else {
-                "disabled".to_string()
-            },
+            enabled_label(game.sound_enabled_value()),
+        ),
+        (
+            ConfigField::GameHistoryPath,
+            "HistoryDir",
+            game.history_path.clone(),
         ),
-    ];
+    ]
+}
+
+fn enabled_label(enabled: bool) -> String {
+    if enabled {
+        "enabled".to_string()
+    } else {
+        "disabled".to_string()
+    }
+}
+
+fn render_game_settings_block(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    fields: &[(ConfigField, &str, String)],
+) -> Option<Position> {
+    let focused = app.config_field();
 
     let mut lines = Vec::new();
-    for (field, label, value) in &fields {
+    for (field, label, value) in fields {
         let is_focused = *field == focused;
         let label_style = if is_focused {
             Style::default()
@@ -154,7 +231,6 @@ fn render_game_settings_block(frame: &mut Frame, area: Rect, app: &App) -> Optio
             Span::styled(format!("{label:>11}: "), label_style),
             Span::styled(value.clone(), value_style),
         ]));
-        lines.push(Line::from(""));
     }
 
     let block = Paragraph::new(lines)
@@ -167,7 +243,7 @@ fn render_game_settings_block(frame: &mut Frame, area: Rect, app: &App) -> Optio
         .wrap(Wrap { trim: false });
     frame.render_widget(block, area);
 
-    config_cursor_position(area, focused, &fields, 13, app.config_cursor_index())
+    config_cursor_position(area, focused, fields, 13, 1, app.config_cursor_index())
 }
 
 fn render_provider_block(
@@ -217,7 +293,7 @@ fn render_provider_block(
         .wrap(Wrap { trim: false });
     frame.render_widget(block, area);
 
-    config_cursor_position(area, focused, &fields, 10, cursor_index)
+    config_cursor_position(area, focused, &fields, 10, 2, cursor_index)
 }
 
 fn config_cursor_position<V: AsRef<str>>(
@@ -225,6 +301,7 @@ fn config_cursor_position<V: AsRef<str>>(
     focused: ConfigField,
     fields: &[(ConfigField, &str, V)],
     label_width: u16,
+    row_step: u16,
     cursor_index: usize,
 ) -> Option<Position> {
     if !focused.accepts_text() {
@@ -234,7 +311,7 @@ fn config_cursor_position<V: AsRef<str>>(
     let field_index = fields.iter().position(|(field, _, _)| *field == focused)?;
     let value = fields.get(field_index)?.2.as_ref();
     let visible_cursor_index = cursor_index.min(value.chars().count());
-    let row_offset = u16::try_from(field_index).ok()?.saturating_mul(2);
+    let row_offset = u16::try_from(field_index).ok()?.saturating_mul(row_step);
     let cursor_x = area
         .x
         .checked_add(1)?
@@ -267,6 +344,7 @@ mod tests {
             ConfigField::GoogleApiKey,
             &fields,
             10,
+            2,
             3,
         );
 
@@ -297,11 +375,32 @@ mod tests {
             &fields,
             13,
             1,
+            1,
         );
 
         assert_eq!(cursor, None);
     }
 
+    #[test]
+    fn config_cursor_position_uses_single_rows_for_game_settings() {
+        let fields = [
+            (ConfigField::GameTimeout, "Timeout", String::from("60")),
+            (ConfigField::GameTestMode, "TestMode", String::from("time")),
+            (ConfigField::GameWordCount, "WordCount", String::from("25")),
+        ];
+
+        let cursor = config_cursor_position(
+            Rect::new(0, 0, 40, 5),
+            ConfigField::GameWordCount,
+            &fields,
+            13,
+            1,
+            2,
+        );
+
+        assert_eq!(cursor, Some(Position::new(16, 3)));
+    }
+
     #[test]
     fn config_cursor_position_clamps_to_value_end() {
         let fields = [(ConfigField::GoogleApiKey, "API Key", String::from("secret"))];
@@ -311,6 +410,7 @@ mod tests {
             ConfigField::GoogleApiKey,
             &fields,
             10,
+            2,
             99,
         );
 
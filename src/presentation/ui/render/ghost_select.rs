@@ -0,0 +1,102 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::history::HistoryEntry;
+use crate::presentation::ui::app::App;
+
+use super::common::centered_rect;
+
+const PREVIEW_CHARS: usize = 24;
+
+pub fn render_ghost_select(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 70, frame.area());
+    let mut lines = vec![
+        Line::from("Race against a recorded run on the same text."),
+        Line::from(""),
+    ];
+
+    for (row, candidate) in app.ghost_candidates().into_iter().enumerate() {
+        let Some(entry) = app.history_entries().get(candidate.entry_index) else {
+            continue;
+        };
+        let selected = row == app.ghost_selected();
+        let style = if selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let pointer = if selected { "▶︎ " } else { "  " };
+        let kind = if candidate.personal_best {
+            "Personal best"
+        } else {
+            "Run"
+        };
+        lines.push(Line::from(vec![
+            Span::styled(pointer, style),
+            Span::styled(ghost_row_label(kind, entry), style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from("Up/Down: select  Enter: race  Esc: back"));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Race a Ghost ")
+                    .border_style(Style::default().fg(Color::Magenta)),
+            )
+            .alignment(Alignment::Left),
+        area,
+    );
+}
+
+fn ghost_row_label(kind: &str, entry: &HistoryEntry) -> String {
+    format!(
+        "{kind}: {:.1} WPM  {:.1}%  {}  \"{}\"",
+        entry.wpm,
+        entry.accuracy,
+        entry.test_length().label(),
+        text_preview(&entry.target_text)
+    )
+}
+
+fn text_preview(text: &str) -> String {
+    let mut preview = text.chars().take(PREVIEW_CHARS).collect::<String>();
+    if text.chars().count() > PREVIEW_CHARS {
+        preview.push('…');
+    }
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::history::TestLength;
+
+    #[test]
+    fn ghost_row_label_summarizes_the_run() {
+        let entry = HistoryEntry {
+            wpm: 72.25,
+            accuracy: 98.0,
+            test: Some(TestLength::Words(25)),
+            target_text: "the quick brown fox jumps over the lazy dog".to_string(),
+            ..HistoryEntry::default()
+        };
+
+        assert_eq!(
+            ghost_row_label("Run", &entry),
+            "Run: 72.2 WPM  98.0%  words 25  \"the quick brown fox jump…\""
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::history::HistoryEntry;
+use crate::domain::timestamp;
+use crate::presentation::ui::app::App;
+
+use super::common::centered_rect;
+
+pub fn render_history_browser(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 80, frame.area());
+    let visible_rows = usize::from(area.height.saturating_sub(6)).max(1);
+    let total = app.history_entries().len();
+    let first_row = scroll_offset(app.history_selected(), visible_rows, total);
+
+    let mut lines = Vec::with_capacity(visible_rows + 4);
+    if total == 0 {
+        lines.push(Line::from("No saved runs yet."));
+    }
+    for row in first_row..(first_row + visible_rows).min(total) {
+        let Some(entry) = app
+            .history_entry_index(row)
+            .and_then(|index| app.history_entries().get(index))
+        else {
+            continue;
+        };
+        let selected = row == app.history_selected();
+        let style = if selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let pointer = if selected { "▶︎ " } else { "  " };
+        lines.push(Line::from(vec![
+            Span::styled(pointer, style),
+            Span::styled(history_row_label(total - row, entry), style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(
+        app.status_message()
+            .unwrap_or("Up/Down: select  Enter: replay  Esc: back to stats")
+            .to_string(),
+    ));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" History ({total} runs) "))
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .alignment(Alignment::Left),
+        area,
+    );
+}
+
+/// Keeps the selected row inside the visible window.
+fn scroll_offset(selected: usize, visible_rows: usize, total: usize) -> usize {
+    let max_offset = total.saturating_sub(visible_rows);
+    selected
+        .saturating_sub(visible_rows.saturating_sub(1))
+        .min(max_offset)
+}
+
+fn history_row_label(number: usize, entry: &HistoryEntry) -> String {
+    let status = if entry.failed { "  failed" } else { "" };
+    let replay = if entry.keystrokes.is_empty() {
+        ""
+    } else {
+        "  [replay]"
+    };
+    let kind = if entry.mode.is_timed() {
+        entry.test_length().label()
+    } else {
+        entry.mode.label().to_string()
+    };
+    let date = entry
+        .timestamp
+        .map_or_else(|| "-".to_string(), timestamp::format_date_time);
+    format!(
+        "#{number:<4} {date:<16}  {:>6.1} WPM  {:>5.1}%  {:<16} {}{status}{replay}",
+        entry.wpm, entry.accuracy, kind, entry.generation_source
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::history::{HistoryMode, Keystroke, TestLength};
+
+    #[test]
+    fn scroll_offset_follows_selection_within_bounds() {
+        assert_eq!(scroll_offset(0, 5, 20), 0);
+        assert_eq!(scroll_offset(4, 5, 20), 0);
+        assert_eq!(scroll_offset(7, 5, 20), 3);
+        assert_eq!(scroll_offset(19, 5, 20), 15);
+        assert_eq!(scroll_offset(2, 5, 3), 0);
+    }
+
+    #[test]
+    fn history_row_label_marks_replayable_and_failed_runs() {
+        let entry = HistoryEntry {
+            wpm: 55.0,
+            accuracy: 97.5,
+            generation_source: "Local".to_string(),
+            test: Some(TestLength::Time(30)),
+            failed: true,
+            keystrokes: vec![Keystroke {
+                elapsed_ms: 1,
+                key: Some('a'),
+                position: 1,
+            }],
+            ..HistoryEntry::default()
+        };
+
+        let label = history_row_label(3, &entry);
+
+        assert!(label.starts_with("#3    -                   55.0 WPM   97.5%  time 30s"));
+        assert!(label.ends_with("Local  failed  [replay]"));
+
+        let dated = HistoryEntry {
+            timestamp: Some(1_792_389_143),
+            ..entry
+        };
+        assert!(history_row_label(3, &dated).starts_with("#3    2026-10-19 05:52    55.0 WPM"));
+
+        let practice = HistoryEntry {
+            mode: HistoryMode::Practice {
+                keys_practiced: vec!['a'],
+            },
+            ..dated
+        };
+        assert!(history_row_label(3, &practice).contains("97.5%  practice "));
+    }
+}
@@ -0,0 +1,101 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::history::HistoryBackup;
+use crate::domain::timestamp;
+use crate::presentation::ui::app::App;
+
+use super::common::centered_rect;
+
+pub fn render_history_recovery(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 60, frame.area());
+    let Some(recovery) = app.history_recovery() else {
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "History could not be read in full",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(
+        recovery
+            .damage
+            .iter()
+            .map(|damage| Line::from(damage.describe())),
+    );
+    lines.push(Line::from(format!(
+        "{} readable runs were loaded. Nothing has been changed on disk.",
+        app.history_entries().len()
+    )));
+    lines.push(Line::from(""));
+    if recovery.backups.is_empty() {
+        lines.push(Line::from("No backups are available."));
+    } else {
+        lines.push(Line::from("Backups:"));
+        lines.extend(
+            recovery
+                .backups
+                .iter()
+                .map(|backup| Line::from(backup_label(backup))),
+        );
+    }
+    lines.push(Line::from(""));
+    if let Some(message) = app.status_message() {
+        lines.push(Line::from(message.to_string()));
+    }
+    lines.push(Line::from(recovery_keys(!recovery.backups.is_empty())));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" History Recovery ")
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .alignment(Alignment::Left),
+        area,
+    );
+}
+
+fn backup_label(backup: &HistoryBackup) -> String {
+    let date = backup
+        .modified
+        .map_or_else(|| "-".to_string(), timestamp::format_date_time);
+    format!("  {}: {date}  {} runs", backup.slot, backup.entries)
+}
+
+fn recovery_keys(has_backups: bool) -> String {
+    let restore = if has_backups {
+        "1-3: restore that backup  "
+    } else {
+        ""
+    };
+    format!("{restore}k: keep the readable runs  Esc: decide later")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_label_shows_slot_date_and_size() {
+        let backup = HistoryBackup {
+            slot: 2,
+            modified: Some(1_792_389_143),
+            entries: 41,
+        };
+
+        assert_eq!(backup_label(&backup), "  2: 2026-10-19 05:52  41 runs");
+        assert!(recovery_keys(false).starts_with("k: keep"));
+    }
+}
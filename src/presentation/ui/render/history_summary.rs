@@ -1,28 +1,90 @@
 use ratatui::text::Line;
 
-use crate::usecase::history_stats::{HistoryStats, MistakeCount};
-
-pub(super) fn history_summary_lines(stats: &HistoryStats) -> Vec<Line<'static>> {
+use crate::usecase::history_stats::{
+    DifficultyStats, HistoryStats, MIN_CHAR_ATTEMPTS, MIN_WORD_ATTEMPTS, WeakSpot,
+};
+
+pub(super) fn history_summary_lines(
+    stats: &HistoryStats,
+    empty_message: &'static str,
+) -> Vec<Line<'static>> {
     if stats.count == 0 {
         return vec![
             Line::from("History Stats"),
             Line::from(""),
-            Line::from("No timed history yet"),
+            Line::from(empty_message),
         ];
     }
 
     vec![
         Line::from("History Stats"),
         Line::from(""),
-        Line::from(format!("Runs: {}", stats.count)),
+        Line::from(format_runs(stats)),
         Line::from(format_optional("Best WPM", stats.best_wpm)),
         Line::from(format_optional("Avg WPM", stats.average_wpm)),
         Line::from(format_optional("Avg Accuracy", stats.average_accuracy)),
-        Line::from(format!("Recent 10 WPM: {}", format_recent_wpm(stats))),
-        Line::from(format!("Frequent misses: {}", format_mistakes(stats))),
+        Line::from(format!(
+            "Recent 10 WPM: {}",
+            format_trend(&stats.recent_wpm)
+        )),
+        Line::from(format!(
+            "Weakest keys: {}",
+            format_weak_spots(&stats.weak_chars, MIN_CHAR_ATTEMPTS)
+        )),
+        Line::from(format!(
+            "Weakest words: {}",
+            format_weak_spots(&stats.weak_words, MIN_WORD_ATTEMPTS)
+        )),
+    ]
+}
+
+/// Speed analytics trends, shown on the Stats screen where there is room.
+pub(super) fn speed_trend_lines(stats: &HistoryStats) -> Vec<Line<'static>> {
+    if stats.count == 0 {
+        return Vec::new();
+    }
+
+    vec![
+        Line::from(format_optional("Best burst WPM", stats.best_burst_wpm)),
+        Line::from(format!(
+            "Recent consistency: {}",
+            format_trend(&stats.recent_consistency)
+        )),
+        Line::from(format_difficulty(stats.difficulty)),
     ]
 }
 
+/// Practice and rhythm sessions are saved but kept out of the timed stats;
+/// this notes how many there are.
+pub(super) fn untimed_sessions_line(practice: usize, rhythm: usize) -> Option<Line<'static>> {
+    (practice + rhythm > 0).then(|| {
+        Line::from(format!(
+            "Not included: {practice} practice, {rhythm} rhythm sessions"
+        ))
+    })
+}
+
+fn format_difficulty(difficulty: Option<DifficultyStats>) -> String {
+    match difficulty {
+        Some(difficulty) => format!(
+            "Difficulty-adjusted WPM: avg {:.1}, best {:.1} (avg difficulty {:.0}, {} runs)",
+            difficulty.average_adjusted_wpm,
+            difficulty.best_adjusted_wpm,
+            difficulty.average_difficulty,
+            difficulty.runs
+        ),
+        None => "Difficulty-adjusted WPM: -".to_string(),
+    }
+}
+
+fn format_runs(stats: &HistoryStats) -> String {
+    if stats.failed_count == 0 {
+        format!("Runs: {}", stats.count)
+    } else {
+        format!("Runs: {} (+{} failed)", stats.count, stats.failed_count)
+    }
+}
+
 fn format_optional(label: &str, value: Option<f64>) -> String {
     match value {
         Some(value) => format!("{label}: {value:.1}"),
@@ -30,52 +92,69 @@ fn format_optional(label: &str, value: Option<f64>) -> String {
     }
 }
 
-fn format_recent_wpm(stats: &HistoryStats) -> String {
-    if stats.recent_wpm.is_empty() {
+fn format_trend(values: &[f64]) -> String {
+    if values.is_empty() {
         return "-".into();
     }
 
-    stats
-        .recent_wpm
+    values
         .iter()
-        .map(|wpm| format!("{wpm:.0}"))
+        .map(|value| format!("{value:.0}"))
         .collect::<Vec<_>>()
         .join(" -> ")
 }
 
-fn format_mistakes(stats: &HistoryStats) -> String {
-    if stats.frequent_mistakes.is_empty() {
-        return "-".into();
+/// Items with their error rate and misses out of attempts, or the sample
+/// size ranking needs when nothing qualifies yet.
+fn format_weak_spots<T: ToString>(spots: &[WeakSpot<T>], min_attempts: usize) -> String {
+    if spots.is_empty() {
+        return format!("- (needs {min_attempts}+ attempts)");
     }
 
-    stats
-        .frequent_mistakes
+    spots
         .iter()
-        .map(format_mistake)
+        .map(|spot| {
+            format!(
+                "{} {:.0}% ({}/{})",
+                visible_item(&spot.item.to_string()),
+                spot.count.error_rate(),
+                spot.count.misses,
+                spot.count.attempts
+            )
+        })
         .collect::<Vec<_>>()
         .join(", ")
 }
 
-fn format_mistake(mistake: &MistakeCount) -> String {
-    format!("{}:{}", mistake.character, mistake.count)
+/// Spaces are shown as `␣`, as on the N-grams page.
+fn visible_item(item: &str) -> String {
+    item.chars()
+        .map(|ch| if ch == ' ' { '␣' } else { ch })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::history::AttemptCount;
 
     #[test]
     fn history_summary_lines_show_empty_message() {
         let stats = HistoryStats {
             count: 0,
+            failed_count: 0,
             best_wpm: None,
             average_wpm: None,
             average_accuracy: None,
             recent_wpm: Vec::new(),
-            frequent_mistakes: Vec::new(),
+            best_burst_wpm: None,
+            recent_consistency: Vec::new(),
+            weak_chars: Vec::new(),
+            weak_words: Vec::new(),
+            difficulty: None,
         };
 
-        let lines = history_summary_lines(&stats);
+        let lines = history_summary_lines(&stats, "No timed history yet");
 
         assert_eq!(lines.len(), 3);
     }
@@ -84,18 +163,69 @@ mod tests {
     fn history_summary_lines_format_recent_and_mistakes() {
         let stats = HistoryStats {
             count: 2,
+            failed_count: 1,
             best_wpm: Some(30.0),
             average_wpm: Some(25.0),
             average_accuracy: Some(97.5),
             recent_wpm: vec![20.0, 30.0],
-            frequent_mistakes: vec![MistakeCount {
-                character: 'a',
-                count: 2,
-            }],
+            best_burst_wpm: Some(41.0),
+            recent_consistency: vec![78.0, 84.4],
+            weak_chars: vec![
+                WeakSpot {
+                    item: ' ',
+                    count: AttemptCount {
+                        attempts: 40,
+                        misses: 6,
+                    },
+                },
+                WeakSpot {
+                    item: 'a',
+                    count: AttemptCount {
+                        attempts: 24,
+                        misses: 2,
+                    },
+                },
+            ],
+            weak_words: Vec::new(),
+            difficulty: Some(DifficultyStats {
+                runs: 2,
+                average_difficulty: 41.6,
+                best_adjusted_wpm: 36.3,
+                average_adjusted_wpm: 29.2,
+            }),
         };
 
-        let lines = history_summary_lines(&stats);
+        let lines = history_summary_lines(&stats, "No timed history yet");
+
+        assert_eq!(lines.len(), 9);
+        assert_eq!(
+            lines.get(7).map(ToString::to_string).as_deref(),
+            Some("Weakest keys: ␣ 15% (6/40), a 8% (2/24)")
+        );
+        assert_eq!(
+            lines.get(8).map(ToString::to_string).as_deref(),
+            Some("Weakest words: - (needs 3+ attempts)")
+        );
+        assert_eq!(format_runs(&stats), "Runs: 2 (+1 failed)");
+
+        let trend = speed_trend_lines(&stats);
+        assert_eq!(trend.len(), 3);
+        assert_eq!(
+            trend.get(1).map(ToString::to_string).as_deref(),
+            Some("Recent consistency: 78 -> 84")
+        );
+        assert_eq!(
+            trend.get(2).map(ToString::to_string).as_deref(),
+            Some("Difficulty-adjusted WPM: avg 29.2, best 36.3 (avg difficulty 42, 2 runs)")
+        );
+    }
 
-        assert_eq!(lines.len(), 8);
+    #[test]
+    fn untimed_sessions_line_appears_only_when_there_are_some() {
+        assert!(untimed_sessions_line(0, 0).is_none());
+        assert_eq!(
+            untimed_sessions_line(2, 1).map(|line| line.to_string()),
+            Some("Not included: 2 practice, 1 rhythm sessions".to_string())
+        );
     }
 }
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::domain::layout::{KeyPosition, KeyboardLayout, SPACE_ROW};
+use crate::presentation::ui::app::HeatmapMetric;
+use crate::usecase::key_stats::{KeyReport, KeyStat};
+
+/// Cool to hot, used for the lowest to highest fifth of the metric.
+const HEAT_COLORS: [Color; 5] = [
+    Color::Rgb(46, 160, 67),
+    Color::Rgb(140, 200, 60),
+    Color::Rgb(230, 200, 40),
+    Color::Rgb(240, 130, 40),
+    Color::Rgb(220, 50, 47),
+];
+const NO_DATA_COLOR: Color = Color::DarkGray;
+/// Indent of each key row, imitating the stagger of a physical keyboard.
+const ROW_OFFSETS: [usize; 4] = [0, 2, 3, 4];
+const SPACE_OFFSET: usize = 12;
+const SPACE_LABEL: &str = "     space     ";
+/// Rows, a blank line, the legend, and the border.
+pub const HEATMAP_HEIGHT: u16 = 9;
+pub const HEATMAP_WIDTH: u16 = 45;
+
+pub fn render_keyboard_heatmap(
+    frame: &mut Frame,
+    area: Rect,
+    layout: &KeyboardLayout,
+    report: &KeyReport,
+    metric: HeatmapMetric,
+    title: &str,
+) {
+    if area.height < 4 || area.width < 8 {
+        return;
+    }
+
+    frame.render_widget(
+        Paragraph::new(heatmap_lines(layout, report, metric))
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center),
+        area,
+    );
+}
+
+fn heatmap_lines(
+    layout: &KeyboardLayout,
+    report: &KeyReport,
+    metric: HeatmapMetric,
+) -> Vec<Line<'static>> {
+    let heats = key_heats(&report.by_key(layout), metric);
+    let mut lines = layout
+        .rows()
+        .zip(ROW_OFFSETS)
+        .enumerate()
+        .map(|(row, (keys, offset))| {
+            let mut spans = vec![Span::raw(" ".repeat(offset))];
+            spans.extend(keys.chars().enumerate().map(|(column, key)| {
+                key_span(
+                    format!(" {key} "),
+                    heats.get(&KeyPosition { row, column }).copied(),
+                )
+            }));
+            Line::from(spans)
+        })
+        .collect::<Vec<_>>();
+
+    let space = KeyPosition {
+        row: SPACE_ROW,
+        column: 0,
+    };
+    lines.push(Line::from(vec![
+        Span::raw(" ".repeat(SPACE_OFFSET)),
+        key_span(SPACE_LABEL.to_string(), heats.get(&space).copied()),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(legend_line(metric));
+    lines
+}
+
+fn key_span(label: String, heat: Option<f64>) -> Span<'static> {
+    let background = heat.map_or(NO_DATA_COLOR, heat_color);
+    Span::styled(label, Style::default().fg(Color::Black).bg(background))
+}
+
+fn legend_line(metric: HeatmapMetric) -> Line<'static> {
+    let mut spans = vec![Span::raw(format!("{}: low ", metric.label()))];
+    spans.extend(
+        HEAT_COLORS
+            .iter()
+            .map(|color| Span::styled("  ", Style::default().bg(*color))),
+    );
+    spans.push(Span::raw(" high  "));
+    spans.push(Span::styled("  ", Style::default().bg(NO_DATA_COLOR)));
+    spans.push(Span::raw(" no data"));
+    Line::from(spans)
+}
+
+/// Scales each key's metric to 0.0..=1.0. Error rates are relative to the
+/// worst key; latencies are spread between the fastest and slowest key.
+fn key_heats(
+    keys: &BTreeMap<KeyPosition, KeyStat>,
+    metric: HeatmapMetric,
+) -> BTreeMap<KeyPosition, f64> {
+    let values = keys
+        .iter()
+        .filter_map(|(position, stat)| {
+            let value = match metric {
+                HeatmapMetric::ErrorRate => stat.error_rate(),
+                HeatmapMetric::Latency => stat.average_latency_ms(),
+            };
+            value.map(|value| (*position, value))
+        })
+        .collect::<Vec<_>>();
+    let max = values.iter().map(|(_, value)| *value).fold(0.0, f64::max);
+    let min = match metric {
+        HeatmapMetric::ErrorRate => 0.0,
+        HeatmapMetric::Latency => values.iter().map(|(_, value)| *value).fold(max, f64::min),
+    };
+    let range = max - min;
+
+    values
+        .into_iter()
+        .map(|(position, value)| {
+            let heat = if range > 0.0 {
+                (value - min) / range
+            } else {
+                0.0
+            };
+            (position, heat)
+        })
+        .collect()
+}
+
+fn heat_color(heat: f64) -> Color {
+    let steps = HEAT_COLORS.len();
+    HEAT_COLORS
+        .iter()
+        .zip(1..=steps)
+        .find(|(_, step)| heat <= to_f64(*step) / to_f64(steps))
+        .map_or(NO_DATA_COLOR, |(color, _)| *color)
+}
+
+fn to_f64(value: usize) -> f64 {
+    f64::from(u32::try_from(value).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+    use crate::domain::history::Keystroke;
+    use crate::domain::layout::QWERTY;
+    use crate::usecase::key_stats;
+
+    fn stat(attempts: usize, errors: usize, total_latency_ms: u64) -> KeyStat {
+        KeyStat {
+            attempts,
+            errors,
+            latency_samples: attempts - errors,
+            total_latency_ms,
+        }
+    }
+
+    fn position(row: usize, column: usize) -> KeyPosition {
+        KeyPosition { row, column }
+    }
+
+    #[test]
+    fn key_heats_scale_error_rate_from_zero_and_latency_between_extremes() {
+        let keys = BTreeMap::from([
+            (position(1, 0), stat(10, 5, 1000)),
+            (position(1, 1), stat(10, 0, 3000)),
+            (position(1, 2), stat(4, 1, 600)),
+        ]);
+
+        let errors = key_heats(&keys, HeatmapMetric::ErrorRate);
+        assert_eq!(errors.get(&position(1, 0)), Some(&1.0));
+        assert_eq!(errors.get(&position(1, 1)), Some(&0.0));
+        assert_eq!(errors.get(&position(1, 2)), Some(&0.5));
+
+        let latency = key_heats(&keys, HeatmapMetric::Latency);
+        assert_eq!(latency.get(&position(1, 0)), Some(&0.0));
+        assert_eq!(latency.get(&position(1, 1)), Some(&1.0));
+        assert_eq!(latency.get(&position(1, 2)), Some(&0.0));
+    }
+
+    #[test]
+    fn heat_color_runs_from_green_to_red() {
+        assert_eq!(heat_color(0.0), Color::Rgb(46, 160, 67));
+        assert_eq!(heat_color(0.5), Color::Rgb(230, 200, 40));
+        assert_eq!(heat_color(1.0), Color::Rgb(220, 50, 47));
+    }
+
+    #[test]
+    fn heatmap_lines_color_typed_keys_and_leave_others_gray() {
+        let report = key_stats::collect(
+            "q",
+            &[Keystroke {
+                elapsed_ms: 100,
+                key: Some('w'),
+                position: 1,
+            }],
+        );
+        let lines = heatmap_lines(&QWERTY, &report, HeatmapMetric::ErrorRate);
+
+        let q_row = lines.get(1).expect("second row holds q");
+        let q = q_row.spans.get(1).expect("q is the first key");
+        assert_eq!(q.content, " q ");
+        assert_eq!(q.style.bg, Some(Color::Rgb(220, 50, 47)));
+        let w = q_row.spans.get(2).expect("w follows q");
+        assert_eq!(w.style.bg, Some(NO_DATA_COLOR));
+        assert_eq!(lines.len(), 7);
+    }
+}
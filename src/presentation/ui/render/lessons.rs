@@ -0,0 +1,119 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::lesson::{CURRICULUM, Lesson, LessonRecord};
+use crate::presentation::ui::app::App;
+
+use super::common::centered_rect;
+
+pub fn render_lessons(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 70, frame.area());
+    let layout = app.keyboard_layout();
+    let progress = app.lesson_progress();
+    let mut lines = vec![
+        Line::from(format!(
+            "Each lesson adds keys on the {} layout. Pass one to unlock the next.",
+            layout.name
+        )),
+        Line::from(""),
+    ];
+
+    for (index, lesson) in CURRICULUM.iter().enumerate() {
+        let selected = index == app.lesson_selected();
+        let unlocked = progress.is_unlocked(index);
+        let style = if selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if unlocked {
+            Style::default().fg(Color::White)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let pointer = if selected { "▶︎ " } else { "  " };
+        let keys = lesson.new_keys(layout).into_iter().collect::<String>();
+        lines.push(Line::from(vec![
+            Span::styled(pointer, style),
+            Span::styled(
+                lesson_row_label(index + 1, lesson, &keys, unlocked, progress.record(lesson)),
+                style,
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(
+        app.status_message()
+            .unwrap_or("Up/Down: select  Enter: start  Esc: back")
+            .to_string(),
+    ));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Lessons ")
+                    .border_style(Style::default().fg(Color::Green)),
+            )
+            .alignment(Alignment::Left),
+        area,
+    );
+}
+
+fn lesson_row_label(
+    number: usize,
+    lesson: &Lesson,
+    keys: &str,
+    unlocked: bool,
+    record: Option<&LessonRecord>,
+) -> String {
+    let status = match record {
+        _ if !unlocked => "locked".to_string(),
+        Some(record) => format!(
+            "{}  best {:.1} WPM  {:.1}%",
+            if record.passed {
+                "passed"
+            } else {
+                "not passed"
+            },
+            record.best_wpm,
+            record.best_accuracy
+        ),
+        None => "new".to_string(),
+    };
+    format!(
+        "{number}. {}  [{keys}]  target {:.0} WPM  {:.0}%  {status}",
+        lesson.title, lesson.target_wpm, lesson.target_accuracy
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lesson_row_label_shows_targets_and_progress() {
+        let [home_row, top_row, ..] = CURRICULUM;
+        let record = LessonRecord {
+            best_wpm: 21.25,
+            best_accuracy: 97.0,
+            passed: true,
+        };
+
+        assert_eq!(
+            lesson_row_label(1, &home_row, "asdf", true, Some(&record)),
+            "1. Home row  [asdf]  target 15 WPM  95%  passed  best 21.2 WPM  97.0%"
+        );
+        assert_eq!(
+            lesson_row_label(2, &top_row, "qwer", false, None),
+            "2. Top row  [qwer]  target 18 WPM  95%  locked"
+        );
+    }
+}
@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::presentation::ui::app::App;
+use crate::usecase::text_difficulty;
 
 use super::common::centered_rect;
 
@@ -20,6 +21,7 @@ pub fn render_loading(frame: &mut Frame, app: &App) {
     };
     let text = vec![
         Line::from(format!("Generating text with {provider}")),
+        Line::from(recent_difficulty_line(app.recent_text_difficulty())),
         Line::from(""),
         Line::from("Please wait..."),
     ];
@@ -35,3 +37,17 @@ pub fn render_loading(frame: &mut Frame, app: &App) {
         area,
     );
 }
+
+/// The new text is measured once it arrives, so loading shows what recent
+/// texts from the same source were like.
+fn recent_difficulty_line(difficulty: Option<f64>) -> String {
+    difficulty.map_or_else(
+        || "Difficulty: measured when the text arrives".to_string(),
+        |difficulty| {
+            format!(
+                "Recent difficulty: {difficulty:.0}/100 ({})",
+                text_difficulty::label(difficulty)
+            )
+        },
+    )
+}
@@ -12,12 +12,14 @@ use super::common::{centered_rect, render_help_overlay};
 
 pub fn render_menu(frame: &mut Frame, app: &App) {
     let area = frame.area();
+    let menu_items = app.visible_menu_items();
+    let menu_height = u16::try_from(menu_items.len() + 1).unwrap_or(u16::MAX);
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Percentage(20),
             Constraint::Length(11),
-            Constraint::Length(8),
+            Constraint::Length(menu_height.max(8)),
             Constraint::Length(3),
             Constraint::Min(0),
         ])
@@ -37,10 +39,9 @@ pub fn render_menu(frame: &mut Frame, app: &App) {
         .alignment(Alignment::Center);
     frame.render_widget(title, *title_area);
 
-    let menu_lines = app
-        .visible_menu_items()
+    let menu_lines = menu_items
         .into_iter()
-        .map(|item| menu_line(app, item, menu_label(item)))
+        .map(|item| menu_line(app, item, &menu_text(app, item)))
         .collect::<Vec<_>>();
     let menu = Paragraph::new(menu_lines)
         .block(
@@ -99,6 +100,18 @@ fn menu_line(app: &App, item: MenuItem, label: &str) -> Line<'static> {
     ])
 }
 
+fn menu_text(app: &App, item: MenuItem) -> String {
+    match item {
+        MenuItem::DrillWeakKeys => drill_menu_label(app.due_drill_items().len()),
+        MenuItem::Profiles => format!("{}: {}", menu_label(item), app.profile_label()),
+        _ => menu_label(item).to_string(),
+    }
+}
+
+fn drill_menu_label(due: usize) -> String {
+    format!("{} ({due} due today)", menu_label(MenuItem::DrillWeakKeys))
+}
+
 fn menu_label(item: MenuItem) -> &'static str {
     match item {
         MenuItem::StartGame => "Start Game",
@@ -106,8 +119,12 @@ fn menu_label(item: MenuItem) -> &'static str {
         MenuItem::StartGameRhythm => "Start Game with Rhythm",
         MenuItem::StartGameGoogle => "Start Game via Google AI Studio",
         MenuItem::StartGameGroq => "Start Game via GroqCloud",
+        MenuItem::RaceGhost => "Race a Ghost",
+        MenuItem::DrillWeakKeys => "Drill Weak Keys",
+        MenuItem::Lessons => "Lessons",
         MenuItem::Stats => "Stats",
         MenuItem::Config => "Config",
+        MenuItem::Profiles => "Profile",
     }
 }
 
@@ -1,8 +1,16 @@
 mod common;
 mod config_screen;
+mod ghost_select;
+mod history_browser;
+mod history_recovery;
 mod history_summary;
+mod keyboard_heatmap;
+mod lessons;
 mod loading;
 mod menu;
+mod profiles;
+mod progress_chart;
+mod replay;
 mod result;
 mod rhythm;
 mod stats;
@@ -22,9 +30,15 @@ pub fn render(frame: &mut Frame, app: &App) {
         AppState::Menu => menu::render_menu(frame, app),
         AppState::Config => config_screen::render_config(frame, app),
         AppState::Stats => stats::render_stats(frame, app),
+        AppState::HistoryBrowser => history_browser::render_history_browser(frame, app),
+        AppState::GhostSelect => ghost_select::render_ghost_select(frame, app),
+        AppState::Lessons => lessons::render_lessons(frame, app),
+        AppState::Profiles => profiles::render_profiles(frame, app),
         AppState::Loading => loading::render_loading(frame, app),
         AppState::Typing => typing::render_typing(frame, app),
         AppState::RhythmTyping => rhythm::render_rhythm(frame, app),
         AppState::Result => result::render_result(frame, app),
+        AppState::Replay => replay::render_replay(frame, app),
+        AppState::HistoryRecovery => history_recovery::render_history_recovery(frame, app),
     }
 }
@@ -0,0 +1,83 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::domain::profile::DEFAULT_PROFILE;
+use crate::presentation::ui::app::{App, ProfilePrompt};
+
+use super::common::centered_rect;
+
+pub fn render_profiles(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 60, frame.area());
+    let mut lines = vec![
+        Line::from("Each profile keeps its own settings, history and lessons."),
+        Line::from("API keys are shared unless a profile sets its own."),
+        Line::from(""),
+    ];
+
+    let names = std::iter::once(DEFAULT_PROFILE)
+        .chain(app.profile_names().iter().map(String::as_str))
+        .enumerate();
+    for (index, name) in names {
+        let selected = index == app.profile_selected();
+        let style = if selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let pointer = if selected { "▶︎ " } else { "  " };
+        let in_use = if name == app.profile_label() {
+            "  (in use)"
+        } else {
+            ""
+        };
+        lines.push(Line::from(vec![
+            Span::styled(pointer, style),
+            Span::styled(format!("{name}{in_use}"), style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(match app.profile_prompt() {
+        Some(prompt) => Line::from(Span::styled(
+            prompt_line(prompt),
+            Style::default().fg(Color::Cyan),
+        )),
+        None => Line::from(
+            app.status_message()
+                .unwrap_or("Enter: switch  n: new  r: rename  d: delete  Esc: back")
+                .to_string(),
+        ),
+    });
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Profiles ")
+                    .border_style(Style::default().fg(Color::Green)),
+            )
+            .alignment(Alignment::Left),
+        area,
+    );
+}
+
+fn prompt_line(prompt: &ProfilePrompt) -> String {
+    match prompt {
+        ProfilePrompt::Create { name } => format!("New profile name: {name}_  (Enter: create)"),
+        ProfilePrompt::Rename { from, name } => {
+            format!("Rename {from} to: {name}_  (Enter: rename)")
+        }
+        ProfilePrompt::Delete { name } => {
+            format!("Delete {name} with its settings and history? Enter: delete  Esc: keep")
+        }
+    }
+}
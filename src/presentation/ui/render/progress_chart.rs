@@ -0,0 +1,237 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Paragraph,
+        canvas::{Canvas, Context, Line as CanvasLine, Points},
+    },
+};
+
+use crate::usecase::progress::{ProgressChart, ProgressPlot, ProgressView};
+
+const CHART_BORDER_COLOR: Color = Color::LightYellow;
+const VALUE_COLOR: Color = Color::LightGreen;
+const AVERAGE_COLOR: Color = Color::Yellow;
+const TREND_COLOR: Color = Color::Cyan;
+
+/// Draws the chart in view with its moving average and trend line, under a
+/// line naming the range shown and a legend.
+pub fn render_progress_chart(
+    frame: &mut Frame,
+    area: Rect,
+    plot: &ProgressPlot,
+    view: ProgressView,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(3)])
+        .split(area);
+    let [header_area, chart_area] = &*chunks else {
+        return;
+    };
+
+    frame.render_widget(
+        Paragraph::new(vec![header_line(plot, view), legend_line(plot.chart)])
+            .alignment(Alignment::Center),
+        *header_area,
+    );
+    if plot.values.is_empty() {
+        frame.render_widget(
+            Paragraph::new(empty_message(plot.chart)).alignment(Alignment::Center),
+            *chart_area,
+        );
+        return;
+    }
+    if chart_area.height < 3 || chart_area.width < 8 {
+        return;
+    }
+
+    let x_bounds = x_bounds(plot);
+    frame.render_widget(
+        Canvas::default()
+            .marker(Marker::Braille)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ", plot.chart.label()))
+                    .border_style(Style::default().fg(CHART_BORDER_COLOR)),
+            )
+            .background_color(Color::Reset)
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds(plot))
+            .paint(|ctx| paint_chart(ctx, plot, x_bounds)),
+        *chart_area,
+    );
+}
+
+fn paint_chart(ctx: &mut Context<'_>, plot: &ProgressPlot, x_bounds: [f64; 2]) {
+    paint_polyline(ctx, &plot.values, VALUE_COLOR);
+    ctx.draw(&Points::new(&plot.values, VALUE_COLOR));
+    paint_polyline(ctx, &plot.average, AVERAGE_COLOR);
+    if let Some(trend) = plot.trend {
+        let [start, end] = x_bounds;
+        ctx.draw(&CanvasLine::new(
+            start,
+            trend.at(start),
+            end,
+            trend.at(end),
+            TREND_COLOR,
+        ));
+    }
+}
+
+fn paint_polyline(ctx: &mut Context<'_>, points: &[(f64, f64)], color: Color) {
+    for window in points.windows(2) {
+        if let [(x1, y1), (x2, y2)] = window {
+            ctx.draw(&CanvasLine::new(*x1, *y1, *x2, *y2, color));
+        }
+    }
+}
+
+fn header_line(plot: &ProgressPlot, view: ProgressView) -> Line<'static> {
+    let trend = plot.trend.map_or_else(
+        || "trend -".to_string(),
+        |trend| format!("trend {:+.2} {}", trend.slope, plot.chart.unit()),
+    );
+    Line::from(format!(
+        "{}  ({})  {trend}",
+        range_label(plot),
+        view.zoom_label()
+    ))
+}
+
+/// Sessions or dates in view, out of the whole series.
+fn range_label(plot: &ProgressPlot) -> String {
+    if plot.visible.is_empty() {
+        return "-".to_string();
+    }
+    let last = plot.visible.end - 1;
+    match (plot.day_label(plot.visible.start), plot.day_label(last)) {
+        (Some(first), Some(last)) => format!("{first} to {last}"),
+        _ => format!(
+            "Sessions {}-{} of {}",
+            plot.visible.start + 1,
+            last + 1,
+            plot.total
+        ),
+    }
+}
+
+fn legend_line(chart: ProgressChart) -> Line<'static> {
+    let item = |label: String, color: Color| {
+        [
+            Span::styled("━ ", Style::default().fg(color)),
+            Span::raw(label),
+        ]
+    };
+    let value = match chart {
+        ProgressChart::Wpm => "WPM",
+        ProgressChart::Accuracy => "accuracy",
+        ProgressChart::Daily => "sessions",
+    };
+    let window = match chart {
+        ProgressChart::Wpm | ProgressChart::Accuracy => "session",
+        ProgressChart::Daily => "day",
+    };
+
+    let mut spans = Vec::new();
+    spans.extend(item(value.to_string(), VALUE_COLOR));
+    spans.push(Span::raw("  "));
+    spans.extend(item(
+        format!("{}-{window} average", chart.average_window()),
+        AVERAGE_COLOR,
+    ));
+    spans.push(Span::raw("  "));
+    spans.extend(item("trend".to_string(), TREND_COLOR));
+    Line::from(spans)
+}
+
+fn empty_message(chart: ProgressChart) -> &'static str {
+    match chart {
+        ProgressChart::Wpm | ProgressChart::Accuracy => "No runs match the filter",
+        ProgressChart::Daily => "No dated sessions match the filter",
+    }
+}
+
+fn x_bounds(plot: &ProgressPlot) -> [f64; 2] {
+    let first = plot.values.first().map_or(0.0, |(x, _)| *x);
+    let last = plot.values.last().map_or(0.0, |(x, _)| *x);
+    [first, last.max(first + 1.0)]
+}
+
+/// Starts at zero like the WPM graph; accuracy charts stop at 100%.
+fn y_bounds(plot: &ProgressPlot) -> [f64; 2] {
+    let max = plot
+        .values
+        .iter()
+        .chain(&plot.average)
+        .map(|(_, y)| *y)
+        .fold(1.0, f64::max);
+    match plot.chart {
+        ProgressChart::Accuracy => [0.0, 100.0],
+        ProgressChart::Wpm | ProgressChart::Daily => [0.0, max],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::usecase::progress::Trend;
+
+    fn plot(chart: ProgressChart, values: Vec<(f64, f64)>) -> ProgressPlot {
+        ProgressPlot {
+            chart,
+            total: 12,
+            visible: 2..4,
+            average: values.clone(),
+            values,
+            trend: Some(Trend {
+                slope: 1.5,
+                intercept: 20.0,
+            }),
+            first_day: None,
+        }
+    }
+
+    #[test]
+    fn header_line_names_the_range_zoom_and_trend() {
+        let sessions = plot(ProgressChart::Wpm, vec![(2.0, 30.0), (3.0, 40.0)]);
+        assert_eq!(
+            header_line(&sessions, ProgressView::default()).to_string(),
+            "Sessions 3-4 of 12  (all)  trend +1.50 WPM/session"
+        );
+
+        let days = ProgressPlot {
+            first_day: Some(20_743),
+            ..plot(ProgressChart::Daily, Vec::new())
+        };
+        assert_eq!(range_label(&days), "2026-10-19 to 2026-10-20");
+    }
+
+    fn assert_bounds(actual: [f64; 2], expected: [f64; 2]) {
+        assert!((actual[0] - expected[0]).abs() < f64::EPSILON);
+        assert!((actual[1] - expected[1]).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn bounds_cover_the_points_in_view() {
+        let wpm = plot(ProgressChart::Wpm, vec![(2.0, 30.0), (3.0, 40.0)]);
+        assert_bounds(x_bounds(&wpm), [2.0, 3.0]);
+        assert_bounds(y_bounds(&wpm), [0.0, 40.0]);
+
+        let single = plot(ProgressChart::Accuracy, vec![(5.0, 97.0)]);
+        assert_bounds(x_bounds(&single), [5.0, 6.0]);
+        assert_bounds(y_bounds(&single), [0.0, 100.0]);
+    }
+
+    #[test]
+    fn legend_names_the_average_window() {
+        assert_eq!(
+            legend_line(ProgressChart::Daily).to_string(),
+            "━ sessions  ━ 7-day average  ━ trend"
+        );
+    }
+}
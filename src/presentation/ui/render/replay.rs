@@ -0,0 +1,153 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::domain::replay::Replay;
+use crate::presentation::ui::app::App;
+use crate::usecase::wpm;
+
+use super::typing::styled_target_lines;
+use super::wpm_graph;
+
+pub fn render_replay(frame: &mut Frame, app: &App) {
+    let Some(replay) = app.replay() else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Min(8),
+            Constraint::Length(3),
+        ])
+        .split(frame.area());
+    let [header_area, graph_area, text_area, footer_area] = &*chunks else {
+        return;
+    };
+
+    frame.render_widget(
+        Paragraph::new(Line::from(replay_header_spans(replay)))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Replay ")
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .alignment(Alignment::Center),
+        *header_area,
+    );
+
+    wpm_graph::render_wpm_graph_with_cursor(
+        frame,
+        *graph_area,
+        &app.replay_wpm_samples(),
+        " WPM Trend ",
+        replay.progress(),
+    );
+
+    let content_width = text_area.width.saturating_sub(2);
+    frame.render_widget(
+        Paragraph::new(styled_target_lines(
+            replay.target(),
+            &replay.inputs(),
+            None,
+            content_width,
+        ))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Target Text ")
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false }),
+        *text_area,
+    );
+
+    frame.render_widget(
+        Paragraph::new("Space: pause/resume  Tab: speed  1/2/4: set speed  r: restart  Esc: back")
+            .block(Block::default().borders(Borders::ALL))
+            .alignment(Alignment::Center),
+        *footer_area,
+    );
+}
+
+fn replay_header_spans(replay: &Replay) -> Vec<Span<'static>> {
+    let (typed, misses) = replay.counts_until(replay.elapsed_ms());
+    let elapsed_seconds = ms_to_seconds(replay.elapsed_ms());
+    let score = if replay.elapsed_ms() == 0 {
+        0.0
+    } else {
+        let seconds = i32::try_from(replay.elapsed_ms().div_ceil(1000)).unwrap_or(i32::MAX);
+        wpm::calc_wpm(typed, seconds, i32::try_from(misses).unwrap_or(i32::MAX)).max(0.0)
+    };
+    let status = if replay.is_finished() {
+        "Finished"
+    } else if replay.is_paused() {
+        "Paused"
+    } else {
+        "Playing"
+    };
+
+    vec![
+        Span::styled(
+            format!("{} ", replay.speed().label()),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(
+            "{elapsed_seconds:.1}s / {:.1}s  ",
+            ms_to_seconds(replay.duration_ms())
+        )),
+        Span::styled(format!("[{status}]  "), Style::default().fg(Color::Gray)),
+        Span::raw(format!("Typed: {typed}  Misses: {misses}  WPM: {score:.1}")),
+    ]
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn ms_to_seconds(ms: u64) -> f64 {
+    ms as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+    use crate::domain::history::Keystroke;
+
+    #[test]
+    fn replay_header_shows_speed_progress_and_counts() {
+        let mut replay = Replay::new(
+            "ab",
+            &[
+                Keystroke {
+                    elapsed_ms: 500,
+                    key: Some('x'),
+                    position: 1,
+                },
+                Keystroke {
+                    elapsed_ms: 2000,
+                    key: None,
+                    position: 0,
+                },
+            ],
+        )
+        .expect("timeline should be replayable");
+        replay.advance(1000);
+
+        let text = replay_header_spans(&replay)
+            .iter()
+            .map(|span| span.content.to_string())
+            .collect::<String>();
+
+        assert!(text.starts_with("1× 1.0s / 2.0s"));
+        assert!(text.contains("[Playing]"));
+        assert!(text.contains("Typed: 1  Misses: 1"));
+    }
+}
@@ -1,19 +1,32 @@
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::Line,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
+use crate::domain::adaptive::AdaptivePlan;
+use crate::domain::config::CorrectionPolicy;
+use crate::domain::drill::DrillCard;
+use crate::domain::history::{AttemptCount, KeyEventMode, Passage, SpeedAnalytics};
+use crate::domain::lesson::Lesson;
 use crate::presentation::ui::app::App;
 use crate::usecase::accuracy;
+use crate::usecase::text_difficulty;
+use crate::usecase::word_errors::WordErrorSummary;
 use crate::usecase::wpm;
 
 use super::common::centered_rect;
 use super::history_summary::history_summary_lines;
+use super::keyboard_heatmap::{HEATMAP_HEIGHT, HEATMAP_WIDTH, render_keyboard_heatmap};
+use super::typing::ghost_gap_text;
 use super::wpm_graph;
 
+const PASSAGE_CONTEXT_CHARS: usize = 8;
+const SUMMARY_MIN_WIDTH: u16 = 40;
+const PERSONAL_BEST_COLOR: Color = Color::Yellow;
+
 pub fn render_result(frame: &mut Frame, app: &App) {
     if app.is_rhythm_result() {
         render_rhythm_result(frame, app);
@@ -28,57 +41,343 @@ pub fn render_result(frame: &mut Frame, app: &App) {
         i32::try_from(app.incorrects()).unwrap_or(i32::MAX),
     );
     let accuracy = accuracy::calc_accuracy(app.typed_count(), app.incorrects());
+    let analytics = app.speed_analytics();
     let [metrics_area, stats_area, graph_area, footer_area] = split_result_area(area);
-    let lines = vec![
-        Line::from("Typing Finished"),
+    let (heading, title, border_color) = result_heading(app);
+    let mut lines = vec![
+        Line::from(heading),
         Line::from(""),
         Line::from(format!("Typed: {}", app.typed_count())),
         Line::from(format!("Misses: {}", app.incorrects())),
         Line::from(format!("Accuracy: {accuracy:.1}%")),
         Line::from(format!("Time: {elapsed} sec")),
         Line::from(format!("WPM: {score:.1}")),
+        Line::from(speed_line(&analytics)),
+        Line::from(key_timing_line(&analytics)),
+        Line::from(word_error_line(app.word_error_summary())),
+        Line::from(missed_words_line(&app.missed_words())),
         Line::from(format!("Source: {}", app.generation_source().label())),
         Line::from(if app.is_practice_mode() {
-            "Mode: Practice"
+            "Mode: Practice".to_string()
         } else {
-            "Mode: Timed"
+            format!("Test: {}", app.test_length().label())
         }),
     ];
+    if let Some(difficulty) = app.text_difficulty() {
+        lines.push(Line::from(difficulty_line(difficulty, score)));
+    }
+    if let Some(passage) = analytics.slowest_passage {
+        lines.push(slowest_passage_line(app.target_string(), passage));
+    }
+    if let (Some(ghost), Some(gap)) = (app.ghost(), app.final_ghost_gap()) {
+        lines.push(Line::from(format!(
+            "Ghost: {} ({})",
+            ghost.label(),
+            ghost_gap_text(gap)
+        )));
+    }
+    if let (Some(lesson), Some(passed)) = (app.active_lesson(), app.lesson_run_passed()) {
+        lines.push(lesson_result_line(lesson, passed));
+    }
+    if app.active_drill().is_some() {
+        lines.push(Line::from(drill_result_line(&app.drill_results())));
+    }
+    if let Some(plan) = app.adaptive_plan() {
+        lines.extend(adaptive_lines(plan, app.rhythm_speed()));
+    }
+    if app.correction_policy() != CorrectionPolicy::Normal && !app.is_practice_mode() {
+        lines.push(Line::from(format!(
+            "Correction: {}",
+            app.correction_policy().label()
+        )));
+    }
+    if let Some(gain) = app.personal_best_gain() {
+        lines.insert(1, personal_best_line(gain));
+    }
+    if app.is_failed() {
+        lines.insert(
+            1,
+            Line::from(Span::styled(
+                failure_reason(app.fail_accuracy()),
+                Style::default().fg(Color::Red),
+            )),
+        );
+    }
     frame.render_widget(Clear, area);
     frame.render_widget(
         Paragraph::new(lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Result ")
-                    .border_style(Style::default().fg(Color::Green)),
+                    .title(title)
+                    .border_style(Style::default().fg(border_color)),
             )
             .alignment(Alignment::Center),
         metrics_area,
     );
 
-    let stats = app.history_stats();
+    render_history_panel(frame, stats_area, app);
+
+    render_speed_graph(frame, graph_area, app, &analytics);
+
     frame.render_widget(
-        Paragraph::new(history_summary_lines(&stats))
-            .block(Block::default().borders(Borders::ALL).title(" History "))
-            .alignment(Alignment::Center),
-        stats_area,
+        Paragraph::new(vec![Line::from(if app.active_lesson().is_some() {
+            "Enter: back to lessons  r: replay  m: heatmap metric"
+        } else {
+            "Enter: return to menu  r: replay  m: heatmap metric"
+        })])
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center),
+        footer_area,
     );
+}
+
+/// Heading, title, and border color of the result panel; a new personal best
+/// turns the border yellow.
+fn result_heading(app: &App) -> (&'static str, &'static str, Color) {
+    if app.is_failed() {
+        ("Session Failed", " Failed ", Color::Red)
+    } else if app.personal_best_gain().is_some() {
+        ("Typing Finished", " Result ", PERSONAL_BEST_COLOR)
+    } else {
+        ("Typing Finished", " Result ", Color::Green)
+    }
+}
+
+fn personal_best_line(gain: f64) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("New personal best! (+{gain:.1} WPM)"),
+        Style::default()
+            .fg(Color::Black)
+            .bg(PERSONAL_BEST_COLOR)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
 
-    wpm_graph::render_wpm_graph(frame, graph_area, app.wpm_history(), " Final WPM Trend ");
+fn lesson_result_line(lesson: &Lesson, passed: bool) -> Line<'static> {
+    let (verdict, color) = if passed {
+        ("passed", Color::Green)
+    } else {
+        ("not passed", Color::Yellow)
+    };
+    Line::from(Span::styled(
+        format!(
+            "Lesson {verdict} (target {:.0} WPM, {:.0}%)",
+            lesson.target_wpm, lesson.target_accuracy
+        ),
+        Style::default().fg(color),
+    ))
+}
+
+fn difficulty_line(difficulty: f64, wpm: f64) -> String {
+    format!(
+        "Difficulty: {difficulty:.0}/100 ({})  Adjusted WPM: {:.1}",
+        text_difficulty::label(difficulty),
+        text_difficulty::adjusted_wpm(wpm, difficulty)
+    )
+}
+
+/// The adaptive level this run was played at and why it was chosen.
+fn adaptive_lines(plan: &AdaptivePlan, rhythm_speed: u8) -> [Line<'static>; 2] {
+    [
+        Line::from(format!("Adaptive {}", plan.summary(rhythm_speed))),
+        Line::from(Span::styled(
+            plan.reason.clone(),
+            Style::default().fg(Color::Gray),
+        )),
+    ]
+}
 
+/// Each drilled item with its grade (0-5) and when it is due again.
+fn drill_result_line(results: &[(&str, u8, Option<&DrillCard>)]) -> String {
+    if results.is_empty() {
+        return "Drill: no drilled item came up".to_string();
+    }
+
+    let items = results
+        .iter()
+        .map(|(item, quality, card)| {
+            let days = card.map_or(0, |card| card.interval_days);
+            let unit = if days == 1 { "day" } else { "days" };
+            format!("{item} {quality}/5 (next in {days} {unit})")
+        })
+        .collect::<Vec<_>>();
+    format!("Drill: {}", items.join("  "))
+}
+
+fn render_history_panel(frame: &mut Frame, area: Rect, app: &App) {
+    let stats = app.history_stats_for_current_test();
+    let (stats_area, heatmap_area) = split_stats_area(area);
+    if let Some(heatmap_area) = heatmap_area {
+        render_keyboard_heatmap(
+            frame,
+            heatmap_area,
+            app.keyboard_layout(),
+            &app.session_key_report(),
+            app.heatmap_metric(),
+            " Keys (m: metric) ",
+        );
+    }
     frame.render_widget(
-        Paragraph::new(vec![Line::from("Press Enter to return to menu")])
-            .block(Block::default().borders(Borders::ALL))
+        Paragraph::new(history_summary_lines(&stats, "No timed history yet"))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" History ({}) ", app.test_length().label())),
+            )
             .alignment(Alignment::Center),
-        footer_area,
+        stats_area,
+    );
+}
+
+fn render_speed_graph(frame: &mut Frame, area: Rect, app: &App, analytics: &SpeedAnalytics) {
+    if analytics.per_second_wpm.is_empty() {
+        wpm_graph::render_wpm_graph(frame, area, app.wpm_history(), " Final WPM Trend ");
+    } else {
+        // Sample `n` covers the second ending at `n + 1`, so errors shift left
+        // by one second to line up with it.
+        let markers = analytics
+            .error_seconds
+            .iter()
+            .map(|seconds| (seconds - 1.0).max(0.0))
+            .collect::<Vec<_>>();
+        wpm_graph::render_wpm_graph_with_markers(
+            frame,
+            area,
+            &analytics.per_second_wpm,
+            " Speed per Second (red: errors) ",
+            &markers,
+        );
+    }
+}
+
+fn speed_line(analytics: &SpeedAnalytics) -> String {
+    let consistency = match (analytics.consistency(), analytics.speed_cv) {
+        (Some(consistency), Some(cv)) => format!("{consistency:.0}% (CV {cv:.0}%)"),
+        _ => "-".to_string(),
+    };
+    format!(
+        "Consistency: {consistency}  Burst: {:.1} WPM",
+        analytics.burst_wpm
+    )
+}
+
+/// Names the key event mode so press-only terminals explain the missing
+/// hold times.
+fn key_timing_line(analytics: &SpeedAnalytics) -> String {
+    let mode = analytics.key_events.label();
+    match (analytics.key_events, &analytics.hold_times) {
+        (KeyEventMode::PressOnly, _) => {
+            format!("Key timing: {mode} (terminal does not report releases)")
+        }
+        (KeyEventMode::PressRelease, None) => format!("Key timing: {mode}, no releases recorded"),
+        (KeyEventMode::PressRelease, Some(holds)) => {
+            let flight = holds
+                .average_flight_ms
+                .map_or_else(|| "-".to_string(), |flight| format!("{flight:.0} ms"));
+            let mut parts = vec![
+                format!("Key timing: {mode}"),
+                format!("Dwell: {:.0} ms", holds.average_dwell_ms),
+                format!("Flight: {flight}"),
+            ];
+            if !holds.longest_holds.is_empty() {
+                let longest = holds
+                    .longest_holds
+                    .iter()
+                    .map(|hold| format!("{} {:.0} ms", visible_key(hold.key), hold.dwell_ms))
+                    .collect::<Vec<_>>();
+                parts.push(format!("Longest: {}", longest.join(", ")));
+            }
+            parts.join("  ")
+        }
+    }
+}
+
+fn visible_key(key: char) -> String {
+    if key == ' ' {
+        "space".to_string()
+    } else {
+        key.to_string()
+    }
+}
+
+/// Shows the slowest passage highlighted within a little of the text around it.
+fn slowest_passage_line(target: &str, passage: Passage) -> Line<'static> {
+    let chars = target.chars().collect::<Vec<_>>();
+    let context_start = passage.start.saturating_sub(PASSAGE_CONTEXT_CHARS);
+    let context_end = (passage.end + PASSAGE_CONTEXT_CHARS).min(chars.len());
+    let slice = |start: usize, end: usize| {
+        chars
+            .get(start..end.max(start))
+            .unwrap_or_default()
+            .iter()
+            .collect::<String>()
+    };
+    let mut before = slice(context_start, passage.start);
+    if context_start > 0 {
+        before.insert(0, '…');
+    }
+    let mut after = slice(passage.end, context_end);
+    if context_end < chars.len() {
+        after.push('…');
+    }
+
+    Line::from(vec![
+        Span::raw("Slowest: "),
+        Span::styled(before, Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            slice(passage.start, passage.end),
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        ),
+        Span::styled(after, Style::default().fg(Color::DarkGray)),
+        Span::raw(format!(" ({:.1} s)", passage.seconds)),
+    ])
+}
+
+fn word_error_line(summary: WordErrorSummary) -> String {
+    let line = format!(
+        "Words: {} typed, {} with errors, {} corrected",
+        summary.typed, summary.with_errors, summary.corrected
     );
+    if summary.skipped > 0 {
+        format!("{line}, {} skipped", summary.skipped)
+    } else {
+        line
+    }
+}
+
+/// Words of this run with a mistake, most misses first; a word missed more
+/// than once shows the count.
+fn missed_words_line(words: &[(String, AttemptCount)]) -> String {
+    if words.is_empty() {
+        return "Missed words: none".to_string();
+    }
+
+    let words = words
+        .iter()
+        .map(|(word, count)| {
+            if count.misses > 1 {
+                format!("{word} x{}", count.misses)
+            } else {
+                word.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("Missed words: {}", words.join(", "))
+}
+
+fn failure_reason(fail_accuracy: f64) -> String {
+    if fail_accuracy >= 100.0 {
+        "Sudden death: the first error ended the run".to_string()
+    } else {
+        format!("Sudden death: accuracy fell below {fail_accuracy:.0}%")
+    }
 }
 
 fn render_rhythm_result(frame: &mut Frame, app: &App) {
     let area = centered_rect(70, 45, frame.area());
     let stats = app.rhythm_stats();
-    let lines = stats.map_or_else(
+    let mut lines = stats.map_or_else(
         || {
             vec![
                 Line::from("Rhythm Finished"),
@@ -88,6 +387,7 @@ fn render_rhythm_result(frame: &mut Frame, app: &App) {
                 Line::from("Hit: 0"),
                 Line::from("OK: 0"),
                 Line::from("Misses: 0"),
+                Line::from("Max combo: 0"),
                 Line::from("Accuracy: 0.0%"),
                 Line::from("Mode: Rhythm"),
             ]
@@ -101,11 +401,29 @@ fn render_rhythm_result(frame: &mut Frame, app: &App) {
                 Line::from(format!("Hit: {}", stats.hit)),
                 Line::from(format!("OK: {}", stats.ok)),
                 Line::from(format!("Misses: {}", stats.miss)),
+                Line::from(format!("Max combo: {}", stats.max_combo)),
                 Line::from(format!("Accuracy: {:.1}%", stats.accuracy)),
                 Line::from("Mode: Rhythm"),
             ]
         },
     );
+    if let Some(difficulty) = app.text_difficulty() {
+        lines.push(Line::from(format!(
+            "Difficulty: {difficulty:.0}/100 ({})",
+            text_difficulty::label(difficulty)
+        )));
+    }
+    if let Some(plan) = app.adaptive_plan() {
+        lines.extend(adaptive_lines(plan, app.rhythm_speed()));
+    }
+    if let Some(gain) = app.personal_best_gain() {
+        lines.insert(1, personal_best_line(gain));
+    }
+    let border_color = if app.personal_best_gain().is_some() {
+        PERSONAL_BEST_COLOR
+    } else {
+        Color::Green
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(10), Constraint::Length(3)])
@@ -121,7 +439,7 @@ fn render_rhythm_result(frame: &mut Frame, app: &App) {
                 Block::default()
                     .borders(Borders::ALL)
                     .title(" Result ")
-                    .border_style(Style::default().fg(Color::Green)),
+                    .border_style(Style::default().fg(border_color)),
             )
             .alignment(Alignment::Center),
         *metrics_area,
@@ -134,6 +452,24 @@ fn render_rhythm_result(frame: &mut Frame, app: &App) {
     );
 }
 
+/// Puts the keyboard heatmap beside the history summary when both fit.
+fn split_stats_area(area: Rect) -> (Rect, Option<Rect>) {
+    if area.height < HEATMAP_HEIGHT || area.width < HEATMAP_WIDTH + SUMMARY_MIN_WIDTH {
+        return (area, None);
+    }
+
+    let summary_width = area.width - HEATMAP_WIDTH;
+    (
+        Rect::new(area.x, area.y, summary_width, area.height),
+        Some(Rect::new(
+            area.x + summary_width,
+            area.y,
+            HEATMAP_WIDTH,
+            area.height,
+        )),
+    )
+}
+
 fn split_result_area(area: Rect) -> [Rect; 4] {
     let stats_height = if area.height >= 24 { 10 } else { 0 };
     let graph_height = if area.height >= 18 { 6 } else { 0 };
@@ -180,9 +516,27 @@ fn split_result_area(area: Rect) -> [Rect; 4] {
 
 #[cfg(test)]
 mod tests {
-    use super::split_result_area;
+    #![expect(clippy::expect_used)]
+    use super::{
+        difficulty_line, drill_result_line, failure_reason, key_timing_line, missed_words_line,
+        personal_best_line, slowest_passage_line, speed_line, split_result_area, split_stats_area,
+        word_error_line,
+    };
+    use crate::domain::drill::DrillCard;
+    use crate::domain::history::{
+        AttemptCount, HoldTimes, KeyEventMode, KeyHold, Passage, SpeedAnalytics,
+    };
+    use crate::usecase::word_errors::WordErrorSummary;
     use ratatui::layout::Rect;
 
+    #[test]
+    fn personal_best_line_shows_the_gain() {
+        assert_eq!(
+            personal_best_line(3.24).to_string(),
+            "New personal best! (+3.2 WPM)"
+        );
+    }
+
     #[test]
     fn split_result_area_reserves_graph_and_footer_when_height_allows() {
         let [metrics_area, stats_area, graph_area, footer_area] =
@@ -219,4 +573,148 @@ mod tests {
         assert_eq!(graph_area.height, 0);
         assert_eq!(footer_area.height, 0);
     }
+
+    #[test]
+    fn split_stats_area_adds_heatmap_only_when_wide_enough() {
+        let (summary, heatmap) = split_stats_area(Rect::new(0, 0, 100, 10));
+        let heatmap = heatmap.expect("100 columns fit both panels");
+        assert_eq!(summary.width + heatmap.width, 100);
+        assert_eq!(heatmap.x, summary.x + summary.width);
+
+        let (summary, heatmap) = split_stats_area(Rect::new(0, 0, 70, 10));
+        assert_eq!(summary.width, 70);
+        assert!(heatmap.is_none());
+    }
+
+    #[test]
+    fn failure_reason_distinguishes_first_error_and_threshold() {
+        assert!(failure_reason(100.0).contains("first error"));
+        assert!(failure_reason(92.0).contains("below 92%"));
+    }
+
+    #[test]
+    fn missed_words_line_counts_repeated_misses() {
+        let count = |attempts, misses| AttemptCount { attempts, misses };
+        let words = [
+            ("the".to_string(), count(3, 2)),
+            ("cat".to_string(), count(1, 1)),
+        ];
+
+        assert_eq!(missed_words_line(&words), "Missed words: the x2, cat");
+        assert_eq!(missed_words_line(&[]), "Missed words: none");
+    }
+
+    #[test]
+    fn word_error_line_mentions_skips_only_when_present() {
+        let summary = WordErrorSummary {
+            typed: 5,
+            with_errors: 2,
+            corrected: 1,
+            skipped: 0,
+        };
+        assert_eq!(
+            word_error_line(summary),
+            "Words: 5 typed, 2 with errors, 1 corrected"
+        );
+
+        let summary = WordErrorSummary {
+            skipped: 1,
+            ..summary
+        };
+        assert!(word_error_line(summary).ends_with(", 1 skipped"));
+    }
+
+    #[test]
+    fn key_timing_line_names_the_mode_and_hold_times() {
+        assert_eq!(
+            key_timing_line(&SpeedAnalytics::default()),
+            "Key timing: press only (terminal does not report releases)"
+        );
+
+        let analytics = SpeedAnalytics {
+            key_events: KeyEventMode::PressRelease,
+            hold_times: Some(HoldTimes {
+                average_dwell_ms: 92.4,
+                average_flight_ms: Some(-12.0),
+                longest_holds: vec![KeyHold {
+                    key: ' ',
+                    dwell_ms: 140.0,
+                    flight_ms: None,
+                }],
+            }),
+            ..SpeedAnalytics::default()
+        };
+        assert_eq!(
+            key_timing_line(&analytics),
+            "Key timing: press + release  Dwell: 92 ms  Flight: -12 ms  Longest: space 140 ms"
+        );
+    }
+
+    #[test]
+    fn speed_line_shows_consistency_and_burst() {
+        let analytics = SpeedAnalytics {
+            speed_cv: Some(18.4),
+            burst_wpm: 95.25,
+            ..SpeedAnalytics::default()
+        };
+        assert_eq!(
+            speed_line(&analytics),
+            "Consistency: 82% (CV 18%)  Burst: 95.2 WPM"
+        );
+        assert!(speed_line(&SpeedAnalytics::default()).starts_with("Consistency: -"));
+    }
+
+    #[test]
+    fn slowest_passage_line_highlights_the_passage_within_context() {
+        let line = slowest_passage_line(
+            "the quick brown fox jumps over the lazy dog",
+            Passage {
+                start: 16,
+                end: 25,
+                seconds: 2.44,
+            },
+        );
+        let texts = line
+            .spans
+            .iter()
+            .map(|span| span.content.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            texts,
+            vec![
+                "Slowest: ",
+                "…k brown ",
+                "fox jumps",
+                " over th…",
+                " (2.4 s)"
+            ]
+        );
+    }
+
+    #[test]
+    fn difficulty_line_shows_the_score_and_adjusted_wpm() {
+        assert_eq!(
+            difficulty_line(54.4, 40.0),
+            "Difficulty: 54/100 (hard)  Adjusted WPM: 49.8"
+        );
+    }
+
+    #[test]
+    fn drill_result_line_shows_grades_and_next_review() {
+        let card = DrillCard {
+            interval_days: 6,
+            ..DrillCard::default()
+        };
+        let retry = DrillCard {
+            interval_days: 1,
+            ..DrillCard::default()
+        };
+
+        assert_eq!(
+            drill_result_line(&[("th", 5, Some(&card)), ("q", 1, Some(&retry))]),
+            "Drill: th 5/5 (next in 6 days)  q 1/5 (next in 1 day)"
+        );
+        assert_eq!(drill_result_line(&[]), "Drill: no drilled item came up");
+    }
 }
@@ -1,32 +1,401 @@
 use ratatui::{
     Frame,
-    layout::Alignment,
-    style::{Color, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
-use crate::presentation::ui::app::App;
+use crate::domain::layout::{Finger, Hand, KeyboardLayout};
+use crate::domain::timestamp;
+use crate::presentation::ui::app::{App, StatsPage};
+use crate::usecase::finger_stats::FingerReport;
+use crate::usecase::history_filter::{HistoryFilter, ModeFilter};
+use crate::usecase::key_stats::KeyStat;
+use crate::usecase::ngram_stats::{MIN_NGRAM_SAMPLES, NgramReport, NgramStat};
+use crate::usecase::personal_best::PersonalBest;
 
-use super::common::centered_rect;
-use super::history_summary::history_summary_lines;
+use super::common::{centered_rect, top_centered_rect};
+use super::history_summary::{history_summary_lines, speed_trend_lines, untimed_sessions_line};
+use super::keyboard_heatmap::{HEATMAP_HEIGHT, HEATMAP_WIDTH, render_keyboard_heatmap};
+use super::progress_chart::render_progress_chart;
+
+const NGRAM_LIMIT: usize = 5;
 
 pub fn render_stats(frame: &mut Frame, app: &App) {
     let area = centered_rect(70, 60, frame.area());
-    let stats = app.history_stats();
-    let mut lines = history_summary_lines(&stats);
-    lines.push("".into());
-    lines.push("Press Enter or Esc to return to menu".into());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Stats ")
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
 
     frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+    let [tabs_area, body_area, hint_area] = &*chunks else {
+        return;
+    };
+
+    frame.render_widget(
+        Paragraph::new(vec![
+            page_tabs(app.stats_page()),
+            filter_line(app.stats_filter()),
+        ])
+        .alignment(Alignment::Center),
+        *tabs_area,
+    );
+    match app.stats_page() {
+        StatsPage::Summary => render_summary(frame, *body_area, app),
+        StatsPage::Progress => {
+            render_progress_chart(frame, *body_area, app.progress_plot(), app.progress_view());
+        }
+        StatsPage::Bests => render_bests(frame, *body_area, app),
+        StatsPage::Ngrams => render_ngrams(frame, *body_area, app),
+        StatsPage::Keyboard => render_keyboard(frame, *body_area, app),
+        StatsPage::Fingers => render_fingers(frame, *body_area, app),
+    }
+    frame.render_widget(
+        Paragraph::new(
+            app.status_message()
+                .unwrap_or_else(|| hint_line(app.stats_page())),
+        )
+        .alignment(Alignment::Center),
+        *hint_area,
+    );
+}
+
+fn hint_line(page: StatsPage) -> &'static str {
+    match page {
+        StatsPage::Keyboard => {
+            "Tab: next page  m: error rate / latency  b: browse history  Enter/Esc: menu"
+        }
+        StatsPage::Progress => "Tab: next page  c: chart  ←/→: scroll  ↑/↓: zoom  Enter/Esc: menu",
+        StatsPage::Summary | StatsPage::Bests | StatsPage::Ngrams | StatsPage::Fingers => {
+            "Tab: next page  b: browse and replay history  Enter/Esc: return to menu"
+        }
+    }
+}
+
+fn filter_line(filter: HistoryFilter) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            format!("Showing {}", filter.label()),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::styled(
+            "  (s: source  o: mode  l: length  d: days  e/j: export CSV/JSON)",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
+
+fn page_tabs(current: StatsPage) -> Line<'static> {
+    let mut spans = Vec::new();
+    for page in StatsPage::ALL {
+        if !spans.is_empty() {
+            spans.push(Span::raw("  "));
+        }
+        let style = if page == current {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        spans.push(Span::styled(format!("[{}]", page.label()), style));
+    }
+    Line::from(spans)
+}
+
+fn render_summary(frame: &mut Frame, area: Rect, app: &App) {
+    let stats = app.history_stats();
+    let mut lines = history_summary_lines(stats, "No runs match the filter");
+    lines.extend(speed_trend_lines(stats));
+    if app.stats_filter().mode == ModeFilter::Timed {
+        let (practice, rhythm) = app.untimed_session_counts();
+        lines.extend(untimed_sessions_line(practice, rhythm));
+    }
+
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+}
+
+fn render_bests(frame: &mut Frame, area: Rect, app: &App) {
+    let lines = best_lines(app.personal_bests());
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+}
+
+fn best_lines(bests: &[PersonalBest]) -> Vec<Line<'static>> {
+    let heading = Style::default().add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(Span::styled("Personal Bests", heading)),
+        Line::from(""),
+    ];
+    if bests.is_empty() {
+        lines.push(Line::from("No runs match the filter"));
+        return lines;
+    }
+    lines.extend(bests.iter().map(|best| Line::from(best_row(best))));
+    lines
+}
+
+fn best_row(best: &PersonalBest) -> String {
+    let date = best.timestamp.map_or_else(
+        || "-".to_string(),
+        |seconds| timestamp::format_date(timestamp::day_number(seconds)),
+    );
+    format!(
+        "{:<32} {:>6.1} WPM  {:>5.1}%  {date:<10}  n={}",
+        best.category.label(),
+        best.wpm,
+        best.accuracy,
+        best.runs
+    )
+}
+
+fn render_ngrams(frame: &mut Frame, area: Rect, app: &App) {
+    let report = app.ngram_report();
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let [bigram_area, trigram_area] = &*columns else {
+        return;
+    };
+
+    frame.render_widget(
+        Paragraph::new(ngram_lines("Bigrams", report, 2)),
+        *bigram_area,
+    );
     frame.render_widget(
-        Paragraph::new(lines)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Stats ")
-                    .border_style(Style::default().fg(Color::Cyan)),
-            )
-            .alignment(Alignment::Center),
+        Paragraph::new(ngram_lines("Trigrams", report, 3)),
+        *trigram_area,
+    );
+}
+
+fn render_keyboard(frame: &mut Frame, area: Rect, app: &App) {
+    let area = top_centered_rect(area, HEATMAP_WIDTH, HEATMAP_HEIGHT);
+    render_keyboard_heatmap(
+        frame,
         area,
+        app.keyboard_layout(),
+        app.history_key_report(),
+        app.heatmap_metric(),
+        " All runs ",
     );
 }
+
+fn render_fingers(frame: &mut Frame, area: Rect, app: &App) {
+    let lines = finger_lines(app.keyboard_layout(), app.finger_report());
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+}
+
+fn finger_lines(layout: &KeyboardLayout, report: &FingerReport) -> Vec<Line<'static>> {
+    let heading = Style::default().add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(Span::styled(format!("Layout: {}", layout.name), heading)),
+        Line::from(""),
+    ];
+    lines.extend(
+        [Hand::Left, Hand::Right]
+            .into_iter()
+            .map(|hand| Line::from(finger_row(hand.label(), report.hands.get(&hand)))),
+    );
+    lines.push(Line::from(""));
+    lines.extend(
+        Finger::ALL
+            .into_iter()
+            .map(|finger| Line::from(finger_row(finger.label(), report.fingers.get(&finger)))),
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!(
+        "Same-finger bigrams: {} ({})",
+        report.same_finger_bigrams,
+        format_percent(report.same_finger_ratio())
+    )));
+    lines.push(Line::from(format!(
+        "Hand alternation: {}",
+        format_percent(report.alternation_ratio())
+    )));
+    lines
+}
+
+fn finger_row(label: &str, stat: Option<&KeyStat>) -> String {
+    let Some(stat) = stat.filter(|stat| stat.attempts > 0) else {
+        return format!("{label:<10}      -");
+    };
+    let accuracy = stat.error_rate().map(|rate| 100.0 - rate);
+    let latency = stat
+        .average_latency_ms()
+        .map_or_else(|| "-".to_string(), |latency| format!("{latency:.0} ms"));
+    format!(
+        "{label:<10} {:>6}  {latency:>7}  n={}",
+        format_percent(accuracy),
+        stat.attempts
+    )
+}
+
+fn format_percent(value: Option<f64>) -> String {
+    value.map_or_else(|| "-".to_string(), |value| format!("{value:.1}%"))
+}
+
+fn ngram_lines(title: &str, report: &NgramReport, size: usize) -> Vec<Line<'static>> {
+    let heading = Style::default().add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(Span::styled(title.to_string(), heading)),
+        Line::from(""),
+        Line::from("Slowest (avg latency)"),
+    ];
+    lines.extend(rows(report.slowest(size, NGRAM_LIMIT), ngram_latency_row));
+    lines.push(Line::from(""));
+    lines.push(Line::from("Most errors (error rate)"));
+    lines.extend(rows(
+        report.most_error_prone(size, NGRAM_LIMIT),
+        ngram_error_row,
+    ));
+    lines
+}
+
+fn rows(stats: Vec<&NgramStat>, format: fn(&NgramStat) -> String) -> Vec<Line<'static>> {
+    if stats.is_empty() {
+        return vec![Line::from(format!(
+            "  - (needs {MIN_NGRAM_SAMPLES}+ samples)"
+        ))];
+    }
+
+    stats
+        .into_iter()
+        .map(|stat| Line::from(format(stat)))
+        .collect()
+}
+
+fn ngram_latency_row(stat: &NgramStat) -> String {
+    format!(
+        "  {:<4} {:>5.0} ms  n={}",
+        visible_ngram(&stat.ngram),
+        stat.average_latency_ms().unwrap_or_default(),
+        stat.latency_samples
+    )
+}
+
+fn ngram_error_row(stat: &NgramStat) -> String {
+    format!(
+        "  {:<4} {:>5.1}%  {}/{}",
+        visible_ngram(&stat.ngram),
+        stat.error_rate(),
+        stat.errors,
+        stat.attempts
+    )
+}
+
+/// Spaces are shown as `␣` so transitions into and out of words stay readable.
+fn visible_ngram(ngram: &str) -> String {
+    ngram
+        .chars()
+        .map(|ch| if ch == ' ' { '␣' } else { ch })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stat(ngram: &str) -> NgramStat {
+        NgramStat {
+            ngram: ngram.to_string(),
+            attempts: 17,
+            errors: 3,
+            latency_samples: 14,
+            total_latency_ms: 2548,
+        }
+    }
+
+    #[test]
+    fn ngram_rows_show_latency_and_error_rate() {
+        assert_eq!(ngram_latency_row(&stat("th")), "  th     182 ms  n=14");
+        assert_eq!(ngram_error_row(&stat("e t")), "  e␣t   17.6%  3/17");
+    }
+
+    #[test]
+    fn empty_rankings_explain_the_sample_minimum() {
+        let lines = ngram_lines("Bigrams", &NgramReport::default(), 2);
+
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.to_string().contains("needs 3+ samples"))
+        );
+    }
+
+    #[test]
+    fn finger_rows_show_accuracy_latency_and_samples() {
+        let stat = KeyStat {
+            attempts: 40,
+            errors: 2,
+            latency_samples: 30,
+            total_latency_ms: 5460,
+        };
+
+        assert_eq!(
+            finger_row("L pinky", Some(&stat)),
+            "L pinky     95.0%   182 ms  n=40"
+        );
+        assert_eq!(finger_row("R ring", None), "R ring          -");
+    }
+
+    #[test]
+    fn finger_lines_summarize_hands_fingers_and_transitions() {
+        let report = FingerReport {
+            hand_pairs: 8,
+            alternating_pairs: 4,
+            same_finger_bigrams: 1,
+            ..FingerReport::default()
+        };
+        let lines = finger_lines(&crate::domain::layout::DVORAK, &report)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines.first().map(String::as_str), Some("Layout: Dvorak"));
+        assert!(lines.contains(&"Same-finger bigrams: 1 (12.5%)".to_string()));
+        assert!(lines.contains(&"Hand alternation: 50.0%".to_string()));
+        assert_eq!(lines.len(), 17);
+    }
+
+    #[test]
+    fn best_rows_show_speed_accuracy_date_and_runs() {
+        let best = PersonalBest {
+            category: crate::usecase::personal_best::BestCategory {
+                mode: "timed",
+                test: Some(crate::domain::history::TestLength::Time(30)),
+                source: "Local".to_string(),
+            },
+            wpm: 72.46,
+            accuracy: 98.0,
+            timestamp: Some(1_792_389_143),
+            runs: 12,
+        };
+
+        assert_eq!(
+            best_row(&best),
+            "time 30s Local                     72.5 WPM   98.0%  2026-10-19  n=12"
+        );
+        assert_eq!(best_lines(&[]).len(), 3);
+    }
+
+    #[test]
+    fn page_tabs_list_every_page() {
+        assert_eq!(
+            page_tabs(StatsPage::Ngrams).to_string(),
+            "[Summary]  [Progress]  [Bests]  [N-grams]  [Keyboard]  [Fingers]"
+        );
+    }
+}
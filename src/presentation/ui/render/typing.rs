@@ -6,6 +6,9 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Wrap},
 };
 
+use crate::domain::config::CorrectionPolicy;
+use crate::domain::ghost::GhostGap;
+use crate::domain::history::TestLength;
 use crate::presentation::ui::app::App;
 
 use super::common::render_decoration_block;
@@ -52,19 +55,36 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
         return;
     };
 
-    let (time_label, time_text, time_color) = if app.timeout() <= 0 {
-        ("Elapsed: ", format!("{:03}", app.timer()), Color::Cyan)
-    } else {
-        let time_remaining = app.timeout().saturating_sub(app.timer());
-        let color = if time_remaining <= 10 {
-            Color::Red
-        } else if time_remaining <= 30 {
-            Color::Yellow
+    let (time_label, time_text, time_unit, time_color) =
+        if let (TestLength::Words(_), false) = (app.test_length(), app.is_practice_mode()) {
+            (
+                "Words: ",
+                format!(
+                    "{:03}/{:03}",
+                    app.completed_word_count(),
+                    app.target_word_count()
+                ),
+                "",
+                Color::Cyan,
+            )
+        } else if app.timeout() <= 0 {
+            (
+                "Elapsed: ",
+                format!("{:03}", app.timer()),
+                " s",
+                Color::Cyan,
+            )
         } else {
-            Color::Green
+            let time_remaining = app.timeout().saturating_sub(app.timer());
+            let color = if time_remaining <= 10 {
+                Color::Red
+            } else if time_remaining <= 30 {
+                Color::Yellow
+            } else {
+                Color::Green
+            };
+            ("Time: ", format!("{time_remaining:03}"), " s", color)
         };
-        ("Time: ", format!("{time_remaining:03}"), color)
-    };
 
     let countdown_widget = vec![Line::from(vec![
         Span::styled(time_label, Style::default().fg(Color::Gray)),
@@ -72,7 +92,7 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
             time_text,
             Style::default().fg(time_color).add_modifier(Modifier::BOLD),
         ),
-        Span::styled(" s", Style::default().fg(Color::Gray)),
+        Span::styled(time_unit, Style::default().fg(Color::Gray)),
     ])];
 
     frame.render_widget(
@@ -87,7 +107,7 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
         crate::usecase::generate_sentence::GenerationSource::Google => "GOOGLE",
         crate::usecase::generate_sentence::GenerationSource::Groq => "GROQ",
     };
-    let title = Paragraph::new(Line::from(vec![
+    let mut title_spans = vec![
         Span::styled(
             " R-Typing ",
             Style::default()
@@ -98,8 +118,15 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
             format!("[{provider_label}]"),
             Style::default().fg(Color::Yellow),
         ),
-    ]))
-    .alignment(Alignment::Center);
+    ];
+    let policy = app.correction_policy();
+    if policy != CorrectionPolicy::Normal && !app.is_practice_mode() {
+        title_spans.push(Span::styled(
+            format!(" [{}]", policy.label().to_uppercase()),
+            Style::default().fg(Color::LightRed),
+        ));
+    }
+    let title = Paragraph::new(Line::from(title_spans)).alignment(Alignment::Center);
     frame.render_widget(title, *title_area);
 
     let wpm_text = vec![Line::from(vec![
@@ -135,7 +162,7 @@ fn render_typing_area(frame: &mut Frame, area: Rect, app: &App) {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Target Text ")
+                    .title(target_text_title(app))
                     .border_style(Style::default().fg(Color::Cyan)),
             )
             .wrap(Wrap { trim: false }),
@@ -143,9 +170,46 @@ fn render_typing_area(frame: &mut Frame, area: Rect, app: &App) {
     );
 }
 
+/// Lessons name themselves and list the keys they introduce; drills list the
+/// items they practise.
+fn target_text_title(app: &App) -> String {
+    if let Some(items) = app.active_drill() {
+        return format!(" Drill: {} ", items.join(" "));
+    }
+    match (app.active_lesson_number(), app.active_lesson()) {
+        (Some(number), Some(lesson)) => lesson_title(number, lesson.title, &app.lesson_new_keys()),
+        _ => " Target Text ".to_string(),
+    }
+}
+
+fn lesson_title(number: usize, title: &str, new_keys: &[char]) -> String {
+    let keys = new_keys
+        .iter()
+        .map(char::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(" Lesson {number}: {title} - new keys: {keys} ")
+}
+
 fn target_text_lines(app: &App, content_width: u16) -> Vec<Line<'static>> {
-    let target_chars = app.target_string().chars().collect::<Vec<_>>();
-    let wrapped_lines = wrapped_lines(app.target_string(), content_width);
+    styled_target_lines(
+        app.target_string(),
+        app.input_chars(),
+        app.ghost_position(),
+        content_width,
+    )
+}
+
+/// Wraps `target` and colours each character against `inputs`, with two blank
+/// lines above and below. Shared by the typing and replay screens.
+pub(super) fn styled_target_lines(
+    target: &str,
+    inputs: &[char],
+    ghost_position: Option<usize>,
+    content_width: u16,
+) -> Vec<Line<'static>> {
+    let target_chars = target.chars().collect::<Vec<_>>();
+    let wrapped_lines = wrapped_lines(target, content_width);
     let mut lines = Vec::with_capacity(wrapped_lines.len() + 4);
     lines.push(Line::from(""));
     lines.push(Line::from(""));
@@ -158,7 +222,13 @@ fn target_text_lines(app: &App, content_width: u16) -> Vec<Line<'static>> {
                     .get(wrapped_char.character_index)
                     .copied()
                     .map(|target_char| {
-                        target_char_span(app, wrapped_char.character_index, target_char)
+                        let span =
+                            input_char_span(inputs, wrapped_char.character_index, target_char);
+                        if ghost_position == Some(wrapped_char.character_index) {
+                            ghost_caret(span)
+                        } else {
+                            span
+                        }
                     })
             })
             .collect::<Vec<_>>();
@@ -170,14 +240,26 @@ fn target_text_lines(app: &App, content_width: u16) -> Vec<Line<'static>> {
     lines
 }
 
+#[cfg(test)]
 fn target_char_span(app: &App, index: usize, target_char: char) -> Span<'static> {
-    match index.cmp(&app.input_chars().len()) {
+    input_char_span(app.input_chars(), index, target_char)
+}
+
+fn input_char_span(inputs: &[char], index: usize, target_char: char) -> Span<'static> {
+    match index.cmp(&inputs.len()) {
         std::cmp::Ordering::Less => {
-            let Some(input_char) = app.input_chars().get(index).copied() else {
+            let Some(input_char) = inputs.get(index).copied() else {
                 return Span::raw("");
             };
             if input_char == target_char {
                 Span::styled(input_char.to_string(), Style::default().fg(Color::Green))
+            } else if input_char.is_whitespace() && !target_char.is_whitespace() {
+                Span::styled(
+                    target_char.to_string(),
+                    Style::default()
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::UNDERLINED),
+                )
             } else {
                 Span::styled(
                     target_char.to_string(),
@@ -197,6 +279,13 @@ fn target_char_span(app: &App, index: usize, target_char: char) -> Span<'static>
     }
 }
 
+/// Marks where the ghost is with a magenta background, keeping the character
+/// colour so correctness stays readable underneath.
+fn ghost_caret(span: Span<'static>) -> Span<'static> {
+    let style = span.style.bg(Color::Magenta);
+    span.style(style)
+}
+
 fn split_typing_area(area: Rect) -> [Rect; 2] {
     let graph_height = if area.height >= TYPING_AREA_MIN_HEIGHT {
         4
@@ -317,14 +406,47 @@ fn character_width(character: char) -> u16 {
 }
 
 fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
+    let ghost_gap = app.ghost_gap();
+    let constraints = if ghost_gap.is_some() {
+        [
+            Constraint::Percentage(33),
+            Constraint::Percentage(34),
+            Constraint::Percentage(33),
+        ]
+    } else {
+        [
+            Constraint::Percentage(50),
+            Constraint::Length(0),
+            Constraint::Percentage(50),
+        ]
+    };
     let footer_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints(constraints)
         .split(area);
-    let [types_area, misses_area] = &*footer_chunks else {
+    let [types_area, ghost_area, misses_area] = &*footer_chunks else {
         return;
     };
 
+    if let Some(gap) = ghost_gap {
+        let color = if gap.chars >= 0 {
+            Color::Green
+        } else {
+            Color::LightRed
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Ghost: ", Style::default().fg(Color::Magenta)),
+                Span::styled(
+                    ghost_gap_text(gap),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+            ]))
+            .alignment(Alignment::Center),
+            *ghost_area,
+        );
+    }
+
     frame.render_widget(
         Paragraph::new(Line::from(vec![
             Span::styled("Types: ", Style::default().fg(Color::Gray)),
@@ -357,14 +479,27 @@ fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
     );
 }
 
+pub(super) fn ghost_gap_text(gap: GhostGap) -> String {
+    let seconds = gap.seconds.map_or_else(
+        || "finished".to_string(),
+        |seconds| format!("{seconds:+.1} s"),
+    );
+    format!("{:+} chars / {seconds}", gap.chars)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{split_typing_area, target_char_span, target_text_lines};
-    use crate::domain::config::AppConfig;
+    use super::{
+        ghost_caret, ghost_gap_text, lesson_title, split_typing_area, target_char_span,
+        target_text_lines,
+    };
+    use crate::domain::config::{AppConfig, GameSettings};
+    use crate::domain::ghost::GhostGap;
     use crate::presentation::ui::app::App;
     use ratatui::{
         layout::Rect,
-        style::{Color, Modifier},
+        style::{Color, Modifier, Style},
+        text::Span,
     };
 
     fn new_app_with_target(target: &str, input_len: usize) -> App {
@@ -461,4 +596,56 @@ mod tests {
         assert_eq!(span.style.fg, Some(Color::White));
         assert_eq!(span.style.bg, Some(Color::Red));
     }
+
+    #[test]
+    fn skipped_characters_are_underlined_in_light_red() {
+        let mut app = App::new(AppConfig {
+            game: GameSettings {
+                space_skip: "true".to_string(),
+                ..GameSettings::default()
+            },
+            ..AppConfig::default()
+        });
+        app.prepare_new_game("abc d".to_string());
+        app.push_char('a');
+        app.push_char(' ');
+        let span = target_char_span(&app, 1, 'b');
+
+        assert_eq!(span.style.fg, Some(Color::LightRed));
+        assert!(span.style.add_modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn lesson_title_lists_new_keys() {
+        assert_eq!(
+            lesson_title(2, "Top row", &['q', 'w', 'e']),
+            " Lesson 2: Top row - new keys: q w e "
+        );
+    }
+
+    #[test]
+    fn ghost_gap_text_shows_signed_chars_and_seconds() {
+        assert_eq!(
+            ghost_gap_text(GhostGap {
+                chars: 3,
+                seconds: Some(0.84),
+            }),
+            "+3 chars / +0.8 s"
+        );
+        assert_eq!(
+            ghost_gap_text(GhostGap {
+                chars: -2,
+                seconds: None,
+            }),
+            "-2 chars / finished"
+        );
+    }
+
+    #[test]
+    fn ghost_caret_keeps_foreground_and_adds_background() {
+        let span = ghost_caret(Span::styled("a", Style::default().fg(Color::Gray)));
+
+        assert_eq!(span.style.fg, Some(Color::Gray));
+        assert_eq!(span.style.bg, Some(Color::Magenta));
+    }
 }
@@ -10,12 +10,75 @@ use ratatui::{
 };
 
 const WPM_GRAPH_BORDER_COLOR: Color = Color::LightYellow;
+const WPM_GRAPH_CURSOR_COLOR: Color = Color::Yellow;
+const WPM_GRAPH_MARKER_COLOR: Color = Color::Red;
 
 pub fn render_wpm_graph(frame: &mut Frame, area: Rect, history: &[u64], title: &str) {
+    render_graph(frame, area, history, title, GraphOverlay::default());
+}
+
+/// Draws the graph with red markers on the line at each x in `markers`,
+/// measured in samples, used to show where errors happened.
+pub fn render_wpm_graph_with_markers(
+    frame: &mut Frame,
+    area: Rect,
+    history: &[u64],
+    title: &str,
+    markers: &[f64],
+) {
+    render_graph(
+        frame,
+        area,
+        history,
+        title,
+        GraphOverlay {
+            markers,
+            ..GraphOverlay::default()
+        },
+    );
+}
+
+/// Draws the graph with a vertical cursor at `progress` (0.0 to 1.0) of the
+/// x range, used to follow a replay.
+pub fn render_wpm_graph_with_cursor(
+    frame: &mut Frame,
+    area: Rect,
+    history: &[u64],
+    title: &str,
+    progress: f64,
+) {
+    render_graph(
+        frame,
+        area,
+        history,
+        title,
+        GraphOverlay {
+            cursor_progress: Some(progress),
+            ..GraphOverlay::default()
+        },
+    );
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct GraphOverlay<'a> {
+    cursor_progress: Option<f64>,
+    markers: &'a [f64],
+}
+
+fn render_graph(
+    frame: &mut Frame,
+    area: Rect,
+    history: &[u64],
+    title: &str,
+    overlay: GraphOverlay<'_>,
+) {
     if area.height < 3 || area.width < 8 {
         return;
     }
 
+    let x_bounds = graph_x_bounds(history);
+    let y_bounds = graph_y_bounds(history);
+
     frame.render_widget(
         Canvas::default()
             .marker(Marker::Braille)
@@ -26,13 +89,55 @@ pub fn render_wpm_graph(frame: &mut Frame, area: Rect, history: &[u64], title: &
                     .border_style(Style::default().fg(WPM_GRAPH_BORDER_COLOR)),
             )
             .background_color(Color::Reset)
-            .x_bounds(graph_x_bounds(history))
-            .y_bounds(graph_y_bounds(history))
-            .paint(|ctx| paint_graph(ctx, history)),
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
+            .paint(|ctx| {
+                paint_graph(ctx, history);
+                let markers = marker_points(history, overlay.markers);
+                if !markers.is_empty() {
+                    ctx.draw(&Points::new(&markers, WPM_GRAPH_MARKER_COLOR));
+                }
+                if let Some(progress) = overlay.cursor_progress {
+                    let x = cursor_x(x_bounds, progress);
+                    ctx.draw(&CanvasLine::new(
+                        x,
+                        y_bounds[0],
+                        x,
+                        y_bounds[1],
+                        WPM_GRAPH_CURSOR_COLOR,
+                    ));
+                }
+            }),
         area,
     );
 }
 
+fn cursor_x(x_bounds: [f64; 2], progress: f64) -> f64 {
+    let [start, end] = x_bounds;
+    start + (end - start) * progress.clamp(0.0, 1.0)
+}
+
+/// Places each marker on the line, interpolating between the samples around
+/// it. Markers outside the sampled range are dropped.
+fn marker_points(history: &[u64], markers: &[f64]) -> Vec<(f64, f64)> {
+    let last = to_f64_usize(history.len().saturating_sub(1));
+    markers
+        .iter()
+        .filter(|x| !history.is_empty() && (0.0..=last).contains(*x))
+        .map(|&x| {
+            let before = (0..history.len())
+                .rev()
+                .find(|index| to_f64_usize(*index) <= x)
+                .unwrap_or(0);
+            let weight = x - to_f64_usize(before);
+            let value_at =
+                |index: usize| history.get(index).map_or(0.0, |value| to_f64_u64(*value));
+            let y = value_at(before) * (1.0 - weight) + value_at(before + 1) * weight;
+            (x, y)
+        })
+        .collect()
+}
+
 fn paint_graph(ctx: &mut ratatui::widgets::canvas::Context<'_>, history: &[u64]) {
     if history.is_empty() {
         ctx.draw(&Points::new(&[(0.0, 0.0)], Color::LightGreen));
@@ -164,7 +269,10 @@ fn to_f64_u64(value: u64) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use super::{graph_x_bounds, graph_y_bounds, point_color, should_highlight_pair, split_runs};
+    use super::{
+        cursor_x, graph_x_bounds, graph_y_bounds, marker_points, point_color,
+        should_highlight_pair, split_runs,
+    };
     use ratatui::style::Color;
 
     fn assert_bounds(actual: [f64; 2], expected: [f64; 2]) {
@@ -185,6 +293,21 @@ mod tests {
         assert_bounds(graph_y_bounds(&[0, 12]), [0.0, 12.0]);
     }
 
+    #[test]
+    fn cursor_x_scales_progress_across_bounds_and_clamps() {
+        assert!((cursor_x([0.0, 10.0], 0.25) - 2.5).abs() < f64::EPSILON);
+        assert!((cursor_x([0.0, 10.0], 3.0) - 10.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn marker_points_sit_on_the_line_and_drop_out_of_range() {
+        assert_eq!(
+            marker_points(&[10, 20, 40], &[0.0, 1.5, 2.0, 2.5, -1.0]),
+            vec![(0.0, 10.0), (1.5, 30.0), (2.0, 40.0)]
+        );
+        assert!(marker_points(&[], &[0.0]).is_empty());
+    }
+
     #[test]
     fn split_runs_keeps_zero_samples_in_the_same_run() {
         let runs = split_runs(&[12, 0, 4, 11]);
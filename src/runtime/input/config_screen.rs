@@ -1,7 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::config;
-use crate::presentation::ui::app::{App, ConfigField};
+use crate::presentation::ui::app::{App, HistoryRecovery};
 
 pub(super) fn handle_config_input(key: KeyEvent, app: &mut App) {
     match key.code {
@@ -10,13 +10,16 @@ pub(super) fn handle_config_input(key: KeyEvent, app: &mut App) {
         KeyCode::Left => app.move_config_cursor_left(),
         KeyCode::Right => app.move_config_cursor_right(),
         KeyCode::Backspace => app.pop_config_char(),
-        KeyCode::Char(' ') if app.config_field() == ConfigField::GameSoundEnabled => {
-            app.toggle_sound_enabled();
+        KeyCode::Char(' ') if !app.config_field().accepts_text() => {
+            app.cycle_config_choice();
         }
-        KeyCode::Enter => match config::save_config(app.config()) {
+        KeyCode::Enter => match config::save_config(app.profile(), app.config()) {
             Ok(()) => {
                 app.return_to_menu_with_start_selected();
                 app.set_status_message("Configuration saved");
+                if app.history_path_changed() {
+                    reload_history(app);
+                }
             }
             Err(err) => {
                 app.set_status_message(format!("Failed to save configuration: {err}"));
@@ -34,6 +37,34 @@ pub(super) fn handle_config_input(key: KeyEvent, app: &mut App) {
     }
 }
 
+/// Loads history from the newly saved `history_path`, asking how to recover
+/// first if it cannot be read in full. On failure the current history stays.
+fn reload_history(app: &mut App) {
+    let history_path = app.config().game.history_path.clone();
+    match config::load_history(app.profile(), &history_path) {
+        Ok(report) => {
+            let count = report.entries.len();
+            let backups = config::history_backups(app.profile(), &history_path).unwrap_or_default();
+            app.replace_history(history_path, report.entries);
+            let message = format!("Configuration saved; loaded {count} runs from history");
+            app.set_status_message(if report.warnings.is_empty() {
+                message
+            } else {
+                format!("{message} / History warning: {}", report.warnings.join(" / "))
+            });
+            if !report.damage.is_empty() {
+                app.open_history_recovery(HistoryRecovery {
+                    damage: report.damage,
+                    backups,
+                });
+            }
+        }
+        Err(err) => app.set_status_message(format!(
+            "Configuration saved; failed to load history, runs are still saved to the previous location: {err}"
+        )),
+    }
+}
+
 fn accepts_config_char(modifiers: KeyModifiers) -> bool {
     modifiers.is_empty() || modifiers == KeyModifiers::SHIFT
 }
@@ -13,9 +13,9 @@ pub(in crate::runtime::input) fn spawn_generation_job(
     next_request_id: &mut u64,
     active_request_id: &mut Option<u64>,
 ) {
-    let (text_scale, source, config) = app.generation_settings();
-    let provider = provider_config_for_source(source, &config);
     app.enter_loading();
+    let (target, source, config, content) = app.generation_settings();
+    let provider = provider_config_for_source(source, &config);
 
     let sender = generation_tx.clone();
     let request_id = *next_request_id;
@@ -23,7 +23,7 @@ pub(in crate::runtime::input) fn spawn_generation_job(
     *active_request_id = Some(request_id);
 
     thread::spawn(move || {
-        let result = generate_sentence::generate(text_scale, source, provider)
+        let result = generate_sentence::generate(target, source, provider, content)
             .map_err(|err| err.to_string());
         sender.send(GenerationJobResult { request_id, result }).ok();
     });
@@ -9,8 +9,19 @@ pub(in crate::runtime::input) fn handle_result_input(
     app: &mut App,
     timer: &Arc<Mutex<i32>>,
 ) {
-    if key.code == KeyCode::Enter {
-        reset_timer(timer);
-        app.return_to_menu_with_start_selected();
+    match key.code {
+        KeyCode::Enter => {
+            reset_timer(timer);
+            if app.active_lesson().is_some() {
+                app.open_lessons();
+            } else {
+                app.return_to_menu_with_start_selected();
+            }
+        }
+        KeyCode::Char('r') => {
+            app.start_result_replay();
+        }
+        KeyCode::Char('m') => app.toggle_heatmap_metric(),
+        _ => {}
     }
 }
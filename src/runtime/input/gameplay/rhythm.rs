@@ -6,7 +6,7 @@ use std::time::Duration;
 use crate::domain::rhythm::RhythmJudgement;
 use crate::presentation::ui::app::App;
 use crate::runtime::TimerCommand;
-use crate::runtime::timer::{cancel_typing_session, stop_timer};
+use crate::runtime::timer::{cancel_typing_session, persist_history, stop_timer};
 
 pub(in crate::runtime::input) fn handle_rhythm_input(
     key: KeyEvent,
@@ -30,6 +30,7 @@ pub(in crate::runtime::input) fn handle_rhythm_input(
                 audio_sink.mixer().add(source);
             }
             if app.is_rhythm_complete() {
+                persist_history(app);
                 app.finish_typing();
             }
         }
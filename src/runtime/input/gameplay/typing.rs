@@ -20,6 +20,16 @@ pub(in crate::runtime::input) fn handle_typing_input(
             stop_timer(timer_command_tx);
             app.quit();
         }
+        KeyCode::Backspace
+            if key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            app.delete_word();
+        }
+        KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.delete_word();
+        }
         KeyCode::Backspace => {
             app.pop_char();
         }
@@ -32,7 +42,7 @@ pub(in crate::runtime::input) fn handle_typing_input(
                 audio_sink.mixer().add(source);
             }
 
-            if app.is_complete() {
+            if app.is_complete() || app.is_failed() {
                 finish_typing_session(app, timer, timer_command_tx);
             }
         }
@@ -0,0 +1,91 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::{Arc, Mutex, mpsc};
+
+use crate::presentation::ui::app::App;
+use crate::runtime::TimerCommand;
+use crate::runtime::timer::reset_timer;
+
+pub(super) fn handle_ghost_select_input(
+    key: KeyEvent,
+    app: &mut App,
+    timer: &Arc<Mutex<i32>>,
+    timer_command_tx: &mpsc::Sender<TimerCommand>,
+) {
+    match key.code {
+        KeyCode::Up => app.move_ghost_selection_up(),
+        KeyCode::Down => app.move_ghost_selection_down(),
+        KeyCode::Enter if app.start_ghost_race() => {
+            reset_timer(timer);
+            app.start_typing();
+            timer_command_tx
+                .send(TimerCommand::Start(app.timeout()))
+                .ok();
+        }
+        KeyCode::Esc => app.return_to_menu(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::AppConfig;
+    use crate::domain::history::{HistoryEntry, Keystroke};
+    use crate::presentation::ui::app::AppState;
+    use crossterm::event::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::empty(),
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        }
+    }
+
+    #[test]
+    fn enter_starts_typing_against_the_selected_ghost() {
+        let mut app = App::new(AppConfig::default());
+        app.set_history_entries(vec![HistoryEntry {
+            target_text: "ab".to_string(),
+            keystrokes: vec![Keystroke {
+                elapsed_ms: 100,
+                key: Some('a'),
+                position: 1,
+            }],
+            ..HistoryEntry::default()
+        }]);
+        app.open_ghost_select();
+        let timer = Arc::new(Mutex::new(7));
+        let (timer_command_tx, timer_command_rx) = mpsc::channel();
+
+        handle_ghost_select_input(key(KeyCode::Enter), &mut app, &timer, &timer_command_tx);
+
+        assert_eq!(app.state(), AppState::Typing);
+        assert_eq!(app.target_string(), "ab");
+        assert!(app.ghost().is_some());
+        assert_eq!(
+            *timer
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+            0
+        );
+        assert!(matches!(
+            timer_command_rx.try_recv(),
+            Ok(TimerCommand::Start(_))
+        ));
+    }
+
+    #[test]
+    fn escape_returns_to_menu() {
+        let mut app = App::new(AppConfig::default());
+        app.open_ghost_select();
+        let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, _timer_command_rx) = mpsc::channel();
+
+        handle_ghost_select_input(key(KeyCode::Esc), &mut app, &timer, &timer_command_tx);
+
+        assert_eq!(app.state(), AppState::Menu);
+    }
+}
@@ -0,0 +1,25 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::presentation::ui::app::App;
+
+pub(super) fn handle_history_browser_input(key: KeyEvent, app: &mut App) {
+    match key.code {
+        KeyCode::Up => {
+            app.clear_status_message();
+            app.move_history_selection_up();
+        }
+        KeyCode::Down => {
+            app.clear_status_message();
+            app.move_history_selection_down();
+        }
+        KeyCode::Enter => {
+            app.start_history_replay();
+        }
+        KeyCode::Esc => {
+            app.clear_status_message();
+            app.open_stats();
+        }
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        _ => {}
+    }
+}
@@ -0,0 +1,61 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::presentation::ui::app::App;
+
+pub(super) fn handle_history_recovery_input(key: KeyEvent, app: &mut App) {
+    let Some(recovery) = app.history_recovery().cloned() else {
+        app.close_history_recovery();
+        return;
+    };
+
+    match key.code {
+        KeyCode::Char(digit) if digit.is_ascii_digit() => {
+            let Some(backup) = digit
+                .to_digit(10)
+                .and_then(|slot| usize::try_from(slot).ok())
+                .and_then(|slot| recovery.backups.iter().find(|backup| backup.slot == slot))
+            else {
+                return;
+            };
+            match crate::config::restore_history_backup(
+                app.profile(),
+                app.history_path(),
+                backup.slot,
+                &recovery.damage,
+            ) {
+                Ok(report) => {
+                    let count = report.entries.len();
+                    app.set_history_entries(report.entries);
+                    app.close_history_recovery();
+                    app.set_status_message(format!(
+                        "Restored {count} runs from backup {}",
+                        backup.slot
+                    ));
+                }
+                Err(err) => app.set_status_message(format!("Failed to restore history: {err}")),
+            }
+        }
+        KeyCode::Char('k') => {
+            match crate::config::keep_readable_history(
+                app.profile(),
+                app.history_path(),
+                &recovery.damage,
+            ) {
+                Ok(()) => {
+                    let count = app.history_entries().len();
+                    app.close_history_recovery();
+                    app.set_status_message(format!(
+                        "Kept {count} readable runs; damaged files were renamed with a .damaged suffix"
+                    ));
+                }
+                Err(err) => app.set_status_message(format!("Failed to rewrite history: {err}")),
+            }
+        }
+        KeyCode::Esc => {
+            app.close_history_recovery();
+            app.set_status_message("History left as is; unreadable entries are skipped");
+        }
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        _ => {}
+    }
+}
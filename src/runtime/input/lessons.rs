@@ -0,0 +1,81 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::{Arc, Mutex, mpsc};
+
+use crate::presentation::ui::app::App;
+use crate::runtime::TimerCommand;
+use crate::runtime::timer::reset_timer;
+
+pub(super) fn handle_lessons_input(
+    key: KeyEvent,
+    app: &mut App,
+    timer: &Arc<Mutex<i32>>,
+    timer_command_tx: &mpsc::Sender<TimerCommand>,
+) {
+    match key.code {
+        KeyCode::Up => app.move_lesson_selection_up(),
+        KeyCode::Down => app.move_lesson_selection_down(),
+        KeyCode::Enter => {
+            if app.start_selected_lesson() {
+                reset_timer(timer);
+                app.start_typing();
+                timer_command_tx
+                    .send(TimerCommand::Start(app.timeout()))
+                    .ok();
+            } else {
+                app.set_status_message("Pass the previous lesson to unlock this one");
+            }
+        }
+        KeyCode::Esc => app.return_to_menu(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::AppConfig;
+    use crate::presentation::ui::app::AppState;
+    use crossterm::event::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::empty(),
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        }
+    }
+
+    #[test]
+    fn enter_starts_the_selected_lesson() {
+        let mut app = App::new(AppConfig::default());
+        app.open_lessons();
+        let timer = Arc::new(Mutex::new(7));
+        let (timer_command_tx, timer_command_rx) = mpsc::channel();
+
+        handle_lessons_input(key(KeyCode::Enter), &mut app, &timer, &timer_command_tx);
+
+        assert_eq!(app.state(), AppState::Typing);
+        assert_eq!(app.active_lesson_number(), Some(1));
+        assert!(matches!(
+            timer_command_rx.try_recv(),
+            Ok(TimerCommand::Start(_))
+        ));
+    }
+
+    #[test]
+    fn enter_on_a_locked_lesson_stays_on_the_list() {
+        let mut app = App::new(AppConfig::default());
+        app.open_lessons();
+        app.move_lesson_selection_down();
+        let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, timer_command_rx) = mpsc::channel();
+
+        handle_lessons_input(key(KeyCode::Enter), &mut app, &timer, &timer_command_tx);
+
+        assert_eq!(app.state(), AppState::Lessons);
+        assert!(app.status_message().is_some());
+        assert!(timer_command_rx.try_recv().is_err());
+    }
+}
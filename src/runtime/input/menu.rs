@@ -1,18 +1,20 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::sync::{Arc, Mutex, mpsc};
 
+use crate::config;
 use crate::presentation::ui::app::{App, GameMode, MenuItem};
 use crate::presentation::ui::render;
 use crate::usecase::generate_sentence::GenerationSource;
 
 use super::gameplay::spawn_generation_job;
-use crate::runtime::GenerationJobResult;
 use crate::runtime::timer::reset_timer;
+use crate::runtime::{GenerationJobResult, TimerCommand};
 
 pub(super) fn handle_menu_input(
     key: KeyEvent,
     app: &mut App,
     timer: &Arc<Mutex<i32>>,
+    timer_command_tx: &mpsc::Sender<TimerCommand>,
     generation_tx: &mpsc::Sender<GenerationJobResult>,
     next_request_id: &mut u64,
     active_request_id: &mut Option<u64>,
@@ -82,6 +84,23 @@ pub(super) fn handle_menu_input(
                 reset_timer(timer);
                 spawn_generation_job(app, generation_tx, next_request_id, active_request_id);
             }
+            MenuItem::RaceGhost => {
+                app.open_ghost_select();
+            }
+            MenuItem::DrillWeakKeys => {
+                if app.start_drill() {
+                    reset_timer(timer);
+                    app.start_typing();
+                    timer_command_tx
+                        .send(TimerCommand::Start(app.timeout()))
+                        .ok();
+                } else {
+                    app.set_status_message("No drills are due today");
+                }
+            }
+            MenuItem::Lessons => {
+                app.open_lessons();
+            }
             MenuItem::Stats => {
                 app.open_stats();
             }
@@ -89,6 +108,10 @@ pub(super) fn handle_menu_input(
                 app.clear_status_message();
                 app.open_config();
             }
+            MenuItem::Profiles => match config::list_profiles() {
+                Ok(names) => app.open_profiles(names),
+                Err(err) => app.set_status_message(format!("Failed to list profiles: {err}")),
+            },
         },
         KeyCode::Esc => app.quit(),
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
@@ -111,8 +134,11 @@ fn should_clear_status_message(key: &KeyEvent) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::*;
     use crate::domain::config::{AppConfig, ProviderConfig};
+    use crate::domain::history::{AttemptCount, HistoryEntry};
     use crate::presentation::ui::app::AppState;
     use crossterm::event::{KeyEventKind, KeyEventState};
 
@@ -149,6 +175,7 @@ mod tests {
     fn enter_on_google_menu_sets_google_source_and_loading() {
         let mut app = test_app_with_provider_config();
         let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, _timer_command_rx) = mpsc::channel();
         let (generation_tx, _generation_rx) = mpsc::channel();
         let mut next_request_id = 1;
         let mut active_request_id = None;
@@ -160,6 +187,7 @@ mod tests {
             key(KeyCode::Enter),
             &mut app,
             &timer,
+            &timer_command_tx,
             &generation_tx,
             &mut next_request_id,
             &mut active_request_id,
@@ -174,6 +202,7 @@ mod tests {
     fn enter_on_practice_mode_sets_local_source_and_practice_mode() {
         let mut app = test_app_with_provider_config();
         let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, _timer_command_rx) = mpsc::channel();
         let (generation_tx, _generation_rx) = mpsc::channel();
         let mut next_request_id = 10;
         let mut active_request_id = None;
@@ -183,6 +212,7 @@ mod tests {
             key(KeyCode::Enter),
             &mut app,
             &timer,
+            &timer_command_tx,
             &generation_tx,
             &mut next_request_id,
             &mut active_request_id,
@@ -198,6 +228,7 @@ mod tests {
     fn enter_on_rhythm_menu_sets_local_source_and_loading() {
         let mut app = test_app();
         let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, _timer_command_rx) = mpsc::channel();
         let (generation_tx, _generation_rx) = mpsc::channel();
         let mut next_request_id = 20;
         let mut active_request_id = None;
@@ -208,6 +239,7 @@ mod tests {
             key(KeyCode::Enter),
             &mut app,
             &timer,
+            &timer_command_tx,
             &generation_tx,
             &mut next_request_id,
             &mut active_request_id,
@@ -223,6 +255,7 @@ mod tests {
     fn enter_on_groq_menu_sets_groq_source_and_loading() {
         let mut app = test_app_with_provider_config();
         let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, _timer_command_rx) = mpsc::channel();
         let (generation_tx, _generation_rx) = mpsc::channel();
         let mut next_request_id = 3;
         let mut active_request_id = None;
@@ -235,6 +268,7 @@ mod tests {
             key(KeyCode::Enter),
             &mut app,
             &timer,
+            &timer_command_tx,
             &generation_tx,
             &mut next_request_id,
             &mut active_request_id,
@@ -245,10 +279,79 @@ mod tests {
         assert_eq!(active_request_id, Some(3));
     }
 
+    #[test]
+    fn enter_on_drill_menu_starts_typing_the_due_drill() {
+        let mut app = test_app();
+        app.set_history_entries(vec![HistoryEntry {
+            char_attempts: BTreeMap::from([(
+                'q',
+                AttemptCount {
+                    attempts: 20,
+                    misses: 4,
+                },
+            )]),
+            ..HistoryEntry::default()
+        }]);
+        let timer = Arc::new(Mutex::new(3));
+        let (timer_command_tx, timer_command_rx) = mpsc::channel();
+        let (generation_tx, _generation_rx) = mpsc::channel();
+        let mut next_request_id = 7;
+        let mut active_request_id = None;
+
+        app.move_menu_down();
+        app.move_menu_down();
+        app.move_menu_down();
+        assert_eq!(app.menu_selected(), MenuItem::DrillWeakKeys);
+        handle_menu_input(
+            key(KeyCode::Enter),
+            &mut app,
+            &timer,
+            &timer_command_tx,
+            &generation_tx,
+            &mut next_request_id,
+            &mut active_request_id,
+        );
+
+        assert_eq!(app.state(), AppState::Typing);
+        assert_eq!(app.active_drill(), Some(["q".to_string()].as_slice()));
+        assert!(matches!(
+            timer_command_rx.try_recv(),
+            Ok(TimerCommand::Start(_))
+        ));
+        assert_eq!(active_request_id, None);
+    }
+
+    #[test]
+    fn enter_on_lessons_menu_opens_lessons_without_generation() {
+        let mut app = test_app();
+        let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, _timer_command_rx) = mpsc::channel();
+        let (generation_tx, _generation_rx) = mpsc::channel();
+        let mut next_request_id = 7;
+        let mut active_request_id = None;
+
+        app.move_menu_down();
+        app.move_menu_down();
+        app.move_menu_down();
+        handle_menu_input(
+            key(KeyCode::Enter),
+            &mut app,
+            &timer,
+            &timer_command_tx,
+            &generation_tx,
+            &mut next_request_id,
+            &mut active_request_id,
+        );
+
+        assert_eq!(app.state(), AppState::Lessons);
+        assert_eq!(active_request_id, None);
+    }
+
     #[test]
     fn enter_on_stats_menu_opens_stats_without_generation() {
         let mut app = test_app();
         let timer = Arc::new(Mutex::new(0));
+        let (timer_command_tx, _timer_command_rx) = mpsc::channel();
         let (generation_tx, _generation_rx) = mpsc::channel();
         let mut next_request_id = 5;
         let mut active_request_id = None;
@@ -256,10 +359,12 @@ mod tests {
         app.move_menu_down();
         app.move_menu_down();
         app.move_menu_down();
+        app.move_menu_down();
         handle_menu_input(
             key(KeyCode::Enter),
             &mut app,
             &timer,
+            &timer_command_tx,
             &generation_tx,
             &mut next_request_id,
             &mut active_request_id,
@@ -1,27 +1,54 @@
 mod config_screen;
 mod gameplay;
+mod ghost_select;
+mod history_browser;
+mod history_recovery;
+mod lessons;
 mod menu;
+mod profiles;
+mod replay;
 mod stats;
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use std::sync::mpsc;
 
 use crate::presentation::ui::app::{App, AppState};
 
 use super::{GenerationJobResult, RuntimeContext, TimerCommand};
 
+/// Releases are only recorded for hold-time analytics; screens act on presses
+/// and auto-repeats.
 pub(super) fn handle_key_event(key: KeyEvent, app: &mut App, context: &mut RuntimeContext<'_>) {
+    if key.kind == KeyEventKind::Release {
+        if let KeyCode::Char(ch) = key.code {
+            app.record_key_release(ch);
+        }
+        return;
+    }
+
     match app.state() {
         AppState::Menu => menu::handle_menu_input(
             key,
             app,
             context.timer,
+            context.timer_command_tx,
             context.generation_tx,
             context.next_request_id,
             context.active_request_id,
         ),
         AppState::Config => config_screen::handle_config_input(key, app),
         AppState::Stats => stats::handle_stats_input(key, app),
+        AppState::HistoryBrowser => history_browser::handle_history_browser_input(key, app),
+        AppState::GhostSelect => ghost_select::handle_ghost_select_input(
+            key,
+            app,
+            context.timer,
+            context.timer_command_tx,
+        ),
+        AppState::Lessons => {
+            lessons::handle_lessons_input(key, app, context.timer, context.timer_command_tx);
+        }
+        AppState::Profiles => profiles::handle_profiles_input(key, app),
         AppState::Loading => gameplay::handle_loading_input(key, app, context.active_request_id),
         AppState::Typing => gameplay::handle_typing_input(
             key,
@@ -34,6 +61,8 @@ pub(super) fn handle_key_event(key: KeyEvent, app: &mut App, context: &mut Runti
             gameplay::handle_rhythm_input(key, app, context.audio_sink, context.timer_command_tx);
         }
         AppState::Result => gameplay::handle_result_input(key, app, context.timer),
+        AppState::Replay => replay::handle_replay_input(key, app),
+        AppState::HistoryRecovery => history_recovery::handle_history_recovery_input(key, app),
     }
 }
 
@@ -0,0 +1,114 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::config;
+use crate::presentation::ui::app::{App, ProfilePrompt};
+use crate::runtime::load_profile;
+
+pub(super) fn handle_profiles_input(key: KeyEvent, app: &mut App) {
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.quit();
+        return;
+    }
+    if app.profile_prompt().is_some() {
+        handle_prompt_input(key, app);
+        return;
+    }
+
+    match key.code {
+        KeyCode::Up => app.move_profile_selection_up(),
+        KeyCode::Down => app.move_profile_selection_down(),
+        KeyCode::Enter => {
+            let selected = app.selected_profile_name().map(str::to_string);
+            switch_profile(app, selected.as_deref());
+        }
+        KeyCode::Char('n') => app.start_profile_create(),
+        KeyCode::Char('r') if !app.start_profile_rename() => {
+            app.set_status_message("The default profile cannot be renamed");
+        }
+        KeyCode::Char('d') if !app.start_profile_delete() => {
+            app.set_status_message("The default profile and the one in use cannot be deleted");
+        }
+        KeyCode::Esc => app.return_to_menu(),
+        _ => {}
+    }
+}
+
+fn handle_prompt_input(key: KeyEvent, app: &mut App) {
+    match key.code {
+        KeyCode::Enter => {
+            if let Some(prompt) = app.take_profile_prompt() {
+                apply_prompt(app, &prompt);
+            }
+        }
+        KeyCode::Esc => {
+            app.take_profile_prompt();
+        }
+        KeyCode::Backspace => app.pop_profile_prompt_char(),
+        KeyCode::Char(ch) => app.push_profile_prompt_char(ch),
+        _ => {}
+    }
+}
+
+fn apply_prompt(app: &mut App, prompt: &ProfilePrompt) {
+    let (result, selected, done) = match prompt {
+        ProfilePrompt::Create { name } => (
+            config::create_profile(name),
+            name.trim().to_string(),
+            format!("Created profile {}", name.trim()),
+        ),
+        ProfilePrompt::Rename { from, name } => (
+            config::rename_profile(from, name),
+            name.trim().to_string(),
+            format!("Renamed profile {from} to {}", name.trim()),
+        ),
+        ProfilePrompt::Delete { name } => (
+            config::delete_profile(name),
+            String::new(),
+            format!("Deleted profile {name}"),
+        ),
+    };
+    if let Err(err) = result {
+        app.set_status_message(format!("Profile not changed: {err}"));
+        return;
+    }
+
+    if let ProfilePrompt::Rename { from, .. } = prompt
+        && app.profile() == Some(from.as_str())
+    {
+        app.rename_active_profile(selected.clone());
+    }
+    reload_names(app, &selected);
+    if app.status_message().is_none() {
+        app.set_status_message(done);
+    }
+}
+
+fn reload_names(app: &mut App, selected: &str) {
+    match config::list_profiles() {
+        Ok(names) => app.set_profile_names(names, Some(selected)),
+        Err(err) => app.set_status_message(format!("Failed to list profiles: {err}")),
+    }
+}
+
+/// Loads another profile and returns to the title menu.
+fn switch_profile(app: &mut App, selected: Option<&str>) {
+    if app.profile() == selected {
+        app.return_to_menu();
+        return;
+    }
+    let selected = match config::find_profile(selected) {
+        Ok(selected) => selected,
+        Err(err) => {
+            app.set_status_message(format!("Failed to switch profile: {err}"));
+            return;
+        }
+    };
+
+    load_profile(app, selected);
+    let switched = format!("Switched to profile {}", app.profile_label());
+    let message = match app.status_message() {
+        Some(warnings) => format!("{switched} / {warnings}"),
+        None => switched,
+    };
+    app.set_status_message(message);
+}
@@ -0,0 +1,69 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::domain::replay::ReplaySpeed;
+use crate::presentation::ui::app::App;
+
+pub(super) fn handle_replay_input(key: KeyEvent, app: &mut App) {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        KeyCode::Char(' ') => app.toggle_replay_pause(),
+        KeyCode::Tab => app.cycle_replay_speed(),
+        KeyCode::Char('1') => app.set_replay_speed(ReplaySpeed::Normal),
+        KeyCode::Char('2') => app.set_replay_speed(ReplaySpeed::Double),
+        KeyCode::Char('4') => app.set_replay_speed(ReplaySpeed::Quadruple),
+        KeyCode::Char('r') => app.restart_replay(),
+        KeyCode::Esc | KeyCode::Enter => app.close_replay(),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::AppConfig;
+    use crate::domain::replay::Replay;
+    use crate::presentation::ui::app::AppState;
+    use crossterm::event::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::empty(),
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        }
+    }
+
+    fn replaying_app() -> App {
+        let mut app = App::new(AppConfig::default());
+        app.prepare_new_game("ab".to_string());
+        app.start_typing();
+        app.push_char('a');
+        app.finish_typing();
+        app.start_result_replay();
+        app
+    }
+
+    #[test]
+    fn speed_keys_change_playback_rate() {
+        let mut app = replaying_app();
+
+        handle_replay_input(key(KeyCode::Char('4')), &mut app);
+        assert_eq!(
+            app.replay().map(Replay::speed),
+            Some(ReplaySpeed::Quadruple)
+        );
+
+        handle_replay_input(key(KeyCode::Tab), &mut app);
+        assert_eq!(app.replay().map(Replay::speed), Some(ReplaySpeed::Normal));
+    }
+
+    #[test]
+    fn escape_returns_to_the_result_screen() {
+        let mut app = replaying_app();
+
+        handle_replay_input(key(KeyCode::Esc), &mut app);
+
+        assert_eq!(app.state(), AppState::Result);
+    }
+}
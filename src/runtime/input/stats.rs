@@ -1,9 +1,43 @@
 use crossterm::event::{KeyCode, KeyEvent};
 
+use crate::config;
+use crate::domain::history::HistoryFormat;
 use crate::presentation::ui::app::App;
+use crate::usecase::history_record;
 
 pub(super) fn handle_stats_input(key: KeyEvent, app: &mut App) {
-    if matches!(key.code, KeyCode::Enter | KeyCode::Esc) {
-        app.return_to_menu();
+    app.clear_status_message();
+    match key.code {
+        KeyCode::Enter | KeyCode::Esc => app.return_to_menu(),
+        KeyCode::Char('b') => app.open_history_browser(),
+        KeyCode::Tab => app.cycle_stats_page(),
+        KeyCode::Char('m') => app.toggle_heatmap_metric(),
+        KeyCode::Char('c') => app.cycle_progress_chart(),
+        KeyCode::Left => app.scroll_progress_back(),
+        KeyCode::Right => app.scroll_progress_forward(),
+        KeyCode::Up => app.zoom_progress_in(),
+        KeyCode::Down => app.zoom_progress_out(),
+        KeyCode::Char('s') => app.cycle_stats_source(),
+        KeyCode::Char('o') => app.cycle_stats_mode(),
+        KeyCode::Char('l') => app.cycle_stats_duration(),
+        KeyCode::Char('d') => app.cycle_stats_range(),
+        KeyCode::Char('e') => export_stats_history(app, HistoryFormat::Csv),
+        KeyCode::Char('j') => export_stats_history(app, HistoryFormat::Json),
+        _ => {}
     }
 }
+
+/// Exports the runs that match the current filter.
+fn export_stats_history(app: &mut App, format: HistoryFormat) {
+    let runs = app.stats_history();
+    let message = match config::export_history(app.profile(), runs, format, history_record::now()) {
+        Ok(path) => format!(
+            "Exported {} runs as {} to {}",
+            runs.len(),
+            format.label(),
+            path.display()
+        ),
+        Err(err) => format!("Failed to export history: {err}"),
+    };
+    app.set_status_message(message);
+}
@@ -1,4 +1,5 @@
 mod input;
+mod profile;
 mod session;
 mod timer;
 
@@ -25,5 +26,6 @@ struct RuntimeContext<'a> {
     timer_command_tx: &'a mpsc::Sender<TimerCommand>,
 }
 
+pub use profile::load_profile;
 pub use session::run_app;
 pub use timer::spawn_timer_thread;
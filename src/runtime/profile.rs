@@ -0,0 +1,113 @@
+use crate::config;
+use crate::domain::config::AppConfig;
+use crate::domain::drill::DrillDeck;
+use crate::domain::history::{HistoryDamage, HistoryEntry};
+use crate::domain::lesson::LessonProgress;
+use crate::presentation::ui::app::{App, HistoryRecovery};
+
+/// Loads the selected profile's config, history, lessons and drills into
+/// `app`, replacing whatever it held. Problems reading them become the
+/// status message, and damaged history opens the recovery screen.
+pub fn load_profile(app: &mut App, profile: Option<String>) {
+    let name = profile.as_deref();
+    let (loaded_config, config_message) = load_config(name);
+    let (history_entries, history_message, history_damage) =
+        load_history(name, &loaded_config.game.history_path);
+    let (lesson_progress, lesson_message) = load_lessons(name);
+    let (drill_deck, drill_message) = load_drills(name);
+
+    app.switch_profile(profile, loaded_config);
+    app.set_history_entries(history_entries);
+    app.set_lesson_progress(lesson_progress);
+    app.set_drill_deck(drill_deck);
+    let messages = [
+        config_message,
+        history_message,
+        lesson_message,
+        drill_message,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    if !messages.is_empty() {
+        app.set_status_message(messages.join(" / "));
+    }
+    if !history_damage.is_empty() {
+        app.open_history_recovery(HistoryRecovery {
+            damage: history_damage,
+            backups: config::history_backups(app.profile(), app.history_path()).unwrap_or_default(),
+        });
+    }
+}
+
+fn load_config(profile: Option<&str>) -> (AppConfig, Option<String>) {
+    match config::load_config(profile) {
+        Ok(report) => {
+            let message = if report.warnings.is_empty() {
+                None
+            } else {
+                Some(format!("Config warning: {}", report.warnings.join(" / ")))
+            };
+            (report.config, message)
+        }
+        Err(err) => (
+            AppConfig::default(),
+            Some(format!("Failed to load config: {err}")),
+        ),
+    }
+}
+
+fn load_history(
+    profile: Option<&str>,
+    history_path: &str,
+) -> (Vec<HistoryEntry>, Option<String>, Vec<HistoryDamage>) {
+    match config::load_history(profile, history_path) {
+        Ok(report) => {
+            let message = if report.warnings.is_empty() {
+                None
+            } else {
+                Some(format!("History warning: {}", report.warnings.join(" / ")))
+            };
+            (report.entries, message, report.damage)
+        }
+        Err(err) => (
+            Vec::new(),
+            Some(format!("Failed to load history: {err}")),
+            Vec::new(),
+        ),
+    }
+}
+
+fn load_lessons(profile: Option<&str>) -> (LessonProgress, Option<String>) {
+    match config::load_lessons(profile) {
+        Ok(report) => {
+            let message = if report.warnings.is_empty() {
+                None
+            } else {
+                Some(format!("Lesson warning: {}", report.warnings.join(" / ")))
+            };
+            (report.progress, message)
+        }
+        Err(err) => (
+            LessonProgress::default(),
+            Some(format!("Failed to load lessons: {err}")),
+        ),
+    }
+}
+
+fn load_drills(profile: Option<&str>) -> (DrillDeck, Option<String>) {
+    match config::load_drills(profile) {
+        Ok(report) => {
+            let message = if report.warnings.is_empty() {
+                None
+            } else {
+                Some(format!("Drill warning: {}", report.warnings.join(" / ")))
+            };
+            (report.deck, message)
+        }
+        Err(err) => (
+            DrillDeck::default(),
+            Some(format!("Failed to load drills: {err}")),
+        ),
+    }
+}
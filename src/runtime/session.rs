@@ -13,7 +13,9 @@ use crate::presentation::ui::app::{App, AppState};
 use crate::presentation::ui::render;
 
 use super::input::{drain_generation_results, handle_key_event};
-use super::timer::{current_timer, persist_timed_history};
+use super::timer::{
+    current_timer, persist_drill_schedule, persist_history, persist_lesson_progress,
+};
 use super::{GenerationJobResult, RuntimeContext, TimerCommand};
 
 pub fn run_app(
@@ -29,9 +31,11 @@ pub fn run_app(
     let mut active_request_id: Option<u64> = None;
     let mut using_typing_cursor_style = false;
     let mut rhythm_started_at: Option<Instant> = None;
+    let mut replay_ticked_at: Option<Instant> = None;
 
     loop {
         update_rhythm_session(app, &mut rhythm_started_at);
+        update_replay(app, &mut replay_ticked_at);
 
         let is_typing = app.state() == AppState::Typing;
         if is_typing && !using_typing_cursor_style {
@@ -57,11 +61,13 @@ pub fn run_app(
 
         if app.state() == AppState::Typing && app.timeout() > 0 && timeout_rx.try_recv().is_ok() {
             app.update_timer(current_timer(timer));
-            persist_timed_history(app);
+            persist_history(app);
+            persist_lesson_progress(app);
+            persist_drill_schedule(app);
             app.finish_typing();
         }
 
-        if event::poll(Duration::from_millis(100))?
+        if event::poll(poll_interval(app))?
             && let Event::Key(key) = event::read()?
         {
             let mut context = RuntimeContext {
@@ -83,6 +89,29 @@ pub fn run_app(
     Ok(())
 }
 
+/// Replays redraw more often than other screens so the caret moves smoothly
+/// at 4× speed.
+fn poll_interval(app: &App) -> Duration {
+    if app.state() == AppState::Replay {
+        Duration::from_millis(30)
+    } else {
+        Duration::from_millis(100)
+    }
+}
+
+fn update_replay(app: &mut App, replay_ticked_at: &mut Option<Instant>) {
+    if app.state() != AppState::Replay {
+        *replay_ticked_at = None;
+        return;
+    }
+
+    let now = Instant::now();
+    if let Some(ticked_at) = replay_ticked_at.replace(now) {
+        let elapsed_ms = now.duration_since(ticked_at).as_millis();
+        app.advance_replay(u64::try_from(elapsed_ms).unwrap_or(u64::MAX));
+    }
+}
+
 fn update_rhythm_session(app: &mut App, rhythm_started_at: &mut Option<Instant>) {
     if app.state() != AppState::RhythmTyping {
         *rhythm_started_at = None;
@@ -92,6 +121,7 @@ fn update_rhythm_session(app: &mut App, rhythm_started_at: &mut Option<Instant>)
     let started_at = rhythm_started_at.get_or_insert_with(Instant::now);
     app.update_rhythm_elapsed_seconds(started_at.elapsed().as_secs_f64());
     if app.is_rhythm_complete() {
+        persist_history(app);
         app.finish_typing();
         *rhythm_started_at = None;
     }
@@ -79,20 +79,41 @@ pub(super) fn finish_typing_session(
 ) {
     stop_timer(timer_command_tx);
     app.update_timer(current_timer(timer));
-    persist_timed_history(app);
+    persist_history(app);
+    persist_lesson_progress(app);
+    persist_drill_schedule(app);
     app.finish_typing();
 }
 
-pub(super) fn persist_timed_history(app: &mut crate::presentation::ui::app::App) {
+pub(super) fn persist_history(app: &mut crate::presentation::ui::app::App) {
     let Some(entry) = app.build_history_entry() else {
         return;
     };
 
-    let mut entries = app.history_entries().to_vec();
-    entries.push(entry);
-    match crate::config::save_history(&entries) {
-        Ok(()) => app.set_history_entries(entries),
-        Err(err) => app.set_status_message(format!("Failed to save history: {err}")),
+    if let Err(err) = crate::config::append_history(app.profile(), app.history_path(), &entry) {
+        app.set_status_message(format!("Failed to save history: {err}"));
+        return;
+    }
+    app.record_history_entry(entry);
+}
+
+pub(super) fn persist_lesson_progress(app: &mut crate::presentation::ui::app::App) {
+    if !app.record_lesson_result() {
+        return;
+    }
+
+    if let Err(err) = crate::config::save_lessons(app.profile(), app.lesson_progress()) {
+        app.set_status_message(format!("Failed to save lesson progress: {err}"));
+    }
+}
+
+pub(super) fn persist_drill_schedule(app: &mut crate::presentation::ui::app::App) {
+    if !app.record_drill_result() {
+        return;
+    }
+
+    if let Err(err) = crate::config::save_drills(app.profile(), app.drill_deck()) {
+        app.set_status_message(format!("Failed to save drill schedule: {err}"));
     }
 }
 
@@ -0,0 +1,177 @@
+use crate::domain::adaptive::{
+    ADAPTIVE_DEFAULT_STEP, ADAPTIVE_MAX_STEP, AdaptivePlan, TARGET_ACCURACY_HIGH,
+    TARGET_ACCURACY_LOW,
+};
+use crate::domain::history::HistoryEntry;
+
+/// Runs at a level needed before the level changes again, so one run never
+/// moves it twice.
+const MIN_RUNS_PER_STEP: usize = 2;
+const RECENT_RUNS: usize = 5;
+/// Runs steadier than this may move up a level.
+const MIN_CONSISTENCY: f64 = 60.0;
+/// Moving up also needs the latest run to keep this share of the recent speed.
+const MIN_SPEED_RATIO: f64 = 0.9;
+
+/// Picks the level for the next run from the latest adaptive runs.
+///
+/// Only the most recent runs at the current level count. The level drops when
+/// their average accuracy is below the target band and rises when it is above
+/// the band with a steady pace and no drop in speed.
+pub fn plan(entries: &[HistoryEntry]) -> AdaptivePlan {
+    let adaptive = entries
+        .iter()
+        .rev()
+        .filter(|entry| entry.adaptive_step.is_some());
+    let Some(step) = adaptive.clone().find_map(|entry| entry.adaptive_step) else {
+        return hold(
+            ADAPTIVE_DEFAULT_STEP,
+            "No adaptive runs yet: starting at the middle level".to_string(),
+        );
+    };
+    let step = step.min(ADAPTIVE_MAX_STEP);
+    let recent = adaptive
+        .take_while(|entry| entry.adaptive_step == Some(step))
+        .take(RECENT_RUNS)
+        .collect::<Vec<_>>();
+    if recent.len() < MIN_RUNS_PER_STEP {
+        return hold(
+            step,
+            format!(
+                "Collecting runs at this level ({} of {MIN_RUNS_PER_STEP})",
+                recent.len()
+            ),
+        );
+    }
+
+    let accuracy = average(recent.iter().map(|entry| entry.accuracy));
+    let wpm = average(recent.iter().map(|entry| entry.wpm));
+    if accuracy < TARGET_ACCURACY_LOW {
+        let reason =
+            format!("Accuracy {accuracy:.1}% is below the {TARGET_ACCURACY_LOW:.0}% target");
+        return if step == 0 {
+            hold(step, format!("{reason}, already at the easiest level"))
+        } else {
+            hold(step - 1, format!("{reason}: easier"))
+        };
+    }
+    if accuracy <= TARGET_ACCURACY_HIGH {
+        return hold(
+            step,
+            format!(
+                "Accuracy {accuracy:.1}% is within the {TARGET_ACCURACY_LOW:.0}-{TARGET_ACCURACY_HIGH:.0}% target: holding"
+            ),
+        );
+    }
+
+    let reason = format!("Accuracy {accuracy:.1}% is above the {TARGET_ACCURACY_HIGH:.0}% target");
+    let consistency = average(
+        recent
+            .iter()
+            .filter_map(|entry| entry.analytics.as_ref()?.consistency()),
+    );
+    let latest_wpm = recent.first().map_or(wpm, |entry| entry.wpm);
+    if consistency < MIN_CONSISTENCY {
+        hold(
+            step,
+            format!("{reason}, but consistency {consistency:.0}% is uneven: holding"),
+        )
+    } else if latest_wpm < wpm * MIN_SPEED_RATIO {
+        hold(
+            step,
+            format!("{reason}, but the latest run slowed to {latest_wpm:.1} WPM: holding"),
+        )
+    } else if step == ADAPTIVE_MAX_STEP {
+        hold(step, format!("{reason}, already at the hardest level"))
+    } else {
+        hold(step + 1, format!("{reason} at {wpm:.1} WPM: harder"))
+    }
+}
+
+fn hold(step: u8, reason: String) -> AdaptivePlan {
+    AdaptivePlan { step, reason }
+}
+
+/// Average of `values`, or 0 when there are none. Consistency is missing for
+/// runs without a timeline, which then never move up on pace alone.
+fn average(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0u32), |(sum, count), value| {
+        (sum + value, count.saturating_add(1))
+    });
+    if count == 0 {
+        return 0.0;
+    }
+    sum / f64::from(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::history::SpeedAnalytics;
+
+    fn run(step: u8, wpm: f64, accuracy: f64) -> HistoryEntry {
+        HistoryEntry {
+            wpm,
+            accuracy,
+            adaptive_step: Some(step),
+            analytics: Some(SpeedAnalytics {
+                speed_cv: Some(20.0),
+                ..SpeedAnalytics::default()
+            }),
+            ..HistoryEntry::default()
+        }
+    }
+
+    #[test]
+    fn plan_starts_in_the_middle_and_waits_for_enough_runs() {
+        assert_eq!(plan(&[HistoryEntry::default()]).step, ADAPTIVE_DEFAULT_STEP);
+
+        let waiting = plan(&[run(1, 40.0, 80.0), run(3, 40.0, 80.0)]);
+        assert_eq!(waiting.step, 3);
+        assert_eq!(waiting.reason, "Collecting runs at this level (1 of 2)");
+    }
+
+    #[test]
+    fn plan_moves_down_below_and_up_above_the_target_band() {
+        let easier = plan(&[run(2, 40.0, 90.0), run(2, 40.0, 92.0)]);
+        assert_eq!(easier.step, 1);
+        assert_eq!(
+            easier.reason,
+            "Accuracy 91.0% is below the 94% target: easier"
+        );
+
+        let holding = plan(&[run(2, 40.0, 96.0), run(2, 40.0, 97.0)]);
+        assert_eq!(holding.step, 2);
+
+        let harder = plan(&[run(2, 40.0, 99.0), run(2, 42.0, 99.5)]);
+        assert_eq!(harder.step, 3);
+        assert_eq!(
+            harder.reason,
+            "Accuracy 99.2% is above the 98% target at 41.0 WPM: harder"
+        );
+    }
+
+    #[test]
+    fn plan_holds_when_pace_is_uneven_or_speed_drops() {
+        let mut uneven = run(2, 40.0, 100.0);
+        uneven.analytics = None;
+        assert_eq!(plan(&[uneven.clone(), uneven]).step, 2);
+
+        let slowed = plan(&[run(2, 50.0, 99.0), run(2, 30.0, 99.0)]);
+        assert_eq!(slowed.step, 2);
+        assert!(slowed.reason.contains("slowed to 30.0 WPM"));
+    }
+
+    #[test]
+    fn only_runs_at_the_current_level_count() {
+        let entries = [
+            run(2, 40.0, 50.0),
+            run(2, 40.0, 50.0),
+            HistoryEntry::default(),
+            run(1, 40.0, 96.0),
+            run(1, 40.0, 96.0),
+        ];
+
+        assert_eq!(plan(&entries).step, 1);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::domain::drill::review_quality;
+use crate::domain::history::{HistoryEntry, Keystroke};
+use crate::domain::timestamp::day_number;
+
+use super::{history_record, history_stats, key_stats, ngram_stats};
+
+const WEAK_CHAR_LIMIT: usize = 3;
+const WEAK_BIGRAM_LIMIT: usize = 2;
+
+/// Days since the Unix epoch in UTC, the unit drill due dates use.
+pub fn today() -> u64 {
+    day_number(history_record::now())
+}
+
+/// Characters and bigrams worth drilling: the characters and bigrams with
+/// the highest error rate in history. Items containing whitespace are left
+/// out.
+pub fn weak_items(entries: &[HistoryEntry]) -> Vec<String> {
+    let characters = history_stats::summarize(entries)
+        .weak_chars
+        .into_iter()
+        .filter(|spot| !spot.item.is_whitespace())
+        .take(WEAK_CHAR_LIMIT)
+        .map(|spot| spot.item.to_string());
+    let report = ngram_stats::aggregate(entries);
+    let bigrams = report
+        .most_error_prone(2, usize::MAX)
+        .into_iter()
+        .filter(|stat| !stat.ngram.contains(char::is_whitespace))
+        .take(WEAK_BIGRAM_LIMIT)
+        .map(|stat| stat.ngram.clone());
+
+    characters.chain(bigrams).collect()
+}
+
+/// Grades each drilled item from its error rate in the run. Items that never
+/// came up are not graded.
+pub fn grade(target: &str, keystrokes: &[Keystroke], items: &[String]) -> Vec<(String, u8)> {
+    let keys = key_stats::collect(target, keystrokes);
+    let ngrams = ngram_stats::collect(target, keystrokes);
+
+    items
+        .iter()
+        .filter_map(|item| {
+            let mut chars = item.chars();
+            let (attempts, errors) = match (chars.next(), chars.next()) {
+                (Some(ch), None) => keys.get(ch).map(|stat| (stat.attempts, stat.errors))?,
+                _ => ngrams.get(item).map(|stat| (stat.attempts, stat.errors))?,
+            };
+            review_quality(attempts, errors).map(|quality| (item.clone(), quality))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::domain::history::AttemptCount;
+
+    fn typed(target: &str, typed: &str) -> Vec<Keystroke> {
+        typed
+            .chars()
+            .enumerate()
+            .map(|(index, key)| Keystroke {
+                elapsed_ms: 100 * (index as u64 + 1),
+                key: Some(key),
+                position: index + 1,
+            })
+            .take(target.chars().count())
+            .collect()
+    }
+
+    #[test]
+    fn weak_items_take_error_prone_characters_and_bigrams() {
+        let count = |attempts, misses| AttemptCount { attempts, misses };
+        let entry = HistoryEntry {
+            char_attempts: BTreeMap::from([
+                ('e', count(100, 2)),
+                ('q', count(20, 5)),
+                (' ', count(50, 25)),
+                ('z', count(20, 2)),
+            ]),
+            target_text: "thththth".to_string(),
+            keystrokes: typed("thththth", "txtxtxtx"),
+            ..HistoryEntry::default()
+        };
+
+        assert_eq!(weak_items(&[entry]), ["q", "z", "e", "th"]);
+    }
+
+    #[test]
+    fn grade_rates_characters_and_bigrams_in_the_run() {
+        let target = "abab";
+        let items = ["a".to_string(), "ab".to_string(), "q".to_string()];
+
+        let grades = grade(target, &typed(target, "axab"), &items);
+
+        assert_eq!(grades, [("a".to_string(), 5), ("ab".to_string(), 1)]);
+    }
+}
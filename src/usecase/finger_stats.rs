@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use crate::domain::history::HistoryEntry;
+use crate::domain::layout::{Finger, Hand, KeyPosition, KeyboardLayout};
+
+use super::key_stats::{KeyReport, KeyStat};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FingerReport {
+    pub fingers: BTreeMap<Finger, KeyStat>,
+    pub hands: BTreeMap<Hand, KeyStat>,
+    /// Consecutive characters typed with both hands, excluding the space bar.
+    pub hand_pairs: usize,
+    pub alternating_pairs: usize,
+    /// Consecutive characters on different keys pressed by the same finger.
+    pub same_finger_bigrams: usize,
+}
+
+impl FingerReport {
+    /// Share of two-key sequences that switch hands, in percent.
+    pub fn alternation_ratio(&self) -> Option<f64> {
+        if self.hand_pairs == 0 {
+            return None;
+        }
+
+        Some(to_f64(self.alternating_pairs) * 100.0 / to_f64(self.hand_pairs))
+    }
+
+    /// Same-finger bigrams as a share of all two-key sequences, in percent.
+    pub fn same_finger_ratio(&self) -> Option<f64> {
+        if self.hand_pairs == 0 {
+            return None;
+        }
+
+        Some(to_f64(self.same_finger_bigrams) * 100.0 / to_f64(self.hand_pairs))
+    }
+}
+
+/// Groups per-key results by finger and hand, and measures finger movement
+/// over the part of each successful run's text that was reached.
+pub fn aggregate(
+    entries: &[HistoryEntry],
+    keys: &KeyReport,
+    layout: &KeyboardLayout,
+) -> FingerReport {
+    let mut report = FingerReport::default();
+
+    for (position, stat) in keys.by_key(layout) {
+        let finger = layout.finger(position);
+        report.fingers.entry(finger).or_default().merge(&stat);
+        if let Some(hand) = finger.hand() {
+            report.hands.entry(hand).or_default().merge(&stat);
+        }
+    }
+
+    for entry in entries.iter().filter(|entry| !entry.failed) {
+        let reached = entry
+            .keystrokes
+            .iter()
+            .map(|keystroke| keystroke.position)
+            .max()
+            .unwrap_or(0);
+        count_transitions(&mut report, entry.target_text.chars().take(reached), layout);
+    }
+
+    report
+}
+
+fn count_transitions(
+    report: &mut FingerReport,
+    text: impl Iterator<Item = char>,
+    layout: &KeyboardLayout,
+) {
+    let mut previous: Option<(KeyPosition, Finger)> = None;
+    for ch in text {
+        let current = layout
+            .key_position(ch)
+            .map(|position| (position, layout.finger(position)))
+            .filter(|(_, finger)| finger.hand().is_some());
+        if let (Some((previous_key, previous_finger)), Some((key, finger))) = (previous, current) {
+            report.hand_pairs += 1;
+            if previous_finger.hand() != finger.hand() {
+                report.alternating_pairs += 1;
+            } else if previous_finger == finger && previous_key != key {
+                report.same_finger_bigrams += 1;
+            }
+        }
+        previous = current;
+    }
+}
+
+fn to_f64(value: usize) -> f64 {
+    f64::from(u32::try_from(value).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+    use crate::domain::history::Keystroke;
+    use crate::domain::layout::{DVORAK, QWERTY};
+    use crate::usecase::key_stats;
+
+    fn typed(text: &str) -> HistoryEntry {
+        HistoryEntry {
+            target_text: text.to_string(),
+            keystrokes: text
+                .chars()
+                .enumerate()
+                .map(|(index, key)| Keystroke {
+                    elapsed_ms: 100 * (index as u64 + 1),
+                    key: Some(key),
+                    position: index + 1,
+                })
+                .collect(),
+            ..HistoryEntry::default()
+        }
+    }
+
+    #[test]
+    fn aggregate_groups_keys_by_finger_and_hand() {
+        let entries = [typed("fr ju")];
+        let keys = key_stats::aggregate(&entries);
+
+        let report = aggregate(&entries, &keys, &QWERTY);
+
+        let left_index = report
+            .fingers
+            .get(&Finger::LeftIndex)
+            .expect("f and r use the left index");
+        assert_eq!(left_index.attempts, 2);
+        let thumbs = report.fingers.get(&Finger::Thumb).expect("space was typed");
+        assert_eq!(thumbs.attempts, 1);
+        let right = report.hands.get(&Hand::Right).expect("j and u were typed");
+        assert_eq!(right.attempts, 2);
+    }
+
+    #[test]
+    fn transitions_count_alternation_and_same_finger_bigrams() {
+        // "fr" is a same-finger bigram, "rj" and "jf" alternate, "ff" repeats
+        // a key, and pairs across the space bar are not counted.
+        let entries = [typed("frjff k")];
+        let report = aggregate(&entries, &KeyReport::default(), &QWERTY);
+
+        assert_eq!(report.hand_pairs, 4);
+        assert_eq!(report.alternating_pairs, 2);
+        assert_eq!(report.same_finger_bigrams, 1);
+        assert_eq!(report.alternation_ratio(), Some(50.0));
+        assert_eq!(report.same_finger_ratio(), Some(25.0));
+    }
+
+    #[test]
+    fn transitions_depend_on_the_layout_and_stop_where_typing_stopped() {
+        let mut entry = typed("ed");
+        assert_eq!(
+            aggregate(&[entry.clone()], &KeyReport::default(), &QWERTY).same_finger_bigrams,
+            1
+        );
+        assert_eq!(
+            aggregate(&[entry.clone()], &KeyReport::default(), &DVORAK).same_finger_bigrams,
+            0
+        );
+
+        entry.keystrokes.truncate(1);
+        let report = aggregate(&[entry], &KeyReport::default(), &QWERTY);
+        assert_eq!(report.hand_pairs, 0);
+        assert_eq!(report.alternation_ratio(), None);
+    }
+}
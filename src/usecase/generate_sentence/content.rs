@@ -0,0 +1,206 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rand::RngExt;
+use rand::prelude::IndexedRandom;
+use rand::rng;
+
+use crate::domain::entity;
+use crate::domain::text_content::TextContent;
+
+/// Words appearing at least this often in the sample text count as common.
+const COMMON_WORD_MIN_COUNT: usize = 3;
+/// A number follows every this many words.
+const NUMBER_INTERVAL: usize = 6;
+/// Every this many words is wrapped in or marked with a symbol.
+const SYMBOL_INTERVAL: usize = 4;
+const LARGEST_NUMBER: u32 = 9999;
+/// Prefix and suffix pairs added to words.
+const SYMBOL_AFFIXES: [(&str, &str); 12] = [
+    ("(", ")"),
+    ("[", "]"),
+    ("{", "}"),
+    ("<", ">"),
+    ("#", ""),
+    ("$", ""),
+    ("@", ""),
+    ("~", ""),
+    ("", "%"),
+    ("", "*"),
+    ("", "+"),
+    ("", "="),
+];
+
+/// Reduces normalized text to `content`: drops punctuation, lowercases, and
+/// leaves out rare words. Digits are dropped unless numbers are allowed, and
+/// words without letters are never considered rare. Falls back to keeping
+/// rare words when none are common.
+pub(super) fn restrict_content(text: &str, content: TextContent) -> String {
+    if content.is_full() {
+        return text.to_string();
+    }
+
+    let common = (!content.rare_words).then(common_words);
+    let restricted = restrict_words(text, content, common.as_ref());
+    if restricted.is_empty() && common.is_some() {
+        return restrict_words(text, content, None);
+    }
+    restricted
+}
+
+/// Adds the numbers and symbols requested by `content` to normalized text.
+/// Numbers are inserted as words of their own and symbols are attached to
+/// the start or end of a word, so existing words are never split.
+pub(super) fn add_content(text: &str, content: TextContent) -> String {
+    if !content.numbers && !content.symbols {
+        return text.to_string();
+    }
+
+    let mut rng = rng();
+    let mut words = Vec::new();
+    for (index, word) in text.split_whitespace().enumerate() {
+        let position = index + 1;
+        let affix = (content.symbols && position % SYMBOL_INTERVAL == 0)
+            .then(|| SYMBOL_AFFIXES.choose(&mut rng))
+            .flatten();
+        words.push(match affix {
+            Some((prefix, suffix)) => format!("{prefix}{word}{suffix}"),
+            None => word.to_string(),
+        });
+        if content.numbers && position % NUMBER_INTERVAL == 0 {
+            words.push(rng.random_range(0..=LARGEST_NUMBER).to_string());
+        }
+    }
+    words.join(" ")
+}
+
+fn restrict_words(text: &str, content: TextContent, common: Option<&BTreeSet<String>>) -> String {
+    text.split_whitespace()
+        .filter_map(|word| {
+            let word = if content.numbers {
+                word.to_string()
+            } else if word.chars().any(char::is_alphabetic) {
+                word.chars().filter(|ch| !ch.is_numeric()).collect()
+            } else if word.chars().any(char::is_numeric) {
+                return None;
+            } else {
+                word.to_string()
+            };
+            let word = if content.punctuation {
+                word
+            } else {
+                word.chars().filter(|ch| ch.is_alphanumeric()).collect()
+            };
+            let word = if content.capitals {
+                word
+            } else {
+                word.to_lowercase()
+            };
+            let key = word_key(&word);
+            let rare = common.is_some_and(|common| !key.is_empty() && !common.contains(&key));
+            (!word.is_empty() && !rare).then_some(word)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn common_words() -> BTreeSet<String> {
+    let mut counts = BTreeMap::<String, usize>::new();
+    for word in entity::get_sample().split_whitespace() {
+        *counts.entry(word_key(word)).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .filter(|(word, count)| !word.is_empty() && *count >= COMMON_WORD_MIN_COUNT)
+        .map(|(word, _)| word)
+        .collect()
+}
+
+fn word_key(word: &str) -> String {
+    word.chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restrict_content_strips_punctuation_and_capitals() {
+        let content = TextContent {
+            capitals: false,
+            punctuation: false,
+            ..TextContent::FULL
+        };
+
+        assert_eq!(
+            restrict_content("The cat's 2 hats, - really!", content),
+            "the cats hats really"
+        );
+    }
+
+    #[test]
+    fn restrict_content_drops_digits_unless_numbers_are_allowed() {
+        let content = TextContent {
+            capitals: false,
+            ..TextContent::FULL
+        };
+        let text = "Room 101, floor 3rd - 42.";
+
+        assert_eq!(restrict_content(text, content), "room floor rd -");
+        assert_eq!(
+            restrict_content(
+                text,
+                TextContent {
+                    numbers: true,
+                    ..content
+                }
+            ),
+            "room 101, floor 3rd - 42."
+        );
+    }
+
+    #[test]
+    fn restrict_content_keeps_only_common_words() {
+        let content = TextContent {
+            rare_words: false,
+            numbers: true,
+            ..TextContent::FULL
+        };
+
+        assert_eq!(
+            restrict_content("The world zyzzyva, and 42.", content),
+            "The world and 42."
+        );
+        assert_eq!(restrict_content("zyzzyva", content), "zyzzyva");
+    }
+
+    #[test]
+    fn add_content_inserts_numbers_and_marks_words_with_symbols() {
+        let content = TextContent {
+            numbers: true,
+            symbols: true,
+            ..TextContent::FULL
+        };
+        let text = "one two three four five six seven eight";
+
+        let added = add_content(text, content);
+        let words = added.split_whitespace().collect::<Vec<_>>();
+
+        assert_eq!(words.len(), 9);
+        assert!(
+            words
+                .get(6)
+                .is_some_and(|word| word.chars().all(|ch| ch.is_ascii_digit()))
+        );
+        for (index, original) in [(3, "four"), (8, "eight")] {
+            assert!(
+                words
+                    .get(index)
+                    .is_some_and(|word| word.contains(original) && *word != original)
+            );
+        }
+        assert_eq!(add_content(text, TextContent::FULL), text);
+    }
+}
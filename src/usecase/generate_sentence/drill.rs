@@ -0,0 +1,62 @@
+use rand::prelude::IndexedRandom;
+use rand::rng;
+use std::collections::BTreeSet;
+
+use crate::domain::entity;
+
+/// Every this many words, the bare item is repeated instead of a word.
+const NGRAM_DRILL_EVERY: usize = 4;
+const NGRAM_DRILL_MIN_CHARS: usize = 4;
+
+/// Builds drill text that cycles through `items`. Most words are corpus words
+/// containing the item, keeping their case and punctuation; every fourth is
+/// the item repeated, which also stands in when no corpus word contains it.
+pub(super) fn generate_drill_words(items: &[String], word_count: usize) -> String {
+    let words = entity::get_sample()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect::<BTreeSet<_>>();
+    let mut rng = rng();
+
+    items
+        .iter()
+        .cycle()
+        .take(word_count)
+        .enumerate()
+        .map(|(index, item)| {
+            let candidates = words
+                .iter()
+                .filter(|word| word.contains(item.as_str()))
+                .collect::<Vec<_>>();
+            match candidates.choose(&mut rng) {
+                Some(word) if (index + 1) % NGRAM_DRILL_EVERY != 0 => (*word).clone(),
+                _ => ngram_drill(item),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn ngram_drill(item: &str) -> String {
+    let length = item.chars().count().max(1);
+    item.repeat(NGRAM_DRILL_MIN_CHARS.div_ceil(length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_drill_word_contains_its_item() {
+        let items = ["th".to_string(), "q".to_string(), "~".to_string()];
+        let text = generate_drill_words(&items, 12);
+        let words = text.split_whitespace().collect::<Vec<_>>();
+
+        assert_eq!(words.len(), 12);
+        for (word, item) in words.iter().zip(items.iter().cycle()) {
+            assert!(word.contains(item.as_str()), "{word} lacks {item}");
+        }
+        assert_eq!(words.get(2), Some(&"~~~~"));
+        assert_eq!(words.get(3), Some(&"thth"));
+    }
+}
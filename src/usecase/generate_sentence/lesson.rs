@@ -0,0 +1,160 @@
+use rand::RngExt;
+use rand::prelude::IndexedRandom;
+use rand::rng;
+use std::collections::BTreeSet;
+
+use crate::domain::entity;
+
+const DRILL_WORD_MIN_CHARS: usize = 2;
+const DRILL_WORD_MAX_CHARS: usize = 5;
+/// Corpus words are only used when enough of them fit the unlocked keys;
+/// otherwise drill words built from the keys fill in.
+const MIN_CORPUS_WORDS: usize = 8;
+
+/// Builds lesson text from the unlocked keys only. Every other word practises
+/// a new key: a corpus word containing it, a drill word, a number, or a word
+/// followed by a new symbol.
+pub(super) fn generate_lesson_words(
+    unlocked: &[char],
+    new_keys: &[char],
+    word_count: usize,
+) -> String {
+    let letters = unlocked
+        .iter()
+        .copied()
+        .filter(char::is_ascii_alphabetic)
+        .collect::<Vec<_>>();
+    let new_letters = new_keys
+        .iter()
+        .copied()
+        .filter(char::is_ascii_alphabetic)
+        .collect::<Vec<_>>();
+    let new_digits = new_keys
+        .iter()
+        .copied()
+        .filter(char::is_ascii_digit)
+        .collect::<Vec<_>>();
+    let new_symbols = new_keys
+        .iter()
+        .copied()
+        .filter(|ch| !ch.is_ascii_alphanumeric())
+        .collect::<Vec<_>>();
+    let words = corpus_words(&letters);
+    let focus_words = words
+        .iter()
+        .filter(|word| word.chars().any(|ch| new_letters.contains(&ch)))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut rng = rng();
+    (0..word_count)
+        .map(|index| {
+            let practises_new_key = index % 2 == 0;
+            if practises_new_key && !new_digits.is_empty() {
+                return drill_word(&new_digits, &new_digits);
+            }
+            let word = if practises_new_key && !new_letters.is_empty() {
+                pick_or_drill(&focus_words, &new_letters, &letters)
+            } else {
+                pick_or_drill(&words, &letters, &letters)
+            };
+            match new_symbols.choose(&mut rng) {
+                Some(symbol) if practises_new_key => format!("{word}{symbol}"),
+                _ => word,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Distinct lowercase corpus words spelled only with `letters`.
+fn corpus_words(letters: &[char]) -> Vec<String> {
+    let words = entity::get_sample()
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|ch: char| !ch.is_alphabetic())
+                .to_lowercase()
+        })
+        .filter(|word| word.chars().count() >= DRILL_WORD_MIN_CHARS)
+        .filter(|word| word.chars().all(|ch| letters.contains(&ch)))
+        .collect::<BTreeSet<_>>();
+    if words.len() < MIN_CORPUS_WORDS {
+        return Vec::new();
+    }
+    words.into_iter().collect()
+}
+
+fn pick_or_drill(words: &[String], required: &[char], pool: &[char]) -> String {
+    words
+        .choose(&mut rng())
+        .cloned()
+        .unwrap_or_else(|| drill_word(required, pool))
+}
+
+/// A random run of `pool` keys that contains at least one `required` key.
+fn drill_word(required: &[char], pool: &[char]) -> String {
+    let mut rng = rng();
+    let length = rng.random_range(DRILL_WORD_MIN_CHARS..=DRILL_WORD_MAX_CHARS);
+    let mut chars = (0..length)
+        .filter_map(|_| pool.choose(&mut rng).copied())
+        .collect::<Vec<_>>();
+    if let (Some(slot), Some(key)) = (
+        chars.get_mut(rng.random_range(0..length)),
+        required.choose(&mut rng),
+    ) {
+        *slot = *key;
+    }
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn lesson_words_use_only_unlocked_keys() {
+        let unlocked = chars("asdfghjkl");
+        let text = generate_lesson_words(&unlocked, &unlocked, 20);
+
+        assert_eq!(text.split_whitespace().count(), 20);
+        assert!(text.chars().all(|ch| ch == ' ' || unlocked.contains(&ch)));
+    }
+
+    #[test]
+    fn every_other_word_practises_a_new_key() {
+        let unlocked = chars("asdfghjklqwertyuiop");
+        let new_keys = chars("qwertyuiop");
+        let text = generate_lesson_words(&unlocked, &new_keys, 10);
+
+        for word in text.split_whitespace().step_by(2) {
+            assert!(word.chars().any(|ch| new_keys.contains(&ch)), "{word}");
+        }
+    }
+
+    #[test]
+    fn digit_and_symbol_lessons_mix_in_their_keys() {
+        let letters = chars("asdfghjkl");
+        let digits = chars("1234567890");
+        let numbers =
+            generate_lesson_words(&[letters.clone(), digits.clone()].concat(), &digits, 4);
+        let words = numbers.split_whitespace().collect::<Vec<_>>();
+        assert!(
+            words
+                .iter()
+                .step_by(2)
+                .all(|word| word.chars().all(|ch| ch.is_ascii_digit()))
+        );
+
+        let symbols = chars(".,;");
+        let text = generate_lesson_words(&[letters, symbols.clone()].concat(), &symbols, 4);
+        assert!(
+            text.split_whitespace()
+                .step_by(2)
+                .all(|word| word.ends_with(|ch| symbols.contains(&ch)))
+        );
+    }
+}
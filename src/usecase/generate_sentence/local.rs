@@ -10,6 +10,24 @@ pub(super) fn generate_local_sentence(target_chars: usize) -> String {
     generate_markov_chain(&sampling_contents, 4, target_chars)
 }
 
+pub(super) fn generate_local_words(word_count: usize) -> String {
+    const MAX_ATTEMPTS: usize = 32;
+
+    let sampling_contents = entity::get_sample();
+    let mut words = Vec::with_capacity(word_count);
+    for _ in 0..MAX_ATTEMPTS {
+        if words.len() >= word_count {
+            break;
+        }
+        let remaining = word_count - words.len();
+        let chunk = generate_markov_chain(&sampling_contents, 4, remaining * 8);
+        words.extend(chunk.split_whitespace().map(ToOwned::to_owned));
+    }
+
+    words.truncate(word_count);
+    words.join(" ")
+}
+
 fn generate_markov_chain(text: &str, n: usize, target_chars: usize) -> String {
     let words: Vec<&str> = text.split_whitespace().collect();
     if words.len() <= n {
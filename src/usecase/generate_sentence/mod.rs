@@ -1,3 +1,6 @@
+mod content;
+mod drill;
+mod lesson;
 mod local;
 mod normalize;
 mod providers;
@@ -5,6 +8,7 @@ mod providers;
 use std::io;
 
 use crate::domain::config::ProviderConfig;
+use crate::domain::text_content::TextContent;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GenerationSource {
@@ -21,29 +25,117 @@ impl GenerationSource {
             Self::Groq => "Groq",
         }
     }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [Self::Local, Self::Google, Self::Groq]
+            .into_iter()
+            .find(|source| source.label() == label)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextTarget {
+    Scale(usize),
+    Words(usize),
+}
+
+impl TextTarget {
+    fn character_budget(self) -> usize {
+        match self {
+            Self::Scale(text_scale) => target_character_count(text_scale),
+            Self::Words(words) => words_character_budget(words),
+        }
+    }
 }
 
+/// Restricted content drops characters and words, so this much more text is
+/// generated before it is cut to the target.
+const RESTRICTED_CONTENT_BUDGET_FACTOR: usize = 3;
+/// Times a words target is topped up with local text before giving up.
+const MAX_WORD_TOP_UPS: usize = 8;
+
 pub fn generate(
-    text_scale: usize,
+    target: TextTarget,
     source: GenerationSource,
     provider_config: Option<ProviderConfig>,
+    text_content: TextContent,
 ) -> Result<String, io::Error> {
-    let target_chars = target_character_count(text_scale);
-    let sentence = match source {
-        GenerationSource::Local => local::generate_local_sentence(target_chars),
-        GenerationSource::Google => {
-            providers::generate_google_sentence(target_chars, provider_config)?
+    let target_chars = target.character_budget();
+    let factor = if text_content.restricts() {
+        RESTRICTED_CONTENT_BUDGET_FACTOR
+    } else {
+        1
+    };
+    let sentence = match (source, target) {
+        (GenerationSource::Local, TextTarget::Scale(_)) => {
+            local::generate_local_sentence(target_chars * factor)
+        }
+        (GenerationSource::Local, TextTarget::Words(words)) => {
+            local::generate_local_words(words * factor)
+        }
+        (GenerationSource::Google, _) => {
+            providers::generate_google_sentence(target_chars * factor, provider_config)?
+        }
+        (GenerationSource::Groq, _) => {
+            providers::generate_groq_sentence(target_chars * factor, provider_config)?
         }
-        GenerationSource::Groq => providers::generate_groq_sentence(target_chars, provider_config)?,
     };
+    let mut sentence = content::restrict_content(
+        &normalize::normalize_sentence(&sentence, usize::MAX),
+        text_content,
+    );
+    if let TextTarget::Words(words) = target {
+        sentence = top_up_words(sentence, words, text_content);
+    }
+    let sentence = content::add_content(&sentence, text_content);
+
+    Ok(match target {
+        TextTarget::Scale(_) => normalize::normalize_sentence(&sentence, target_chars),
+        TextTarget::Words(words) => normalize::normalize_words(&sentence, words),
+    })
+}
+
+/// Providers may reply with fewer words than asked for, and restricted
+/// content drops words, so a words target is filled up with local text.
+fn top_up_words(mut text: String, words: usize, text_content: TextContent) -> String {
+    for _ in 0..MAX_WORD_TOP_UPS {
+        let missing = words.saturating_sub(text.split_whitespace().count());
+        if missing == 0 {
+            break;
+        }
+        let extra = content::restrict_content(
+            &normalize::normalize_sentence(
+                &local::generate_local_words(missing * RESTRICTED_CONTENT_BUDGET_FACTOR),
+                usize::MAX,
+            ),
+            text_content,
+        );
+        text = format!("{text} {extra}").trim().to_string();
+    }
+    text
+}
 
-    Ok(normalize::normalize_sentence(&sentence, target_chars))
+/// Lesson text is limited to the unlocked keys, so it is always generated
+/// locally.
+pub fn generate_lesson(unlocked: &[char], new_keys: &[char], word_count: usize) -> String {
+    lesson::generate_lesson_words(unlocked, new_keys, word_count)
+}
+
+/// Drill text repeats the drilled characters and bigrams, so it is always
+/// generated locally.
+pub fn generate_drill(items: &[String], word_count: usize) -> String {
+    drill::generate_drill_words(items, word_count)
 }
 
 pub fn target_character_count(text_scale: usize) -> usize {
     (text_scale.max(4)) * 5
 }
 
+/// Generous enough that a generated text can be trimmed to the word count.
+fn words_character_budget(words: usize) -> usize {
+    words.max(1) * 8
+}
+
 #[cfg(test)]
 mod tests {
     #![expect(clippy::expect_used)]
@@ -51,8 +143,13 @@ mod tests {
 
     #[test]
     fn test_generate_sentence_success() {
-        let sentence =
-            generate(10, GenerationSource::Local, None).expect("local generation should succeed");
+        let sentence = generate(
+            TextTarget::Scale(10),
+            GenerationSource::Local,
+            None,
+            TextContent::FULL,
+        )
+        .expect("local generation should succeed");
         assert!(
             !sentence.is_empty(),
             "Generated sentence should not be empty"
@@ -60,6 +157,60 @@ mod tests {
         assert!(sentence.chars().count() <= target_character_count(10));
     }
 
+    #[test]
+    fn local_word_target_generates_exact_word_count() {
+        let sentence = generate(
+            TextTarget::Words(40),
+            GenerationSource::Local,
+            None,
+            TextContent::FULL,
+        )
+        .expect("local generation should succeed");
+
+        assert_eq!(sentence.split_whitespace().count(), 40);
+        assert_eq!(sentence, sentence.trim());
+    }
+
+    #[test]
+    fn restricted_content_still_fills_the_word_count() {
+        let content = TextContent {
+            capitals: false,
+            punctuation: false,
+            rare_words: false,
+            ..TextContent::FULL
+        };
+        let sentence = generate(
+            TextTarget::Words(30),
+            GenerationSource::Local,
+            None,
+            content,
+        )
+        .expect("local generation should succeed");
+
+        assert_eq!(sentence.split_whitespace().count(), 30);
+        assert!(
+            sentence
+                .chars()
+                .all(|ch| ch == ' ' || ch.is_ascii_lowercase() || ch.is_ascii_digit())
+        );
+    }
+
+    #[test]
+    fn short_provider_reply_is_topped_up_to_the_word_count() {
+        let reply = normalize::normalize_sentence("Only four words here", usize::MAX);
+        let sentence = normalize::normalize_words(&top_up_words(reply, 25, TextContent::FULL), 25);
+
+        assert_eq!(sentence.split_whitespace().count(), 25);
+        assert!(sentence.starts_with("Only four words here "));
+    }
+
+    #[test]
+    fn normalize_words_keeps_requested_word_count() {
+        let normalized = normalize::normalize_words("one two\nthree  four five", 3);
+
+        assert_eq!(normalized, "one two three");
+    }
+
     #[test]
     fn target_length_scales_with_level() {
         assert!(target_character_count(20) > target_character_count(10));
@@ -103,8 +254,13 @@ mod tests {
 
     #[test]
     fn google_generation_requires_complete_config() {
-        let err =
-            generate(10, GenerationSource::Google, None).expect_err("config should be required");
+        let err = generate(
+            TextTarget::Scale(10),
+            GenerationSource::Google,
+            None,
+            TextContent::FULL,
+        )
+        .expect_err("config should be required");
         assert!(err.to_string().contains("Google config is missing"));
 
         let incomplete = ProviderConfig {
@@ -112,8 +268,13 @@ mod tests {
             api_key: String::new(),
             model: "gemini".into(),
         };
-        let err = generate(10, GenerationSource::Google, Some(incomplete))
-            .expect_err("incomplete config should fail");
+        let err = generate(
+            TextTarget::Scale(10),
+            GenerationSource::Google,
+            Some(incomplete),
+            TextContent::FULL,
+        )
+        .expect_err("incomplete config should fail");
         assert!(
             err.to_string()
                 .contains("Google AI Studio config is incomplete")
@@ -122,8 +283,13 @@ mod tests {
 
     #[test]
     fn groq_generation_requires_complete_config() {
-        let err =
-            generate(10, GenerationSource::Groq, None).expect_err("config should be required");
+        let err = generate(
+            TextTarget::Scale(10),
+            GenerationSource::Groq,
+            None,
+            TextContent::FULL,
+        )
+        .expect_err("config should be required");
         assert!(err.to_string().contains("Groq config is missing"));
 
         let incomplete = ProviderConfig {
@@ -131,8 +297,13 @@ mod tests {
             api_key: "secret".into(),
             model: String::new(),
         };
-        let err = generate(10, GenerationSource::Groq, Some(incomplete))
-            .expect_err("incomplete config should fail");
+        let err = generate(
+            TextTarget::Scale(10),
+            GenerationSource::Groq,
+            Some(incomplete),
+            TextContent::FULL,
+        )
+        .expect_err("incomplete config should fail");
         assert!(err.to_string().contains("Groq config is incomplete"));
     }
 
@@ -20,3 +20,11 @@ pub(super) fn normalize_sentence(sentence: &str, target_chars: usize) -> String
 
     trimmed.chars().take(target_chars).collect()
 }
+
+pub(super) fn normalize_words(sentence: &str, words: usize) -> String {
+    normalize_sentence(sentence, usize::MAX)
+        .split_whitespace()
+        .take(words)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,247 @@
+use crate::domain::history::{HistoryEntry, HistoryMode};
+use crate::domain::timestamp::SECONDS_PER_DAY;
+use crate::usecase::generate_sentence::GenerationSource;
+
+/// Which saved entries the Stats screen summarizes. The default is every
+/// timed run, matching what the statistics described before filters existed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryFilter {
+    /// `None` keeps every source.
+    pub source: Option<GenerationSource>,
+    pub mode: ModeFilter,
+    pub duration: DurationBucket,
+    pub range: DateRange,
+}
+
+impl HistoryFilter {
+    /// Whether `entry` is kept, with `now` in seconds since the Unix epoch.
+    pub fn matches(self, entry: &HistoryEntry, now: u64) -> bool {
+        self.source
+            .is_none_or(|source| entry.generation_source == source.label())
+            && self.mode.matches(&entry.mode)
+            && self.duration.matches(entry.elapsed_seconds)
+            && self.range.matches(entry.timestamp, now)
+    }
+
+    pub fn apply(self, entries: &[HistoryEntry], now: u64) -> Vec<HistoryEntry> {
+        entries
+            .iter()
+            .filter(|entry| self.matches(entry, now))
+            .cloned()
+            .collect()
+    }
+
+    pub fn cycle_source(&mut self) {
+        self.source = match self.source {
+            None => Some(GenerationSource::Local),
+            Some(GenerationSource::Local) => Some(GenerationSource::Google),
+            Some(GenerationSource::Google) => Some(GenerationSource::Groq),
+            Some(GenerationSource::Groq) => None,
+        };
+    }
+
+    pub fn label(self) -> String {
+        [
+            self.mode.label(),
+            self.source.map_or("all sources", GenerationSource::label),
+            self.duration.label(),
+            self.range.label(),
+        ]
+        .join(" / ")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModeFilter {
+    #[default]
+    Timed,
+    Practice,
+    Rhythm,
+    All,
+}
+
+impl ModeFilter {
+    pub fn matches(self, mode: &HistoryMode) -> bool {
+        match self {
+            Self::Timed => matches!(mode, HistoryMode::Timed),
+            Self::Practice => matches!(mode, HistoryMode::Practice { .. }),
+            Self::Rhythm => matches!(mode, HistoryMode::Rhythm(_)),
+            Self::All => true,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Timed => "timed",
+            Self::Practice => "practice",
+            Self::Rhythm => "rhythm",
+            Self::All => "all modes",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Timed => Self::Practice,
+            Self::Practice => Self::Rhythm,
+            Self::Rhythm => Self::All,
+            Self::All => Self::Timed,
+        }
+    }
+}
+
+/// Ranges of the time a run actually took, so time, word, and passage tests
+/// of similar length are compared together.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DurationBucket {
+    #[default]
+    Any,
+    UpTo30,
+    UpTo60,
+    UpTo120,
+    Over120,
+}
+
+impl DurationBucket {
+    pub fn matches(self, elapsed_seconds: i32) -> bool {
+        match self {
+            Self::Any => true,
+            Self::UpTo30 => elapsed_seconds <= 30,
+            Self::UpTo60 => (31..=60).contains(&elapsed_seconds),
+            Self::UpTo120 => (61..=120).contains(&elapsed_seconds),
+            Self::Over120 => elapsed_seconds > 120,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Any => "any length",
+            Self::UpTo30 => "up to 30s",
+            Self::UpTo60 => "31-60s",
+            Self::UpTo120 => "61-120s",
+            Self::Over120 => "over 120s",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Any => Self::UpTo30,
+            Self::UpTo30 => Self::UpTo60,
+            Self::UpTo60 => Self::UpTo120,
+            Self::UpTo120 => Self::Over120,
+            Self::Over120 => Self::Any,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateRange {
+    #[default]
+    All,
+    Last7Days,
+    Last30Days,
+}
+
+impl DateRange {
+    /// Entries saved before timestamps were recorded only match `All`.
+    pub fn matches(self, timestamp: Option<u64>, now: u64) -> bool {
+        let Some(days) = self.days() else {
+            return true;
+        };
+        timestamp.is_some_and(|timestamp| now.saturating_sub(timestamp) < days * SECONDS_PER_DAY)
+    }
+
+    fn days(self) -> Option<u64> {
+        match self {
+            Self::All => None,
+            Self::Last7Days => Some(7),
+            Self::Last30Days => Some(30),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "all time",
+            Self::Last7Days => "last 7 days",
+            Self::Last30Days => "last 30 days",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Last7Days,
+            Self::Last7Days => Self::Last30Days,
+            Self::Last30Days => Self::All,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::history::RhythmRecord;
+
+    const NOW: u64 = 1_792_389_143;
+
+    fn entry(source: &str, elapsed_seconds: i32, days_ago: Option<u64>) -> HistoryEntry {
+        HistoryEntry {
+            generation_source: source.to_string(),
+            elapsed_seconds,
+            timestamp: days_ago.map(|days| NOW - days * SECONDS_PER_DAY),
+            ..HistoryEntry::default()
+        }
+    }
+
+    #[test]
+    fn default_filter_keeps_every_timed_run() {
+        let filter = HistoryFilter::default();
+        let rhythm = HistoryEntry {
+            mode: HistoryMode::Rhythm(RhythmRecord::default()),
+            ..HistoryEntry::default()
+        };
+
+        assert!(filter.matches(&entry("Groq", 200, None), NOW));
+        assert!(!filter.matches(&rhythm, NOW));
+        assert_eq!(
+            filter.label(),
+            "timed / all sources / any length / all time"
+        );
+    }
+
+    #[test]
+    fn each_criterion_narrows_the_entries() {
+        let entries = [
+            entry("Local", 30, Some(1)),
+            entry("Local", 45, Some(10)),
+            entry("Groq", 45, Some(2)),
+            entry("Local", 45, None),
+        ];
+        let filter = HistoryFilter {
+            source: Some(GenerationSource::Local),
+            duration: DurationBucket::UpTo60,
+            range: DateRange::Last30Days,
+            ..HistoryFilter::default()
+        };
+
+        assert_eq!(
+            filter.apply(&entries, NOW),
+            vec![entry("Local", 45, Some(10))]
+        );
+        let recent = HistoryFilter {
+            range: DateRange::Last7Days,
+            ..HistoryFilter::default()
+        };
+        assert_eq!(recent.apply(&entries, NOW).len(), 2);
+    }
+
+    #[test]
+    fn cycles_return_to_the_unfiltered_choice() {
+        let mut filter = HistoryFilter::default();
+        for _ in 0..4 {
+            filter.cycle_source();
+        }
+        assert_eq!(filter.source, None);
+        assert_eq!(ModeFilter::All.next(), ModeFilter::Timed);
+        assert_eq!(DurationBucket::Over120.next(), DurationBucket::Any);
+        assert_eq!(DateRange::Last30Days.next(), DateRange::All);
+    }
+}
@@ -0,0 +1,30 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::RngExt;
+use rand::rng;
+
+/// Random 64-bit identifier for a new history entry, as 16 hex digits.
+pub fn new_id() -> String {
+    format!("{:016x}", rng().random::<u64>())
+}
+
+/// Seconds since the Unix epoch (UTC).
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_ids_are_hex_and_distinct() {
+        let first = new_id();
+
+        assert_eq!(first.len(), 16);
+        assert!(first.chars().all(|ch| ch.is_ascii_hexdigit()));
+        assert_ne!(first, new_id());
+    }
+}
@@ -1,33 +1,70 @@
 use std::collections::BTreeMap;
 
-use crate::domain::history::HistoryEntry;
+use crate::domain::history::{AttemptCount, HistoryEntry};
+use crate::usecase::{key_stats, text_difficulty};
+
+/// Characters need this many attempts across history to be ranked, so rare
+/// keys are not ranked on a handful of presses.
+pub const MIN_CHAR_ATTEMPTS: usize = 20;
+/// Words need to have been finished this many times to be ranked.
+pub const MIN_WORD_ATTEMPTS: usize = 3;
+const WEAK_SPOT_LIMIT: usize = 5;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct HistoryStats {
     pub count: usize,
+    pub failed_count: usize,
     pub best_wpm: Option<f64>,
     pub average_wpm: Option<f64>,
     pub average_accuracy: Option<f64>,
     pub recent_wpm: Vec<f64>,
-    pub frequent_mistakes: Vec<MistakeCount>,
+    pub best_burst_wpm: Option<f64>,
+    pub recent_consistency: Vec<f64>,
+    /// Characters with the highest error rate, worst first.
+    pub weak_chars: Vec<WeakSpot<char>>,
+    pub weak_words: Vec<WeakSpot<String>>,
+    pub difficulty: Option<DifficultyStats>,
+}
+
+/// Speed scaled by text difficulty, over the runs that recorded it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyStats {
+    pub runs: usize,
+    pub average_difficulty: f64,
+    pub best_adjusted_wpm: f64,
+    pub average_adjusted_wpm: f64,
 }
 
+/// A character or word and how often it was missed.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct MistakeCount {
-    pub character: char,
-    pub count: usize,
+pub struct WeakSpot<T> {
+    pub item: T,
+    pub count: AttemptCount,
 }
 
+/// Failed sudden-death runs are counted separately and left out of the speed
+/// and accuracy figures, which would otherwise reward ending a run early.
 pub fn summarize(entries: &[HistoryEntry]) -> HistoryStats {
+    let failed_count = entries.iter().filter(|entry| entry.failed).count();
+    let entries = entries
+        .iter()
+        .filter(|entry| !entry.failed)
+        .collect::<Vec<_>>();
+    let entries = entries.as_slice();
     let count = entries.len();
     if entries.is_empty() {
         return HistoryStats {
             count,
+            failed_count,
             best_wpm: None,
             average_wpm: None,
             average_accuracy: None,
             recent_wpm: Vec::new(),
-            frequent_mistakes: Vec::new(),
+            best_burst_wpm: None,
+            recent_consistency: Vec::new(),
+            weak_chars: Vec::new(),
+            weak_words: Vec::new(),
+            difficulty: None,
         };
     }
 
@@ -36,19 +73,74 @@ pub fn summarize(entries: &[HistoryEntry]) -> HistoryStats {
     let average_wpm = Some(entries.iter().map(|entry| entry.wpm).sum::<f64>() / divisor);
     let average_accuracy = Some(entries.iter().map(|entry| entry.accuracy).sum::<f64>() / divisor);
     let recent_wpm = recent_wpm(entries);
-    let frequent_mistakes = frequent_mistakes(entries);
+    let best_burst_wpm = entries
+        .iter()
+        .filter_map(|entry| entry.analytics.as_ref())
+        .map(|analytics| analytics.burst_wpm)
+        .reduce(f64::max);
+    let recent_consistency = recent_consistency(entries);
+    let weak_chars = weak_chars(entries);
+    let weak_words = weak_spots(
+        entries.iter().flat_map(|entry| {
+            entry
+                .word_attempts
+                .iter()
+                .map(|(word, count)| (word.as_str(), *count))
+        }),
+        MIN_WORD_ATTEMPTS,
+    )
+    .into_iter()
+    .map(|spot| WeakSpot {
+        item: spot.item.to_string(),
+        count: spot.count,
+    })
+    .collect();
+    let difficulty = difficulty_stats(entries);
 
     HistoryStats {
         count,
+        failed_count,
         best_wpm,
         average_wpm,
         average_accuracy,
         recent_wpm,
-        frequent_mistakes,
+        best_burst_wpm,
+        recent_consistency,
+        weak_chars,
+        weak_words,
+        difficulty,
+    }
+}
+
+/// Runs saved before difficulty was recorded are left out.
+fn difficulty_stats(entries: &[&HistoryEntry]) -> Option<DifficultyStats> {
+    let scored = entries
+        .iter()
+        .filter_map(|entry| {
+            let difficulty = entry.difficulty?;
+            Some((
+                difficulty,
+                text_difficulty::adjusted_wpm(entry.wpm, difficulty),
+            ))
+        })
+        .collect::<Vec<_>>();
+    if scored.is_empty() {
+        return None;
     }
+
+    let divisor = f64::from(u32::try_from(scored.len()).unwrap_or(u32::MAX));
+    Some(DifficultyStats {
+        runs: scored.len(),
+        average_difficulty: scored.iter().map(|(difficulty, _)| difficulty).sum::<f64>() / divisor,
+        best_adjusted_wpm: scored
+            .iter()
+            .map(|(_, adjusted)| *adjusted)
+            .fold(f64::MIN, f64::max),
+        average_adjusted_wpm: scored.iter().map(|(_, adjusted)| adjusted).sum::<f64>() / divisor,
+    })
 }
 
-fn recent_wpm(entries: &[HistoryEntry]) -> Vec<f64> {
+fn recent_wpm(entries: &[&HistoryEntry]) -> Vec<f64> {
     const RECENT_LIMIT: usize = 10;
 
     entries
@@ -58,35 +150,69 @@ fn recent_wpm(entries: &[HistoryEntry]) -> Vec<f64> {
         .collect()
 }
 
-fn frequent_mistakes(entries: &[HistoryEntry]) -> Vec<MistakeCount> {
-    const MISTAKE_LIMIT: usize = 5;
+/// Consistency of the latest runs that recorded speed analytics; older
+/// entries without a keystroke timeline are skipped.
+fn recent_consistency(entries: &[&HistoryEntry]) -> Vec<f64> {
+    const RECENT_LIMIT: usize = 10;
 
-    let mut counts = BTreeMap::<char, usize>::new();
-    for character in entries
+    let scores = entries
         .iter()
-        .flat_map(|entry| entry.missed_chars.iter().copied())
-    {
-        *counts.entry(character).or_default() += 1;
+        .filter_map(|entry| entry.analytics.as_ref()?.consistency())
+        .collect::<Vec<_>>();
+    scores
+        .get(scores.len().saturating_sub(RECENT_LIMIT)..)
+        .unwrap_or_default()
+        .to_vec()
+}
+
+/// Runs saved before attempts were recorded are rated from their keystroke
+/// timeline; runs without one only have misses and are left out.
+fn weak_chars(entries: &[&HistoryEntry]) -> Vec<WeakSpot<char>> {
+    let attempts = entries.iter().flat_map(|entry| {
+        let rated = (entry.char_attempts.is_empty() && !entry.keystrokes.is_empty())
+            .then(|| key_stats::collect(&entry.target_text, &entry.keystrokes).attempt_counts());
+        let recorded = entry.char_attempts.iter().map(|(ch, count)| (*ch, *count));
+        rated.into_iter().flatten().chain(recorded)
+    });
+    weak_spots(attempts, MIN_CHAR_ATTEMPTS)
+}
+
+/// Ranks by error rate the items attempted at least `min_attempts` times and
+/// missed at least once.
+fn weak_spots<T: Ord>(
+    attempts: impl Iterator<Item = (T, AttemptCount)>,
+    min_attempts: usize,
+) -> Vec<WeakSpot<T>> {
+    let mut totals = BTreeMap::<T, AttemptCount>::new();
+    for (item, count) in attempts {
+        totals.entry(item).or_default().merge(count);
     }
 
-    let mut mistakes = counts
+    let mut spots = totals
         .into_iter()
-        .map(|(character, count)| MistakeCount { character, count })
+        .filter(|(_, count)| count.attempts >= min_attempts && count.misses > 0)
+        .map(|(item, count)| WeakSpot { item, count })
         .collect::<Vec<_>>();
-    mistakes.sort_by(|left, right| {
+    spots.sort_by(|left, right| {
         right
             .count
-            .cmp(&left.count)
-            .then_with(|| left.character.cmp(&right.character))
+            .error_rate()
+            .total_cmp(&left.count.error_rate())
+            .then_with(|| right.count.misses.cmp(&left.count.misses))
+            .then_with(|| left.item.cmp(&right.item))
     });
-    mistakes.truncate(MISTAKE_LIMIT);
-    mistakes
+    spots.truncate(WEAK_SPOT_LIMIT);
+    spots
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::history::HistoryMode;
+    use crate::domain::history::{HistoryMode, Keystroke, SpeedAnalytics};
+
+    fn count(attempts: usize, misses: usize) -> AttemptCount {
+        AttemptCount { attempts, misses }
+    }
 
     fn entry(wpm: f64, accuracy: f64, missed_chars: Vec<char>) -> HistoryEntry {
         HistoryEntry {
@@ -97,6 +223,7 @@ mod tests {
             generation_source: "Local".into(),
             mode: HistoryMode::Timed,
             missed_chars,
+            ..HistoryEntry::default()
         }
     }
 
@@ -107,7 +234,7 @@ mod tests {
         assert_eq!(stats.count, 0);
         assert_eq!(stats.best_wpm, None);
         assert!(stats.recent_wpm.is_empty());
-        assert!(stats.frequent_mistakes.is_empty());
+        assert!(stats.weak_chars.is_empty());
     }
 
     #[test]
@@ -125,18 +252,96 @@ mod tests {
         assert_eq!(stats.average_wpm, Some(15.0));
         assert_eq!(stats.average_accuracy, Some(90.0));
         assert_eq!(stats.recent_wpm, vec![10.0, 20.0, 15.0]);
+    }
+
+    #[test]
+    fn summarize_counts_failed_runs_separately() {
+        let mut failed = entry(90.0, 50.0, vec!['z']);
+        failed.failed = true;
+        failed.char_attempts = BTreeMap::from([('z', count(30, 30))]);
+        let entries = [entry(10.0, 90.0, vec!['a']), failed];
+
+        let stats = summarize(&entries);
+
+        assert_eq!(stats.count, 1);
+        assert_eq!(stats.failed_count, 1);
+        assert_eq!(stats.best_wpm, Some(10.0));
+        assert!(stats.weak_chars.is_empty());
+    }
+
+    #[test]
+    fn summarize_ranks_weak_chars_by_error_rate_not_miss_count() {
+        let run = |attempts: &[(char, AttemptCount)]| HistoryEntry {
+            char_attempts: attempts.iter().copied().collect(),
+            ..entry(30.0, 95.0, Vec::new())
+        };
+        let entries = [
+            run(&[
+                ('e', count(150, 6)),
+                ('q', count(12, 3)),
+                ('z', count(5, 5)),
+            ]),
+            run(&[
+                ('e', count(150, 6)),
+                ('q', count(12, 3)),
+                ('t', count(40, 0)),
+            ]),
+        ];
+
+        let weak = summarize(&entries)
+            .weak_chars
+            .into_iter()
+            .map(|spot| (spot.item, spot.count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(weak, vec![('q', count(24, 6)), ('e', count(300, 12))]);
+    }
+
+    #[test]
+    fn summarize_rates_older_runs_from_their_keystroke_timeline() {
+        let keystrokes = "xa"
+            .chars()
+            .cycle()
+            .take(40)
+            .enumerate()
+            .map(|(index, key)| Keystroke {
+                elapsed_ms: 100 * index as u64,
+                key: Some(key),
+                position: index / 2 + 1,
+            })
+            .collect::<Vec<_>>();
+        let older = HistoryEntry {
+            target_text: "a".repeat(21),
+            keystrokes,
+            ..entry(30.0, 50.0, Vec::new())
+        };
+
+        assert_eq!(
+            summarize(&[older]).weak_chars,
+            vec![WeakSpot {
+                item: 'a',
+                count: count(40, 20)
+            }]
+        );
+    }
+
+    #[test]
+    fn summarize_ranks_words_finished_often_enough() {
+        let run = |words: &[(&str, AttemptCount)]| HistoryEntry {
+            word_attempts: words
+                .iter()
+                .map(|(word, count)| ((*word).to_string(), *count))
+                .collect(),
+            ..entry(30.0, 95.0, Vec::new())
+        };
+        let entries = [run(&[("the", count(4, 1)), ("rhythm", count(2, 2))])];
+
         assert_eq!(
-            stats.frequent_mistakes,
-            vec![
-                MistakeCount {
-                    character: 'a',
-                    count: 3
-                },
-                MistakeCount {
-                    character: 'b',
-                    count: 1
-                }
-            ]
+            summarize(&entries).weak_words,
+            vec![WeakSpot {
+                item: "the".to_string(),
+                count: count(4, 1)
+            }]
         );
     }
 
@@ -153,4 +358,56 @@ mod tests {
             vec![2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0]
         );
     }
+
+    #[test]
+    fn summarize_tracks_burst_and_consistency_from_analytics() {
+        let with_analytics = |wpm, burst_wpm, speed_cv| HistoryEntry {
+            analytics: Some(SpeedAnalytics {
+                speed_cv,
+                burst_wpm,
+                ..SpeedAnalytics::default()
+            }),
+            ..entry(wpm, 100.0, Vec::new())
+        };
+        let entries = [
+            entry(10.0, 100.0, Vec::new()),
+            with_analytics(40.0, 55.0, Some(20.0)),
+            with_analytics(42.0, 61.0, None),
+            with_analytics(45.0, 58.0, Some(12.5)),
+        ];
+
+        let stats = summarize(&entries);
+
+        assert_eq!(stats.best_burst_wpm, Some(61.0));
+        assert_eq!(stats.recent_consistency, vec![80.0, 87.5]);
+    }
+
+    #[test]
+    fn summarize_adjusts_wpm_for_runs_with_a_difficulty() {
+        let scored = |wpm, difficulty| HistoryEntry {
+            difficulty: Some(difficulty),
+            ..entry(wpm, 100.0, Vec::new())
+        };
+        let entries = [
+            entry(90.0, 100.0, Vec::new()),
+            scored(50.0, 50.0),
+            scored(40.0, 10.0),
+        ];
+
+        let difficulty = summarize(&entries).difficulty;
+
+        assert_eq!(
+            difficulty,
+            Some(DifficultyStats {
+                runs: 2,
+                average_difficulty: 30.0,
+                best_adjusted_wpm: 60.0,
+                average_adjusted_wpm: 46.0,
+            })
+        );
+        assert_eq!(
+            summarize(&[entry(90.0, 100.0, Vec::new())]).difficulty,
+            None
+        );
+    }
 }
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+
+use crate::domain::history::{AttemptCount, HistoryEntry, Keystroke, keystroke_misses};
+use crate::domain::layout::{KeyPosition, KeyboardLayout};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyStat {
+    pub attempts: usize,
+    pub errors: usize,
+    pub latency_samples: usize,
+    pub total_latency_ms: u64,
+}
+
+impl KeyStat {
+    pub fn average_latency_ms(&self) -> Option<f64> {
+        if self.latency_samples == 0 {
+            return None;
+        }
+
+        Some(to_f64(self.total_latency_ms) / to_f64(self.latency_samples as u64))
+    }
+
+    /// Error rate in percent, or `None` before the key was ever expected.
+    pub fn error_rate(&self) -> Option<f64> {
+        if self.attempts == 0 {
+            return None;
+        }
+
+        Some(to_f64(self.errors as u64) * 100.0 / to_f64(self.attempts as u64))
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.attempts += other.attempts;
+        self.errors += other.errors;
+        self.latency_samples += other.latency_samples;
+        self.total_latency_ms += other.total_latency_ms;
+    }
+}
+
+/// Per-character accuracy and speed, keyed by the expected character.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyReport {
+    stats: BTreeMap<char, KeyStat>,
+}
+
+impl KeyReport {
+    pub fn get(&self, ch: char) -> Option<&KeyStat> {
+        self.stats.get(&ch)
+    }
+
+    /// Combines the characters typed with each physical key of `layout`, so
+    /// `a` and `A` count towards the same key.
+    pub fn by_key(&self, layout: &KeyboardLayout) -> BTreeMap<KeyPosition, KeyStat> {
+        let mut keys = BTreeMap::<KeyPosition, KeyStat>::new();
+        for (ch, stat) in &self.stats {
+            if let Some(position) = layout.key_position(*ch) {
+                keys.entry(position).or_default().merge(stat);
+            }
+        }
+        keys
+    }
+
+    /// Attempts and errors per expected character, in the form saved with
+    /// history entries.
+    pub fn attempt_counts(&self) -> BTreeMap<char, AttemptCount> {
+        self.stats
+            .iter()
+            .map(|(ch, stat)| {
+                let count = AttemptCount {
+                    attempts: stat.attempts,
+                    misses: stat.errors,
+                };
+                (*ch, count)
+            })
+            .collect()
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (ch, stat) in other.stats {
+            self.stats.entry(ch).or_default().merge(&stat);
+        }
+    }
+}
+
+/// Collects per-character results from one run.
+///
+/// Every key press counts as an attempt at the character expected at the
+/// cursor. Latency is the time since the previous key and is only measured
+/// when that key was correct and nothing was deleted in between.
+pub fn collect(target: &str, keystrokes: &[Keystroke]) -> KeyReport {
+    let target_chars = target.chars().collect::<Vec<_>>();
+    let misses = keystroke_misses(target, keystrokes);
+    let mut report = KeyReport::default();
+    let mut cursor = 0;
+    let mut previous_correct_ms = None;
+
+    for (keystroke, miss) in keystrokes.iter().zip(misses) {
+        if keystroke.key.is_some()
+            && let Some(expected) = target_chars.get(cursor)
+        {
+            let stat = report.stats.entry(*expected).or_default();
+            stat.attempts += 1;
+            if miss {
+                stat.errors += 1;
+            } else if let Some(previous_ms) = previous_correct_ms {
+                stat.latency_samples += 1;
+                stat.total_latency_ms += keystroke.elapsed_ms.saturating_sub(previous_ms);
+            }
+        }
+
+        previous_correct_ms = (keystroke.key.is_some() && !miss).then_some(keystroke.elapsed_ms);
+        cursor = keystroke.position;
+    }
+
+    report
+}
+
+/// Merges the per-character results of every successful run with a timeline.
+pub fn aggregate(entries: &[HistoryEntry]) -> KeyReport {
+    let mut report = KeyReport::default();
+    for entry in entries.iter().filter(|entry| !entry.failed) {
+        report.merge(collect(&entry.target_text, &entry.keystrokes));
+    }
+    report
+}
+
+fn to_f64(value: u64) -> f64 {
+    f64::from(u32::try_from(value).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+    use crate::domain::layout::QWERTY;
+
+    fn keystroke(elapsed_ms: u64, key: Option<char>, position: usize) -> Keystroke {
+        Keystroke {
+            elapsed_ms,
+            key,
+            position,
+        }
+    }
+
+    #[test]
+    fn collect_counts_attempts_errors_and_latency_per_expected_char() {
+        let report = collect(
+            "aba",
+            &[
+                keystroke(100, Some('a'), 1),
+                keystroke(250, Some('x'), 2),
+                keystroke(300, None, 1),
+                keystroke(500, Some('b'), 2),
+                keystroke(650, Some('a'), 3),
+            ],
+        );
+
+        let b = report.get('b').expect("b was expected");
+        assert_eq!((b.attempts, b.errors, b.latency_samples), (2, 1, 0));
+        assert_eq!(b.error_rate(), Some(50.0));
+        let a = report.get('a').expect("a was expected");
+        assert_eq!((a.attempts, a.errors), (2, 0));
+        assert_eq!(a.average_latency_ms(), Some(150.0));
+    }
+
+    #[test]
+    fn by_key_merges_shifted_characters_and_drops_unknown_ones() {
+        let entry = HistoryEntry {
+            target_text: "aAé".to_string(),
+            keystrokes: vec![
+                keystroke(100, Some('a'), 1),
+                keystroke(200, Some('a'), 2),
+                keystroke(300, Some('é'), 3),
+            ],
+            ..HistoryEntry::default()
+        };
+        let report = aggregate(&[entry.clone(), entry]);
+
+        let keys = report.by_key(&QWERTY);
+        let a_key = QWERTY.key_position('a').expect("a is on QWERTY");
+        let a = keys.get(&a_key).expect("a key has stats");
+        assert_eq!((a.attempts, a.errors), (4, 2));
+        assert_eq!(keys.len(), 1);
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+
+use crate::domain::history::{HoldTimes, KeyHold, KeyRelease, Keystroke};
+use crate::domain::layout::KeyboardLayout;
+
+/// Keys need this many holds to be ranked among the longest.
+const MIN_HOLD_SAMPLES: usize = 2;
+const LONGEST_HOLD_LIMIT: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct KeyTiming {
+    dwell_samples: usize,
+    total_dwell_ms: u64,
+    flight_samples: usize,
+    total_flight_ms: i64,
+}
+
+impl KeyTiming {
+    fn average_dwell_ms(&self) -> Option<f64> {
+        average(self.total_dwell_ms, self.dwell_samples)
+    }
+
+    fn average_flight_ms(&self) -> Option<f64> {
+        signed_average(self.total_flight_ms, self.flight_samples)
+    }
+}
+
+/// Pairs each release with the earliest unreleased press of the same key and
+/// measures dwell (press to release) and flight (previous release to press).
+///
+/// Shifted characters match their key on `layout`, so a release reported as
+/// `1` ends a press of `!`. Other presses of the key before the release are
+/// auto-repeats and get no dwell of their own. Returns `None` when no press
+/// could be paired.
+pub fn analyze(
+    keystrokes: &[Keystroke],
+    releases: &[KeyRelease],
+    layout: &KeyboardLayout,
+) -> Option<HoldTimes> {
+    let presses = keystrokes
+        .iter()
+        .filter_map(|keystroke| keystroke.key.map(|key| (keystroke.elapsed_ms, key)))
+        .collect::<Vec<_>>();
+    let released_at = pair_releases(&presses, releases, layout);
+
+    let mut keys = BTreeMap::<char, KeyTiming>::new();
+    let mut previous_release = None;
+    for ((pressed_at, key), released) in presses.iter().zip(&released_at) {
+        let timing = keys.entry(key.to_ascii_lowercase()).or_default();
+        if let Some(released) = released {
+            timing.dwell_samples += 1;
+            timing.total_dwell_ms += released - pressed_at;
+        }
+        if let Some(previous) = previous_release {
+            timing.flight_samples += 1;
+            timing.total_flight_ms += to_i64(*pressed_at) - to_i64(previous);
+        }
+        previous_release = *released;
+    }
+
+    let total = keys
+        .values()
+        .fold(KeyTiming::default(), |mut total, timing| {
+            total.dwell_samples += timing.dwell_samples;
+            total.total_dwell_ms += timing.total_dwell_ms;
+            total.flight_samples += timing.flight_samples;
+            total.total_flight_ms += timing.total_flight_ms;
+            total
+        });
+
+    Some(HoldTimes {
+        average_dwell_ms: total.average_dwell_ms()?,
+        average_flight_ms: total.average_flight_ms(),
+        longest_holds: longest_holds(&keys),
+    })
+}
+
+fn pair_releases(
+    presses: &[(u64, char)],
+    releases: &[KeyRelease],
+    layout: &KeyboardLayout,
+) -> Vec<Option<u64>> {
+    let mut released_at = vec![None; presses.len()];
+    let mut consumed = vec![false; presses.len()];
+
+    for release in releases {
+        let mut paired = false;
+        for ((pressed_at, key), (released, consumed)) in presses
+            .iter()
+            .zip(released_at.iter_mut().zip(consumed.iter_mut()))
+        {
+            if *consumed || *pressed_at > release.elapsed_ms || !same_key(*key, release.key, layout)
+            {
+                continue;
+            }
+            *consumed = true;
+            if !paired {
+                *released = Some(release.elapsed_ms);
+                paired = true;
+            }
+        }
+    }
+
+    released_at
+}
+
+fn same_key(pressed: char, released: char, layout: &KeyboardLayout) -> bool {
+    pressed == released
+        || layout
+            .key_position(pressed)
+            .is_some_and(|position| layout.key_position(released) == Some(position))
+}
+
+fn longest_holds(keys: &BTreeMap<char, KeyTiming>) -> Vec<KeyHold> {
+    let mut holds = keys
+        .iter()
+        .filter(|(_, timing)| timing.dwell_samples >= MIN_HOLD_SAMPLES)
+        .filter_map(|(key, timing)| {
+            timing.average_dwell_ms().map(|dwell_ms| KeyHold {
+                key: *key,
+                dwell_ms,
+                flight_ms: timing.average_flight_ms(),
+            })
+        })
+        .collect::<Vec<_>>();
+    holds.sort_by(|left, right| right.dwell_ms.total_cmp(&left.dwell_ms));
+    holds.truncate(LONGEST_HOLD_LIMIT);
+    holds
+}
+
+fn average(total: u64, samples: usize) -> Option<f64> {
+    signed_average(to_i64(total), samples)
+}
+
+fn signed_average(total: i64, samples: usize) -> Option<f64> {
+    if samples == 0 {
+        return None;
+    }
+
+    let total = total.clamp(i64::from(i32::MIN), i64::from(i32::MAX));
+    let total = f64::from(i32::try_from(total).unwrap_or_default());
+    Some(total / f64::from(u32::try_from(samples).unwrap_or(u32::MAX)))
+}
+
+fn to_i64(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used, clippy::float_cmp)]
+    use super::*;
+    use crate::domain::layout::QWERTY;
+
+    fn press(elapsed_ms: u64, key: char) -> Keystroke {
+        Keystroke {
+            elapsed_ms,
+            key: Some(key),
+            position: 0,
+        }
+    }
+
+    fn release(elapsed_ms: u64, key: char) -> KeyRelease {
+        KeyRelease { elapsed_ms, key }
+    }
+
+    #[test]
+    fn analyze_measures_dwell_and_signed_flight() {
+        // "ab" with a released before b, then "c" pressed while b is held.
+        let holds = analyze(
+            &[press(0, 'a'), press(150, 'b'), press(260, 'c')],
+            &[release(100, 'a'), release(300, 'b'), release(340, 'c')],
+            &QWERTY,
+        )
+        .expect("every press was released");
+
+        assert_eq!(holds.average_dwell_ms, 110.0);
+        assert_eq!(holds.average_flight_ms, Some(5.0));
+    }
+
+    #[test]
+    fn releases_match_shifted_characters_and_swallow_repeats() {
+        let holds = analyze(
+            &[
+                press(0, '!'),
+                press(100, 'A'),
+                press(600, 'A'),
+                press(630, 'A'),
+            ],
+            &[release(80, '1'), release(650, 'a')],
+            &QWERTY,
+        )
+        .expect("releases were paired");
+
+        assert_eq!(holds.average_dwell_ms, 315.0);
+    }
+
+    #[test]
+    fn longest_holds_need_repeated_samples() {
+        let holds = analyze(
+            &[press(0, 'a'), press(200, 'a'), press(400, 's')],
+            &[release(120, 'a'), release(300, 'a'), release(700, 's')],
+            &QWERTY,
+        )
+        .expect("every press was released");
+
+        let longest = holds.longest_holds.first().expect("a has two holds");
+        assert_eq!(longest.key, 'a');
+        assert_eq!(longest.dwell_ms, 110.0);
+        assert_eq!(holds.longest_holds.len(), 1);
+    }
+
+    #[test]
+    fn analyze_without_releases_has_no_hold_times() {
+        assert_eq!(analyze(&[press(0, 'a')], &[], &QWERTY), None);
+    }
+}
@@ -1,4 +1,17 @@
 pub mod accuracy;
+pub mod adaptive;
+pub mod drill;
+pub mod finger_stats;
 pub mod generate_sentence;
+pub mod history_filter;
+pub mod history_record;
 pub mod history_stats;
+pub mod key_stats;
+pub mod key_timing;
+pub mod ngram_stats;
+pub mod personal_best;
+pub mod progress;
+pub mod speed_analytics;
+pub mod text_difficulty;
+pub mod word_errors;
 pub mod wpm;
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+
+use crate::domain::history::{HistoryEntry, Keystroke, keystroke_misses};
+
+/// Sequences with fewer observations than this are left out of rankings, so a
+/// single hesitation does not top the list.
+pub const MIN_NGRAM_SAMPLES: usize = 3;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NgramStat {
+    pub ngram: String,
+    /// Times the last character was typed right after the rest of the sequence.
+    pub attempts: usize,
+    pub errors: usize,
+    /// Correct attempts, whose latency was measured.
+    pub latency_samples: usize,
+    pub total_latency_ms: u64,
+}
+
+impl NgramStat {
+    pub fn average_latency_ms(&self) -> Option<f64> {
+        if self.latency_samples == 0 {
+            return None;
+        }
+
+        Some(to_f64(self.total_latency_ms) / to_f64(self.latency_samples as u64))
+    }
+
+    /// Error rate in percent.
+    pub fn error_rate(&self) -> f64 {
+        if self.attempts == 0 {
+            return 0.0;
+        }
+
+        to_f64(self.errors as u64) * 100.0 / to_f64(self.attempts as u64)
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.attempts += other.attempts;
+        self.errors += other.errors;
+        self.latency_samples += other.latency_samples;
+        self.total_latency_ms += other.total_latency_ms;
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NgramReport {
+    stats: BTreeMap<String, NgramStat>,
+}
+
+impl NgramReport {
+    pub fn get(&self, ngram: &str) -> Option<&NgramStat> {
+        self.stats.get(ngram)
+    }
+
+    /// Sequences of `size` characters with the highest average latency.
+    pub fn slowest(&self, size: usize, limit: usize) -> Vec<&NgramStat> {
+        let mut ranked = self
+            .sized(size)
+            .filter(|stat| stat.latency_samples >= MIN_NGRAM_SAMPLES)
+            .collect::<Vec<_>>();
+        ranked.sort_by(|left, right| {
+            right
+                .average_latency_ms()
+                .partial_cmp(&left.average_latency_ms())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| left.ngram.cmp(&right.ngram))
+        });
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// Sequences of `size` characters with the highest error rate. Sequences
+    /// that were never mistyped are not listed.
+    pub fn most_error_prone(&self, size: usize, limit: usize) -> Vec<&NgramStat> {
+        let mut ranked = self
+            .sized(size)
+            .filter(|stat| stat.attempts >= MIN_NGRAM_SAMPLES && stat.errors > 0)
+            .collect::<Vec<_>>();
+        ranked.sort_by(|left, right| {
+            right
+                .error_rate()
+                .partial_cmp(&left.error_rate())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| right.attempts.cmp(&left.attempts))
+                .then_with(|| left.ngram.cmp(&right.ngram))
+        });
+        ranked.truncate(limit);
+        ranked
+    }
+
+    fn sized(&self, size: usize) -> impl Iterator<Item = &NgramStat> {
+        self.stats
+            .values()
+            .filter(move |stat| stat.ngram.chars().count() == size)
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (ngram, stat) in other.stats {
+            self.stats
+                .entry(ngram)
+                .and_modify(|existing| existing.merge(&stat))
+                .or_insert(stat);
+        }
+    }
+
+    fn record(&mut self, ngram: String, latency_ms: Option<u64>) {
+        let stat = self
+            .stats
+            .entry(ngram.clone())
+            .or_insert_with(|| NgramStat {
+                ngram,
+                ..NgramStat::default()
+            });
+        stat.attempts += 1;
+        match latency_ms {
+            Some(latency_ms) => {
+                stat.latency_samples += 1;
+                stat.total_latency_ms += latency_ms;
+            }
+            None => stat.errors += 1,
+        }
+    }
+}
+
+/// Collects bigram and trigram timings from one run.
+///
+/// A sequence is observed when its last key is pressed straight after the
+/// rest of it was typed correctly, without deletions in between. A correct key
+/// adds the time since the sequence's first key; a wrong key or a skipped word
+/// counts as an error for the expected sequence.
+pub fn collect(target: &str, keystrokes: &[Keystroke]) -> NgramReport {
+    let target_chars = target.chars().collect::<Vec<_>>();
+    let misses = keystroke_misses(target, keystrokes);
+    let mut report = NgramReport::default();
+    // The last correct keys typed back to back, as (target index, elapsed ms).
+    let mut run: Vec<(usize, u64)> = Vec::new();
+
+    for (keystroke, miss) in keystrokes.iter().zip(misses) {
+        if keystroke.key.is_none() {
+            run.clear();
+            continue;
+        }
+        let Some(&(previous, _)) = run.last() else {
+            if let (false, Some(index)) = (miss, keystroke.position.checked_sub(1)) {
+                run.push((index, keystroke.elapsed_ms));
+            }
+            continue;
+        };
+        // A wrong key may leave the cursor in place or jump past a skipped
+        // word, so the expected character is the one after the run.
+        let index = previous + 1;
+
+        for size in [2, 3] {
+            let Some(first) = run
+                .len()
+                .checked_sub(size - 1)
+                .and_then(|start| run.get(start))
+            else {
+                continue;
+            };
+            let Some(ngram) = target_chars.get(index + 1 - size..=index) else {
+                continue;
+            };
+            let latency = (!miss).then(|| keystroke.elapsed_ms.saturating_sub(first.1));
+            report.record(ngram.iter().collect(), latency);
+        }
+
+        if miss {
+            run.clear();
+        } else {
+            run.push((index, keystroke.elapsed_ms));
+            if run.len() > 2 {
+                run.remove(0);
+            }
+        }
+    }
+
+    report
+}
+
+/// Merges the n-gram timings of every successful run with a timeline.
+pub fn aggregate(entries: &[HistoryEntry]) -> NgramReport {
+    let mut report = NgramReport::default();
+    for entry in entries.iter().filter(|entry| !entry.failed) {
+        report.merge(collect(&entry.target_text, &entry.keystrokes));
+    }
+    report
+}
+
+fn to_f64(value: u64) -> f64 {
+    f64::from(u32::try_from(value).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+
+    fn keystroke(elapsed_ms: u64, key: Option<char>, position: usize) -> Keystroke {
+        Keystroke {
+            elapsed_ms,
+            key,
+            position,
+        }
+    }
+
+    #[test]
+    fn collect_measures_bigram_and_trigram_latency() {
+        let report = collect(
+            "abc",
+            &[
+                keystroke(100, Some('a'), 1),
+                keystroke(250, Some('b'), 2),
+                keystroke(450, Some('c'), 3),
+            ],
+        );
+
+        let ab = report.get("ab").expect("ab was typed");
+        assert_eq!(ab.average_latency_ms(), Some(150.0));
+        let bc = report.get("bc").expect("bc was typed");
+        assert_eq!(bc.average_latency_ms(), Some(200.0));
+        let abc = report.get("abc").expect("abc was typed");
+        assert_eq!(abc.average_latency_ms(), Some(350.0));
+        assert_eq!(report.get("a"), None);
+    }
+
+    #[test]
+    fn collect_counts_errors_and_skips_measurements_after_deletions() {
+        let report = collect(
+            "abc",
+            &[
+                keystroke(100, Some('a'), 1),
+                keystroke(200, Some('x'), 2),
+                keystroke(300, None, 1),
+                keystroke(400, Some('b'), 2),
+                keystroke(500, Some('c'), 3),
+            ],
+        );
+
+        let ab = report.get("ab").expect("ab was attempted");
+        assert_eq!((ab.attempts, ab.errors, ab.latency_samples), (1, 1, 0));
+        assert!((ab.error_rate() - 100.0).abs() < f64::EPSILON);
+        let bc = report.get("bc").expect("bc was typed");
+        assert_eq!(bc.average_latency_ms(), Some(100.0));
+        assert_eq!(report.get("abc"), None);
+    }
+
+    #[test]
+    fn aggregate_merges_runs_and_rankings_require_samples() {
+        let run = |gap: u64| HistoryEntry {
+            target_text: "ab ab".to_string(),
+            keystrokes: vec![
+                keystroke(0, Some('a'), 1),
+                keystroke(gap, Some('b'), 2),
+                keystroke(gap + 100, Some(' '), 3),
+                keystroke(gap + 200, Some('a'), 4),
+                keystroke(gap * 2 + 200, Some('x'), 5),
+            ],
+            ..HistoryEntry::default()
+        };
+        let failed = HistoryEntry {
+            failed: true,
+            ..run(1000)
+        };
+        let report = aggregate(&[run(100), run(300), failed]);
+
+        let ab = report.get("ab").expect("ab was typed");
+        assert_eq!((ab.attempts, ab.errors), (4, 2));
+        assert_eq!(ab.average_latency_ms(), Some(200.0));
+
+        let slowest = report.slowest(2, 5);
+        assert!(slowest.is_empty(), "each bigram has fewer than 3 samples");
+        let error_prone = report.most_error_prone(2, 5);
+        assert_eq!(
+            error_prone
+                .iter()
+                .map(|stat| stat.ngram.as_str())
+                .collect::<Vec<_>>(),
+            vec!["ab"]
+        );
+    }
+
+    #[test]
+    fn slowest_ranks_by_average_latency() {
+        let mut report = NgramReport::default();
+        for _ in 0..3 {
+            report.record("th".to_string(), Some(100));
+            report.record("qu".to_string(), Some(300));
+            report.record("the".to_string(), Some(900));
+        }
+
+        let slowest = report
+            .slowest(2, 5)
+            .iter()
+            .map(|stat| stat.ngram.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(slowest, vec!["qu", "th"]);
+    }
+}
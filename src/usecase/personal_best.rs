@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use crate::domain::history::{HistoryEntry, HistoryMode, TestLength};
+
+/// Sessions only compete with others of the same mode, test length, and
+/// source. Practice and rhythm sessions have no test length.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BestCategory {
+    pub mode: &'static str,
+    pub test: Option<TestLength>,
+    pub source: String,
+}
+
+impl BestCategory {
+    pub fn of(entry: &HistoryEntry) -> Self {
+        Self {
+            mode: entry.mode.label(),
+            test: entry.mode.is_timed().then(|| entry.test_length()),
+            source: entry.generation_source.clone(),
+        }
+    }
+
+    /// The category of a timed run of `test` on `source`.
+    pub fn timed(test: TestLength, source: &str) -> Self {
+        Self {
+            mode: HistoryMode::Timed.label(),
+            test: Some(test),
+            source: source.to_string(),
+        }
+    }
+
+    fn is_timed(&self) -> bool {
+        self.mode == HistoryMode::Timed.label()
+    }
+
+    pub fn label(&self) -> String {
+        match self.test {
+            Some(test) => format!("{} {}", test.label(), self.source),
+            None => format!("{} {}", self.mode, self.source),
+        }
+    }
+}
+
+/// The fastest session of a category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalBest {
+    pub category: BestCategory,
+    pub wpm: f64,
+    pub accuracy: f64,
+    pub timestamp: Option<u64>,
+    /// Sessions counted in the category.
+    pub runs: usize,
+}
+
+/// Personal bests of every category, timed runs first. Failed runs do not
+/// count, and a later run must be faster to replace an earlier one.
+pub fn personal_bests(entries: &[HistoryEntry]) -> Vec<PersonalBest> {
+    let mut bests = BTreeMap::<BestCategory, PersonalBest>::new();
+    for entry in entries.iter().filter(|entry| !entry.failed) {
+        let category = BestCategory::of(entry);
+        let best = bests
+            .entry(category.clone())
+            .or_insert_with(|| PersonalBest {
+                category,
+                wpm: entry.wpm,
+                accuracy: entry.accuracy,
+                timestamp: entry.timestamp,
+                runs: 0,
+            });
+        best.runs += 1;
+        if entry.wpm > best.wpm {
+            best.wpm = entry.wpm;
+            best.accuracy = entry.accuracy;
+            best.timestamp = entry.timestamp;
+        }
+    }
+
+    let mut bests = bests.into_values().collect::<Vec<_>>();
+    bests.sort_by_key(|best| !best.category.is_timed());
+    bests
+}
+
+/// How much faster `entry` is than the best earlier session of its category,
+/// or `None` unless it beats one.
+pub fn improvement(earlier: &[HistoryEntry], entry: &HistoryEntry) -> Option<f64> {
+    if entry.failed {
+        return None;
+    }
+
+    let best = fastest(
+        earlier.iter().map(|earlier| (earlier.wpm, earlier)),
+        &BestCategory::of(entry),
+    )?;
+    (entry.wpm > best).then_some(entry.wpm - best)
+}
+
+/// The key of the fastest session of `category` among `runs`, skipping failed
+/// runs. The earliest run wins a tie.
+pub fn fastest<'a, K>(
+    runs: impl IntoIterator<Item = (K, &'a HistoryEntry)>,
+    category: &BestCategory,
+) -> Option<K> {
+    runs.into_iter()
+        .filter(|(_, entry)| !entry.failed && BestCategory::of(entry) == *category)
+        .reduce(|best, run| if run.1.wpm > best.1.wpm { run } else { best })
+        .map(|(key, _)| key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::history::RhythmRecord;
+
+    fn run(wpm: f64, test: TestLength, source: &str) -> HistoryEntry {
+        HistoryEntry {
+            wpm,
+            accuracy: 95.0,
+            test: Some(test),
+            generation_source: source.to_string(),
+            ..HistoryEntry::default()
+        }
+    }
+
+    #[test]
+    fn personal_bests_are_kept_per_mode_length_and_source() {
+        let rhythm = HistoryEntry {
+            mode: HistoryMode::Rhythm(RhythmRecord::default()),
+            ..run(20.0, TestLength::Time(30), "Local")
+        };
+        let failed = HistoryEntry {
+            failed: true,
+            ..run(90.0, TestLength::Time(30), "Local")
+        };
+        let entries = [
+            rhythm,
+            run(40.0, TestLength::Time(30), "Local"),
+            run(45.0, TestLength::Time(30), "Local"),
+            run(60.0, TestLength::Time(60), "Local"),
+            run(50.0, TestLength::Time(30), "Groq"),
+            failed,
+        ];
+
+        let bests = personal_bests(&entries)
+            .into_iter()
+            .map(|best| (best.category.label(), best.wpm, best.runs))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            bests,
+            vec![
+                ("time 30s Groq".to_string(), 50.0, 1),
+                ("time 30s Local".to_string(), 45.0, 2),
+                ("time 60s Local".to_string(), 60.0, 1),
+                ("rhythm Local".to_string(), 20.0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn improvement_needs_an_earlier_run_to_beat() {
+        let earlier = [
+            run(40.0, TestLength::Time(30), "Local"),
+            run(70.0, TestLength::Words(25), "Local"),
+        ];
+
+        assert_eq!(
+            improvement(&earlier, &run(42.5, TestLength::Time(30), "Local")),
+            Some(2.5)
+        );
+        assert_eq!(
+            improvement(&earlier, &run(39.0, TestLength::Time(30), "Local")),
+            None
+        );
+        assert_eq!(
+            improvement(&earlier, &run(50.0, TestLength::Time(30), "Groq")),
+            None
+        );
+        let failed = HistoryEntry {
+            failed: true,
+            ..run(80.0, TestLength::Words(25), "Local")
+        };
+        assert_eq!(improvement(&earlier, &failed), None);
+    }
+}
@@ -0,0 +1,353 @@
+//! Long-term progress series for the charts on the Stats screen.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::domain::history::HistoryEntry;
+use crate::domain::timestamp;
+
+/// Points shown at each zoom level, widest first; `None` shows every point.
+const ZOOM_LEVELS: [Option<usize>; 4] = [None, Some(100), Some(50), Some(20)];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProgressChart {
+    #[default]
+    Wpm,
+    Accuracy,
+    /// Sessions per calendar day (UTC), including days without any.
+    Daily,
+}
+
+impl ProgressChart {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Wpm => "WPM per session",
+            Self::Accuracy => "Accuracy per session",
+            Self::Daily => "Sessions per day",
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Wpm => "WPM/session",
+            Self::Accuracy => "%/session",
+            Self::Daily => "sessions/day",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Wpm => Self::Accuracy,
+            Self::Accuracy => Self::Daily,
+            Self::Daily => Self::Wpm,
+        }
+    }
+
+    /// Points averaged by the moving average: ten sessions, or a week.
+    pub fn average_window(self) -> usize {
+        match self {
+            Self::Wpm | Self::Accuracy => 10,
+            Self::Daily => 7,
+        }
+    }
+}
+
+/// Which chart is shown and which part of it. The offset counts points from
+/// the newest end, so new sessions stay in view until the user scrolls back.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProgressView {
+    pub chart: ProgressChart,
+    zoom: usize,
+    offset: usize,
+}
+
+impl ProgressView {
+    pub fn cycle_chart(&mut self) {
+        self.chart = self.chart.next();
+        self.offset = 0;
+    }
+
+    pub fn zoom_in(&mut self) {
+        self.zoom = (self.zoom + 1).min(ZOOM_LEVELS.len() - 1);
+    }
+
+    pub fn zoom_out(&mut self) {
+        self.zoom = self.zoom.saturating_sub(1);
+    }
+
+    /// Moves a quarter of the visible points towards older sessions.
+    pub fn scroll_back(&mut self, len: usize) {
+        let width = self.width(len);
+        self.offset = (self.offset + scroll_step(width)).min(len - width);
+    }
+
+    pub fn scroll_forward(&mut self, len: usize) {
+        let step = scroll_step(self.width(len));
+        self.offset = self.offset.saturating_sub(step);
+    }
+
+    /// Indices of the points in view out of `len`.
+    pub fn visible(self, len: usize) -> Range<usize> {
+        let width = self.width(len);
+        let end = len - self.offset.min(len - width);
+        end - width..end
+    }
+
+    pub fn zoom_label(self) -> String {
+        match ZOOM_LEVELS.get(self.zoom).copied().flatten() {
+            Some(points) => format!("last {points}"),
+            None => "all".to_string(),
+        }
+    }
+
+    fn width(self, len: usize) -> usize {
+        ZOOM_LEVELS
+            .get(self.zoom)
+            .copied()
+            .flatten()
+            .map_or(len, |points| points.min(len))
+    }
+}
+
+fn scroll_step(width: usize) -> usize {
+    (width / 4).max(1)
+}
+
+/// Least-squares line through the points, with x as the point index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trend {
+    pub slope: f64,
+    pub intercept: f64,
+}
+
+impl Trend {
+    pub fn at(self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+}
+
+/// The part of a chart in view, with x as the index into the whole series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressPlot {
+    pub chart: ProgressChart,
+    pub total: usize,
+    pub visible: Range<usize>,
+    pub values: Vec<(f64, f64)>,
+    pub average: Vec<(f64, f64)>,
+    /// Fitted to the points in view.
+    pub trend: Option<Trend>,
+    /// Day number of the first point of a daily chart.
+    pub first_day: Option<u64>,
+}
+
+impl ProgressPlot {
+    /// `YYYY-MM-DD` of a point of a daily chart.
+    pub fn day_label(&self, index: usize) -> Option<String> {
+        let first_day = self.first_day?;
+        Some(timestamp::format_date(
+            first_day + u64::try_from(index).ok()?,
+        ))
+    }
+}
+
+/// Builds the chart of `entries` for `view`. Failed runs are left out of the
+/// session charts, as in the summary; the daily chart counts every session
+/// with a timestamp.
+pub fn plot(entries: &[HistoryEntry], view: ProgressView) -> ProgressPlot {
+    let (series, first_day) = match view.chart {
+        ProgressChart::Wpm => (session_values(entries, |entry| entry.wpm), None),
+        ProgressChart::Accuracy => (session_values(entries, |entry| entry.accuracy), None),
+        ProgressChart::Daily => match sessions_per_day(entries) {
+            Some((first_day, counts)) => (counts, Some(first_day)),
+            None => (Vec::new(), None),
+        },
+    };
+    let visible = view.visible(series.len());
+    let average = moving_average(&series, view.chart.average_window());
+    let points = |values: &[f64]| {
+        visible
+            .clone()
+            .filter_map(|index| Some((to_f64(index), *values.get(index)?)))
+            .collect::<Vec<_>>()
+    };
+    let values = points(&series);
+
+    ProgressPlot {
+        chart: view.chart,
+        total: series.len(),
+        visible: visible.clone(),
+        trend: trend(&values),
+        average: points(&average),
+        values,
+        first_day,
+    }
+}
+
+fn session_values(entries: &[HistoryEntry], value: fn(&HistoryEntry) -> f64) -> Vec<f64> {
+    entries
+        .iter()
+        .filter(|entry| !entry.failed)
+        .map(value)
+        .collect()
+}
+
+/// Session counts from the first day with a session to the last, or `None`
+/// when no entry has a timestamp.
+pub fn sessions_per_day(entries: &[HistoryEntry]) -> Option<(u64, Vec<f64>)> {
+    let mut counts = BTreeMap::<u64, u32>::new();
+    for day in entries
+        .iter()
+        .filter_map(|entry| entry.timestamp.map(timestamp::day_number))
+    {
+        *counts.entry(day).or_default() += 1;
+    }
+    let (&first_day, _) = counts.first_key_value()?;
+    let (&last_day, _) = counts.last_key_value()?;
+
+    let days = (first_day..=last_day)
+        .map(|day| f64::from(counts.get(&day).copied().unwrap_or_default()))
+        .collect();
+    Some((first_day, days))
+}
+
+/// Trailing average over up to `window` points; the first points average
+/// what there is so far.
+pub fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    let window = window.max(1);
+    (0..values.len())
+        .map(|end| {
+            let points = values
+                .get((end + 1).saturating_sub(window)..=end)
+                .unwrap_or_default();
+            points.iter().sum::<f64>() / to_f64(points.len())
+        })
+        .collect()
+}
+
+/// `None` with fewer than two points.
+pub fn trend(points: &[(f64, f64)]) -> Option<Trend> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let count = to_f64(points.len());
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>();
+    let variance = points
+        .iter()
+        .map(|(x, _)| (x - mean_x).powi(2))
+        .sum::<f64>();
+    let slope = if variance == 0.0 {
+        0.0
+    } else {
+        covariance / variance
+    };
+    Some(Trend {
+        slope,
+        intercept: mean_y - slope * mean_x,
+    })
+}
+
+fn to_f64(value: usize) -> f64 {
+    f64::from(u32::try_from(value).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::timestamp::SECONDS_PER_DAY;
+
+    fn run(wpm: f64, day: u64) -> HistoryEntry {
+        HistoryEntry {
+            wpm,
+            accuracy: 90.0,
+            timestamp: Some(day * SECONDS_PER_DAY + 60),
+            ..HistoryEntry::default()
+        }
+    }
+
+    #[test]
+    fn moving_average_trails_over_the_window() {
+        assert_eq!(
+            moving_average(&[10.0, 20.0, 30.0, 40.0], 2),
+            vec![10.0, 15.0, 25.0, 35.0]
+        );
+        assert!(moving_average(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn trend_fits_a_least_squares_line() {
+        let trend = trend(&[(0.0, 10.0), (1.0, 12.0), (2.0, 14.0)]);
+
+        assert_eq!(
+            trend,
+            Some(Trend {
+                slope: 2.0,
+                intercept: 10.0
+            })
+        );
+        assert_eq!(super::trend(&[(0.0, 1.0)]), None);
+    }
+
+    #[test]
+    fn sessions_per_day_fills_days_without_sessions() {
+        let entries = [
+            run(30.0, 100),
+            run(31.0, 100),
+            run(32.0, 102),
+            HistoryEntry::default(),
+        ];
+
+        assert_eq!(sessions_per_day(&entries), Some((100, vec![2.0, 0.0, 1.0])));
+        assert_eq!(sessions_per_day(&[HistoryEntry::default()]), None);
+    }
+
+    #[test]
+    fn view_zooms_and_scrolls_within_the_series() {
+        let mut view = ProgressView::default();
+        assert_eq!(view.visible(120), 0..120);
+
+        view.zoom_in();
+        view.zoom_in();
+        assert_eq!(view.visible(120), 70..120);
+        view.scroll_back(120);
+        assert_eq!(view.visible(120), 58..108);
+        for _ in 0..5 {
+            view.scroll_back(120);
+        }
+        assert_eq!(view.visible(120), 0..50);
+        view.scroll_forward(120);
+        assert_eq!(view.visible(120), 12..62);
+
+        view.zoom_in();
+        view.zoom_in();
+        assert_eq!(view.zoom_label(), "last 20");
+        assert_eq!(view.visible(10), 0..10);
+        assert_eq!(view.visible(0), 0..0);
+    }
+
+    #[test]
+    fn plot_leaves_failed_runs_out_of_session_charts() {
+        let failed = HistoryEntry {
+            failed: true,
+            ..run(99.0, 101)
+        };
+        let entries = [run(30.0, 100), failed, run(40.0, 102)];
+
+        let wpm = plot(&entries, ProgressView::default());
+        assert_eq!(wpm.values, vec![(0.0, 30.0), (1.0, 40.0)]);
+        assert_eq!(wpm.average, vec![(0.0, 30.0), (1.0, 35.0)]);
+        assert_eq!(wpm.trend.map(|trend| trend.slope), Some(10.0));
+
+        let mut view = ProgressView::default();
+        view.cycle_chart();
+        view.cycle_chart();
+        let daily = plot(&entries, view);
+        assert_eq!(daily.total, 3);
+        assert_eq!(daily.day_label(2), Some(timestamp::format_date(102)));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::domain::history::{KeyEventMode, Keystroke, Passage, SpeedAnalytics, keystroke_misses};
+
+const BURST_WINDOW_SECONDS: usize = 5;
+const SLOWEST_PASSAGE_CHARS: usize = 10;
+/// Characters per second converted to words per minute (60 / 5).
+const CPS_TO_WPM: u32 = 12;
+
+/// Derives per-second speed, consistency, burst speed, error times and the
+/// slowest passage from a keystroke timeline.
+pub fn analyze(target: &str, keystrokes: &[Keystroke]) -> SpeedAnalytics {
+    let misses = keystroke_misses(target, keystrokes);
+    let per_second_wpm = per_second_wpm(keystrokes, &misses);
+    let per_second = per_second_wpm
+        .iter()
+        .map(|wpm| f64::from(*wpm))
+        .collect::<Vec<_>>();
+
+    SpeedAnalytics {
+        per_second_wpm: per_second_wpm.into_iter().map(u64::from).collect(),
+        speed_cv: coefficient_of_variation(&per_second),
+        burst_wpm: burst_wpm(&per_second),
+        error_seconds: keystrokes
+            .iter()
+            .zip(&misses)
+            .filter(|(_, miss)| **miss)
+            .map(|(keystroke, _)| ms_to_seconds(keystroke.elapsed_ms))
+            .collect(),
+        slowest_passage: slowest_passage(target, keystrokes),
+        key_events: KeyEventMode::default(),
+        hold_times: None,
+    }
+}
+
+/// A key pressed at exactly 1000 ms belongs to the first second, so each
+/// bucket covers `(n, n + 1]` seconds.
+fn per_second_wpm(keystrokes: &[Keystroke], misses: &[bool]) -> Vec<u32> {
+    let Some(last) = keystrokes.last() else {
+        return Vec::new();
+    };
+    let seconds = usize::try_from(last.elapsed_ms.div_ceil(1000).max(1)).unwrap_or(usize::MAX);
+    let mut correct = vec![0_u32; seconds];
+
+    for (keystroke, miss) in keystrokes.iter().zip(misses) {
+        if keystroke.key.is_none() || *miss {
+            continue;
+        }
+        let bucket = usize::try_from(keystroke.elapsed_ms.saturating_sub(1) / 1000).unwrap_or(0);
+        if let Some(count) = correct.get_mut(bucket) {
+            *count += 1;
+        }
+    }
+
+    correct
+        .into_iter()
+        .map(|count| count.saturating_mul(CPS_TO_WPM))
+        .collect()
+}
+
+fn coefficient_of_variation(samples: &[f64]) -> Option<f64> {
+    if samples.len() < 2 {
+        return None;
+    }
+
+    let count = f64::from(u32::try_from(samples.len()).unwrap_or(u32::MAX));
+    let mean = samples.iter().sum::<f64>() / count;
+    if mean <= 0.0 {
+        return None;
+    }
+    let variance = samples
+        .iter()
+        .map(|sample| (sample - mean).powi(2))
+        .sum::<f64>()
+        / count;
+
+    Some(variance.sqrt() / mean * 100.0)
+}
+
+/// Runs shorter than the window use their whole length instead.
+fn burst_wpm(samples: &[f64]) -> f64 {
+    let window = BURST_WINDOW_SECONDS.min(samples.len()).max(1);
+    let divisor = f64::from(u32::try_from(window).unwrap_or(u32::MAX));
+
+    samples
+        .windows(window)
+        .map(|window| window.iter().sum::<f64>() / divisor)
+        .fold(0.0, f64::max)
+}
+
+/// Finds the fixed-length stretch of the target that took longest to get
+/// through, widened to whole words. Time before the first key is ignored.
+fn slowest_passage(target: &str, keystrokes: &[Keystroke]) -> Option<Passage> {
+    let target_chars = target.chars().collect::<Vec<_>>();
+    let reached_at = |position: usize| -> Option<u64> {
+        if position == 0 {
+            return keystrokes.first().map(|keystroke| keystroke.elapsed_ms);
+        }
+        keystrokes
+            .iter()
+            .find(|keystroke| keystroke.position >= position)
+            .map(|keystroke| keystroke.elapsed_ms)
+    };
+
+    let mut slowest: Option<(usize, u64)> = None;
+    for start in 0..=target_chars.len().saturating_sub(SLOWEST_PASSAGE_CHARS) {
+        let (Some(from), Some(to)) = (reached_at(start), reached_at(start + SLOWEST_PASSAGE_CHARS))
+        else {
+            break;
+        };
+        let duration = to.saturating_sub(from);
+        if slowest.is_none_or(|(_, longest)| duration > longest) {
+            slowest = Some((start, duration));
+        }
+    }
+
+    let (window_start, _) = slowest?;
+    let start = word_start(&target_chars, window_start);
+    let end = word_end(&target_chars, window_start + SLOWEST_PASSAGE_CHARS);
+    let from = reached_at(start)?;
+    let to = reached_at(end)?;
+
+    Some(Passage {
+        start,
+        end,
+        seconds: ms_to_seconds(to.saturating_sub(from)),
+    })
+}
+
+fn word_start(chars: &[char], index: usize) -> usize {
+    let mut start = index;
+    while start > 0 && chars.get(start - 1).is_some_and(|ch| !ch.is_whitespace()) {
+        start -= 1;
+    }
+    start
+}
+
+fn word_end(chars: &[char], index: usize) -> usize {
+    let mut end = index.min(chars.len());
+    while chars.get(end).is_some_and(|ch| !ch.is_whitespace()) {
+        end += 1;
+    }
+    end
+}
+
+fn ms_to_seconds(elapsed_ms: u64) -> f64 {
+    f64::from(u32::try_from(elapsed_ms).unwrap_or(u32::MAX)) / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+    use super::*;
+
+    fn typed(text: &str, interval_ms: &[u64]) -> Vec<Keystroke> {
+        let mut elapsed_ms = 0;
+        text.chars()
+            .zip(interval_ms.iter().cycle())
+            .enumerate()
+            .map(|(index, (key, interval))| {
+                elapsed_ms += interval;
+                Keystroke {
+                    elapsed_ms,
+                    key: Some(key),
+                    position: index + 1,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn per_second_speed_counts_correct_keys_in_each_second() {
+        let keystrokes = vec![
+            Keystroke {
+                elapsed_ms: 500,
+                key: Some('a'),
+                position: 1,
+            },
+            Keystroke {
+                elapsed_ms: 1000,
+                key: Some('x'),
+                position: 2,
+            },
+            Keystroke {
+                elapsed_ms: 1500,
+                key: None,
+                position: 1,
+            },
+            Keystroke {
+                elapsed_ms: 1800,
+                key: Some('b'),
+                position: 2,
+            },
+        ];
+
+        let analytics = analyze("ab", &keystrokes);
+
+        assert_eq!(analytics.per_second_wpm, vec![12, 12]);
+        assert_eq!(analytics.error_seconds, vec![1.0]);
+    }
+
+    #[test]
+    fn even_pace_has_no_variation_and_uneven_pace_does() {
+        let steady = analyze(&"a".repeat(20), &typed(&"a".repeat(20), &[250]));
+        assert_eq!(steady.per_second_wpm, vec![48; 5]);
+        assert_eq!(steady.speed_cv, Some(0.0));
+        assert_eq!(steady.consistency(), Some(100.0));
+
+        let uneven = analyze(&"a".repeat(6), &typed(&"a".repeat(6), &[200, 200, 1600]));
+        let cv = uneven.speed_cv.expect("run spans several seconds");
+        assert!(cv > 30.0);
+    }
+
+    #[test]
+    fn burst_takes_the_fastest_five_second_window() {
+        assert!((burst_wpm(&[12.0, 60.0, 60.0, 60.0, 60.0, 60.0, 12.0]) - 60.0).abs() < 1e-9);
+        assert!((burst_wpm(&[24.0, 48.0]) - 36.0).abs() < 1e-9);
+        assert!(burst_wpm(&[]).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn slowest_passage_widens_the_slow_stretch_to_whole_words() {
+        let target = "aaaa bbbb cccc dddd eeee";
+        let mut intervals = vec![100; 24];
+        if let Some(interval) = intervals.get_mut(16) {
+            *interval = 3000;
+        }
+        let keystrokes = typed(target, &intervals);
+
+        let passage = analyze(target, &keystrokes)
+            .slowest_passage
+            .expect("text is long enough");
+
+        assert_eq!(passage.start, 5);
+        assert_eq!(passage.end, 19);
+        assert!(passage.seconds > 3.0);
+    }
+
+    #[test]
+    fn short_or_unfinished_runs_have_no_slowest_passage() {
+        assert_eq!(analyze("abc", &typed("abc", &[100])).slowest_passage, None);
+        assert_eq!(analyze("abc", &[]), SpeedAnalytics::default());
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use crate::domain::entity;
+
+/// Difficulty of the plain lowercase prose most runs are typed on. Adjusted
+/// WPM is the speed scaled to a text of this difficulty.
+pub const REFERENCE_DIFFICULTY: f64 = 30.0;
+/// Letter pairs seen fewer times than this in the sample text count as rare.
+const RARE_BIGRAM_MAX_COUNT: usize = 2;
+/// US-layout symbols typed with Shift.
+const SHIFTED_SYMBOLS: &str = "~!@#$%^&*()_+{}|:\"<>?";
+const PUNCTUATION: &str = ".,;:'\"!?-()";
+
+/// Each feature adds up to its weight to the score, reaching it at the
+/// saturation ratio, so the score runs from 0 to 100.
+const SHIFT_WEIGHT: f64 = 25.0;
+const SHIFT_SATURATION: f64 = 0.15;
+const SYMBOL_WEIGHT: f64 = 20.0;
+const SYMBOL_SATURATION: f64 = 0.15;
+const RARE_BIGRAM_WEIGHT: f64 = 25.0;
+const RARE_BIGRAM_SATURATION: f64 = 0.2;
+const WORD_LENGTH_WEIGHT: f64 = 20.0;
+const SHORT_WORD_LENGTH: f64 = 3.0;
+const LONG_WORD_LENGTH: f64 = 8.0;
+const CHAR_CLASS_WEIGHT: f64 = 10.0;
+const CHAR_CLASS_COUNT: usize = 5;
+
+/// Features of a target text that make it harder to type than plain prose.
+/// Ratios are fractions of the non-space characters, or of the letter pairs
+/// inside words for rare bigrams.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextDifficulty {
+    /// Lowercase, capitals, digits, punctuation, and other symbols present.
+    pub char_classes: usize,
+    pub shift_ratio: f64,
+    /// Digits, punctuation, and other symbols.
+    pub symbol_ratio: f64,
+    pub rare_bigram_ratio: f64,
+    pub average_word_length: f64,
+}
+
+impl TextDifficulty {
+    /// Score from 0 (easiest) to 100.
+    pub fn score(&self) -> f64 {
+        let classes = self.char_classes.clamp(1, CHAR_CLASS_COUNT) - 1;
+        let word_length =
+            (self.average_word_length - SHORT_WORD_LENGTH) / (LONG_WORD_LENGTH - SHORT_WORD_LENGTH);
+
+        saturate(self.shift_ratio / SHIFT_SATURATION) * SHIFT_WEIGHT
+            + saturate(self.symbol_ratio / SYMBOL_SATURATION) * SYMBOL_WEIGHT
+            + saturate(self.rare_bigram_ratio / RARE_BIGRAM_SATURATION) * RARE_BIGRAM_WEIGHT
+            + saturate(word_length) * WORD_LENGTH_WEIGHT
+            + ratio(classes, CHAR_CLASS_COUNT - 1) * CHAR_CLASS_WEIGHT
+    }
+}
+
+/// Measures `text`, or `None` when it has nothing to type.
+pub fn measure(text: &str) -> Option<TextDifficulty> {
+    let chars = text
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .collect::<Vec<_>>();
+    if chars.is_empty() {
+        return None;
+    }
+
+    let mut classes = [false; CHAR_CLASS_COUNT];
+    for &ch in &chars {
+        if let Some(class) = classes.get_mut(char_class(ch)) {
+            *class = true;
+        }
+    }
+    let shifted = chars
+        .iter()
+        .filter(|ch| ch.is_uppercase() || SHIFTED_SYMBOLS.contains(**ch))
+        .count();
+    let symbols = chars.iter().filter(|ch| !ch.is_alphabetic()).count();
+    let words = text.split_whitespace().collect::<Vec<_>>();
+
+    Some(TextDifficulty {
+        char_classes: classes.iter().filter(|present| **present).count(),
+        shift_ratio: ratio(shifted, chars.len()),
+        symbol_ratio: ratio(symbols, chars.len()),
+        rare_bigram_ratio: rare_bigram_ratio(&words),
+        average_word_length: ratio(chars.len(), words.len()),
+    })
+}
+
+/// Difficulty score of `text`, or `None` when it has nothing to type.
+pub fn score(text: &str) -> Option<f64> {
+    measure(text).map(|difficulty| difficulty.score())
+}
+
+/// `wpm` scaled to a text of [`REFERENCE_DIFFICULTY`]: each point of
+/// difficulty above it adds 1% and each point below takes 1% away.
+pub fn adjusted_wpm(wpm: f64, difficulty: f64) -> f64 {
+    wpm * (100.0 + difficulty - REFERENCE_DIFFICULTY) / 100.0
+}
+
+pub fn label(score: f64) -> &'static str {
+    match score {
+        score if score < 25.0 => "easy",
+        score if score < 50.0 => "moderate",
+        score if score < 75.0 => "hard",
+        _ => "very hard",
+    }
+}
+
+fn char_class(ch: char) -> usize {
+    if ch.is_lowercase() {
+        0
+    } else if ch.is_uppercase() {
+        1
+    } else if ch.is_numeric() {
+        2
+    } else if PUNCTUATION.contains(ch) {
+        3
+    } else {
+        4
+    }
+}
+
+fn rare_bigram_ratio(words: &[&str]) -> f64 {
+    let common = sample_bigrams();
+    let bigrams = words
+        .iter()
+        .flat_map(|word| letter_bigrams(word))
+        .collect::<Vec<_>>();
+    let rare = bigrams
+        .iter()
+        .filter(|bigram| common.get(*bigram).copied().unwrap_or_default() < RARE_BIGRAM_MAX_COUNT)
+        .count();
+    ratio(rare, bigrams.len())
+}
+
+fn sample_bigrams() -> BTreeMap<(char, char), usize> {
+    let mut counts = BTreeMap::new();
+    for bigram in entity::get_sample()
+        .split_whitespace()
+        .flat_map(letter_bigrams)
+    {
+        *counts.entry(bigram).or_default() += 1;
+    }
+    counts
+}
+
+/// Adjacent lowercased letters within `word`; pairs broken by a non-letter
+/// are left out.
+fn letter_bigrams(word: &str) -> Vec<(char, char)> {
+    let letters = word
+        .chars()
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    letters
+        .windows(2)
+        .filter_map(|pair| match pair {
+            [left, right] if left.is_alphabetic() && right.is_alphabetic() => Some((*left, *right)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn saturate(value: f64) -> f64 {
+    value.clamp(0.0, 1.0)
+}
+
+fn ratio(part: usize, whole: usize) -> f64 {
+    if whole == 0 {
+        return 0.0;
+    }
+    let to_f64 = |value| f64::from(u32::try_from(value).unwrap_or(u32::MAX));
+    to_f64(part) / to_f64(whole)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn measure_counts_shifted_keys_symbols_and_word_length() {
+        let difficulty = measure("Hi, you 42!").expect("text to type");
+
+        assert_eq!(difficulty.char_classes, 4);
+        assert!((difficulty.shift_ratio - 2.0 / 9.0).abs() < 1e-9);
+        assert!((difficulty.symbol_ratio - 4.0 / 9.0).abs() < 1e-9);
+        assert!((difficulty.average_word_length - 3.0).abs() < 1e-9);
+        assert_eq!(measure("   "), None);
+    }
+
+    #[test]
+    fn rare_bigrams_are_pairs_the_sample_text_barely_uses() {
+        let common = measure("the and then").expect("text to type");
+        let rare = measure("zqx jvq").expect("text to type");
+
+        assert!(common.rare_bigram_ratio.abs() < 1e-9);
+        assert!((rare.rare_bigram_ratio - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn punctuated_text_scores_harder_than_lowercase_prose() {
+        let plain = score("the world we live in is a complex place").expect("text to type");
+        let punctuated = score("The World (we live in) is a #1 \"complex\" place; right?")
+            .expect("text to type");
+
+        assert!(plain < REFERENCE_DIFFICULTY);
+        assert!(punctuated > 50.0);
+        assert!(punctuated <= 100.0);
+        assert_eq!(label(plain), "easy");
+    }
+
+    #[test]
+    fn adjusted_wpm_scales_around_the_reference_difficulty() {
+        assert!((adjusted_wpm(50.0, REFERENCE_DIFFICULTY) - 50.0).abs() < 1e-9);
+        assert!((adjusted_wpm(50.0, 50.0) - 60.0).abs() < 1e-9);
+        assert!((adjusted_wpm(50.0, 10.0) - 40.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+
+use crate::domain::history::AttemptCount;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WordErrorSummary {
+    pub typed: usize,
+    pub with_errors: usize,
+    pub corrected: usize,
+    pub skipped: usize,
+}
+
+/// Summarizes the target words whose last character has been reached.
+///
+/// A word has errors when its final input still differs from the target, and
+/// counts as corrected when a mistake was made inside it but fixed before
+/// moving on. Skipped words are filled with whitespace by the space-skip mode
+/// and are also counted as words with errors.
+pub fn summarize(target: &str, inputs: &[char], mistake_positions: &[usize]) -> WordErrorSummary {
+    let target_chars = target.chars().collect::<Vec<_>>();
+    let mut summary = WordErrorSummary::default();
+
+    for (start, end) in word_ranges(&target_chars) {
+        if end > inputs.len() {
+            break;
+        }
+
+        summary.typed += 1;
+        let mut wrong = false;
+        let mut skipped = false;
+        for index in start..end {
+            let target_char = target_chars.get(index);
+            let input_char = inputs.get(index);
+            if input_char != target_char {
+                wrong = true;
+                skipped |= input_char.is_some_and(|ch| ch.is_whitespace());
+            }
+        }
+
+        if wrong {
+            summary.with_errors += 1;
+        } else if mistake_positions
+            .iter()
+            .any(|position| (start..end).contains(position))
+        {
+            summary.corrected += 1;
+        }
+        if skipped {
+            summary.skipped += 1;
+        }
+    }
+
+    summary
+}
+
+/// Counts each finished word, and as missed when a mistake was made inside it
+/// even if it was corrected. Words are lowercased without surrounding
+/// punctuation, so `The` and `the,` count together.
+pub fn attempts(
+    target: &str,
+    inputs: &[char],
+    mistake_positions: &[usize],
+) -> BTreeMap<String, AttemptCount> {
+    let target_chars = target.chars().collect::<Vec<_>>();
+    let mut words = BTreeMap::<String, AttemptCount>::new();
+
+    for (start, end) in word_ranges(&target_chars) {
+        if end > inputs.len() {
+            break;
+        }
+        let word = normalize_word(target_chars.get(start..end).unwrap_or_default());
+        if word.is_empty() {
+            continue;
+        }
+
+        let wrong = (start..end).any(|index| inputs.get(index) != target_chars.get(index));
+        let corrected = mistake_positions
+            .iter()
+            .any(|position| (start..end).contains(position));
+        words.entry(word).or_default().record(wrong || corrected);
+    }
+
+    words
+}
+
+/// Missed words, most misses first, then by error rate and alphabetically.
+pub fn most_missed(
+    words: &BTreeMap<String, AttemptCount>,
+    limit: usize,
+) -> Vec<(String, AttemptCount)> {
+    let mut missed = words
+        .iter()
+        .filter(|(_, count)| count.misses > 0)
+        .map(|(word, count)| (word.clone(), *count))
+        .collect::<Vec<_>>();
+    missed.sort_by(|(left_word, left), (right_word, right)| {
+        right
+            .misses
+            .cmp(&left.misses)
+            .then_with(|| right.error_rate().total_cmp(&left.error_rate()))
+            .then_with(|| left_word.cmp(right_word))
+    });
+    missed.truncate(limit);
+    missed
+}
+
+fn normalize_word(chars: &[char]) -> String {
+    chars
+        .iter()
+        .collect::<String>()
+        .trim_matches(|ch: char| !ch.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn word_ranges(target_chars: &[char]) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+
+    for (index, ch) in target_chars.iter().enumerate() {
+        match (ch.is_whitespace(), start) {
+            (false, None) => start = Some(index),
+            (true, Some(word_start)) => {
+                ranges.push((word_start, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        ranges.push((word_start, target_chars.len()));
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn summarize_counts_only_finished_words() {
+        let summary = summarize("ab cd ef", &chars("ab c"), &[]);
+
+        assert_eq!(summary.typed, 1);
+        assert_eq!(summary.with_errors, 0);
+    }
+
+    #[test]
+    fn summarize_separates_remaining_and_corrected_errors() {
+        let summary = summarize("ab cd ef", &chars("ax cd ef"), &[1, 3]);
+
+        assert_eq!(
+            summary,
+            WordErrorSummary {
+                typed: 3,
+                with_errors: 1,
+                corrected: 1,
+                skipped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn attempts_count_finished_words_with_any_mistake_as_missed() {
+        let words = attempts("The cat, the dog", &chars("The cat, thx d"), &[1, 11]);
+
+        assert_eq!(
+            words.into_iter().collect::<Vec<_>>(),
+            vec![
+                (
+                    "cat".to_string(),
+                    AttemptCount {
+                        attempts: 1,
+                        misses: 0
+                    }
+                ),
+                (
+                    "the".to_string(),
+                    AttemptCount {
+                        attempts: 2,
+                        misses: 2
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn most_missed_ranks_by_misses_then_error_rate() {
+        let count = |attempts, misses| AttemptCount { attempts, misses };
+        let words = BTreeMap::from([
+            ("and".to_string(), count(4, 1)),
+            ("but".to_string(), count(1, 1)),
+            ("the".to_string(), count(6, 2)),
+            ("was".to_string(), count(3, 0)),
+        ]);
+
+        let ranked = most_missed(&words, 2)
+            .into_iter()
+            .map(|(word, _)| word)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranked, ["the", "but"]);
+    }
+
+    #[test]
+    fn summarize_counts_whitespace_filled_words_as_skipped() {
+        let summary = summarize("abc de", &chars("a   de"), &[1]);
+
+        assert_eq!(summary.typed, 2);
+        assert_eq!(summary.with_errors, 1);
+        assert_eq!(summary.skipped, 1);
+    }
+}